@@ -1,4 +1,13 @@
-from typing import Tuple, List
+from typing import Any, Awaitable, Callable, Dict, List, Mapping, Optional, Tuple, Union
+
+
+PDB_COLUMNS: Mapping[str, Tuple[int, int]]
+"""
+Read-only mapping of the ATOM/HETATM record fields to the column ranges used by the parser
+(0-based, end exclusive), e.g. `PDB_COLUMNS["b_factor"] == (60, 66)`. The fields are:
+record, serial, name, alt_loc, residue_name, chain, residue_number, insertion_code, x, y,
+z, occupancy, b_factor, element and charge.
+"""
 
 
 class Periodic:
@@ -34,8 +43,96 @@ class UnitCell:
     # Special methods
     # -----------------------------------------------------------------------------------------
 
+    def __reduce__(self) -> Tuple[Any, ...]: ...
+
     def __repr__(self) -> str: ...
 
+    # -----------------------------------------------------------------------------------------
+    # Methods
+    # -----------------------------------------------------------------------------------------
+
+    def cartesian_to_fractional(self) -> List[List[float]]:
+        """
+        Returns the matrix converting Cartesian coordinates to fractional coordinates.
+
+        The matrix is the inverse of the orthogonalization matrix (see
+        fractional_to_cartesian) and corresponds to the SCALE records of the PDB file. A
+        ValueError is raised for a unit cell without volume (e.g. a structure without a
+        CRYST1 record).
+
+
+        # Returns
+        `list[list[float]]`
+            The 3x3 matrix (rows), fractional = matrix * cartesian.
+
+
+        # Examples
+        ### Computing the SCALE matrix of the structure.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> matrix = structure.unit_cell.cartesian_to_fractional()
+        >>> [[round(value, 6) for value in row] for row in matrix]
+
+        ``` raw
+        [[0.012083, -0.0, 0.001328], [0.0, 0.010615, -0.0], [0.0, 0.0, 0.01538]]
+        ```
+        """
+
+    def fractional_to_cartesian(self) -> List[List[float]]:
+        """
+        Returns the matrix converting fractional coordinates to Cartesian coordinates.
+
+        The orthogonalization matrix follows the PDB convention: the 'a' axis is along x, the
+        'b' axis lies in the xy plane and the 'c' axis completes the right-handed system.
+
+
+        # Returns
+        `list[list[float]]`
+            The 3x3 upper triangular matrix (rows), cartesian = matrix * fractional.
+
+
+        # Examples
+        ### Computing the orthogonalization matrix of the structure.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> matrix = structure.unit_cell.fractional_to_cartesian()
+        >>> [[round(value, 3) for value in row] for row in matrix]
+
+        ``` raw
+        [[82.76, 0.0, -7.144], [0.0, 94.21, 0.0], [0.0, 0.0, 65.021]]
+        ```
+        """
+
+    def volume(self) -> float:
+        """
+        Returns the volume of the unit cell.
+
+        The volume of the triclinic cell is V = abc * sqrt(1 - cos(alpha)^2 - cos(beta)^2 -
+        cos(gamma)^2 + 2 cos(alpha) cos(beta) cos(gamma)).
+
+
+        # Returns
+        `float`
+            The volume of the unit cell (in cubic Angstroms).
+
+
+        # Examples
+        ### Computing the volume of the unit cell.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.unit_cell.volume()
+
+        ``` raw
+        506954.85113109805
+        ```
+        """
+
 
 class Atom:
     """
@@ -51,6 +148,12 @@ class Atom:
     name: str
     """[`str`] Atom name."""
 
+    alt_loc: Optional[str]
+    """
+    [`str | None`] Alternate location indicator (None for atoms without alternate
+    conformations).
+    """
+
     element: str
     """[`str`] Chemical element name."""
 
@@ -60,426 +163,3687 @@ class Atom:
     occupancy: float
     """[`float`] Atom occupancy."""
 
-    # -----------------------------------------------------------------------------------------
-    # Special methods
-    # -----------------------------------------------------------------------------------------
-
-    def __repr__(self) -> str: ...
+    b_factor: float
+    """[`float`] Temperature (B-)factor."""
 
+    charge: Optional[int]
+    """[`int | None`] Formal charge (None when the charge field is blank)."""
 
-class Residue:
+    is_exposed: bool
     """
-    Residue - a class that represents a residue of a PDB structure.
+    [`bool`] Whether the atom is solvent exposed, computed by Structure.mark_exposure (a
+    ValueError is raised before it is called).
     """
 
-    number: int
-    """[`int`] Residue number."""
-
-    name: str
-    """[`str`] Residue name."""
+    u_aniso: Optional[Tuple[float, float, float, float, float, float]]
+    """
+    [`(float, float, float, float, float, float) | None`] Anisotropic displacement
+    parameters U11, U22, U33, U12, U13 and U23 (in square Angstroms) from the ANISOU
+    record (None for atoms without one).
+    """
 
     # -----------------------------------------------------------------------------------------
     # Special methods
     # -----------------------------------------------------------------------------------------
 
-    def __getitem__(self, index: int) -> Atom: ...
+    def __reduce__(self) -> Tuple[Any, ...]: ...
 
-    def __iter__(self) -> 'Residue': ...
+    def __repr__(self) -> str:
+        """
+        Returns the concise representation with the name, number and position.
 
-    def __len__(self) -> int: ...
 
-    def __next__(self) -> Atom: ...
+        # Examples
+        ### Inspecting the atom in the interactive session.
 
-    def __repr__(self) -> str: ...
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> atom = structure[0][0][0]
+        ...
+        >>> atom
+
+        ``` raw
+        <Atom N 1: (42.854, 36.56, 10.394)>
+        ```
+        """
+
+    def __str__(self) -> str:
+        """
+        Returns the verbose representation with all fields.
+        """
 
     # -----------------------------------------------------------------------------------------
     # Methods
     # -----------------------------------------------------------------------------------------
 
-    def get_atoms(self) -> List[Atom]:
+    @staticmethod
+    def angle(a: Atom, b: Atom, c: Atom) -> float:
         """
-        Returns a list of atoms that builds the residue.
+        Returns the bond angle at the atom b between the atoms a and c.
+
+
+        # Parameters
+        `a` : Atom
+            The first atom.
+        `b` : Atom
+            The vertex atom.
+        `c` : Atom
+            The third atom.
 
 
         # Returns
-        `list[Atom]`:
-            The list of atoms that builds the residue.
+        `float`
+            The angle (in degrees, 0 to 180).
 
 
         # Examples
-        ### Retrieving the list of atoms that builds the residue.
+        ### Measuring the N-CA-C angle of a residue.
 
         >>> parser = nanoPDB.Parser()
         >>> structure = parser.fetch("1zhy")
-        >>> residue = structure[0][0]
+        >>> residue = structure[0][1]
         ...
-        >>> residue.get_atoms()
+        >>> nanoPDB.Atom.angle(residue.atom("N"), residue.atom("CA"), residue.atom("C"))
 
         ``` raw
-        [Atom {
-            label: "ATOM",
-            number: 1,
-            name: "N",
-            element: "N",
-            position: (
-                42.854,
-                36.56,
-                10.394,
-            ),
-            occupancy: 1.0,
-        }, Atom {
-            label: "ATOM",
-            number: 2,
-            name: "CA",
-            element: "C",
-            position: (
-                42.25,
-                35.232,
-                10.096,
-            ),
-            occupancy: 1.0,
-        }, Atom {
-            label: "ATOM",
-            number: 3,
-            name: "C",
-            element: "C",
-            position: (
-                41.642,
-                34.623,
-                11.355,
-            ),
-            occupancy: 1.0,
-        }
-        ...
+        107.74...
         ```
         """
 
+    def cpk_color(self) -> Tuple[int, int, int]:
+        """
+        Returns the CPK color of the atom's element.
 
-class Chain:
-    """
-    Chain - a class that represents a chain of a PDB structure.
-    """
+        Colors follow the RasMol CPK convention (carbon light gray, oxygen red, nitrogen
+        light blue, sulfur yellow, ...). Elements without a conventional color are deep pink.
 
-    name: str
-    """[`str`] Chain name."""
 
-    # -----------------------------------------------------------------------------------------
-    # Special methods
-    # -----------------------------------------------------------------------------------------
+        # Returns
+        `(int, int, int)`
+            The RGB color (0-255 components).
 
-    def __getitem__(self, index: int) -> Residue: ...
 
-    def __iter__(self) -> 'Chain': ...
+        # Examples
+        ### Retrieving the color of an atom.
 
-    def __len__(self) -> int: ...
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> atom = structure[0][0][0]
+        ...
+        >>> atom.cpk_color()
 
-    def __next__(self) -> Residue: ...
+        ``` raw
+        (143, 143, 255)
+        ```
+        """
 
-    def __repr__(self) -> str: ...
+    @staticmethod
+    def dihedral(a: Atom, b: Atom, c: Atom, d: Atom) -> float:
+        """
+        Returns the torsion (dihedral) angle of the atoms a, b, c and d around the b -> c bond.
 
-    # -----------------------------------------------------------------------------------------
-    # Methods
-    # -----------------------------------------------------------------------------------------
+        The sign follows the IUPAC convention: the angle is positive when, looking along the
+        b -> c bond, the a -> b bond has to be rotated clockwise (by less than 180 degrees) to
+        eclipse the c -> d bond. Cis arrangements are near 0, trans near 180 (or -180).
 
-    def get_atoms(self) -> List[Atom]:
-        """
-        Returns a list of atoms that builds the chain.
+
+        # Parameters
+        `a` : Atom
+            The first atom.
+        `b` : Atom
+            The second atom (first atom of the central bond).
+        `c` : Atom
+            The third atom (second atom of the central bond).
+        `d` : Atom
+            The fourth atom.
 
 
         # Returns
-        `list[Atom]`
-            The list of atoms that build the chain.
+        `float`
+            The torsion angle (in degrees, -180 to 180).
 
 
         # Examples
-        ### Retrieving the list of atoms that builds the chain.
+        ### Computing the phi torsion of the second residue.
 
         >>> parser = nanoPDB.Parser()
         >>> structure = parser.fetch("1zhy")
-        >>> chain = structure[0]
+        >>> previous, residue = structure[0][0], structure[0][1]
         ...
-        >>> chain.get_atoms()
+        >>> nanoPDB.Atom.dihedral(
+        ...     previous.atom("C"), residue.atom("N"), residue.atom("CA"), residue.atom("C")
+        ... )
 
         ``` raw
-        [Atom {
-            label: "ATOM",
-            number: 1,
-            name: "N",
-            element: "N",
-            position: (
-                42.854,
-                36.56,
-                10.394,
-            ),
-            occupancy: 1.0,
-        }, Atom {
-            label: "ATOM",
-            number: 2,
-            name: "CA",
-            element: "C",
-            position: (
-                42.25,
-                35.232,
-                10.096,
-            ),
-            occupancy: 1.0,
-        }, Atom {
-            label: "ATOM",
-            number: 3,
-            name: "C",
-            element: "C",
-            position: (
-                41.642,
-                34.623,
-                11.355,
-            ),
-            occupancy: 1.0,
-        }
-        ...
+        -79.71...
         ```
         """
 
-    def get_residues(self) -> List[Residue]:
+    def distance_to(self, other: Atom) -> float:
         """
-        Returns the list of residues that builds the chain.
+        Returns the distance between the atom and the other atom.
+
+
+        # Parameters
+        `other` : Atom
+            The other atom.
 
 
         # Returns
-        `list[Residue]`
-            The list of residues that builds the chain.
+        `float`
+            The distance (in Angstroms).
 
 
         # Examples
-        ### Retrieving the list of residues that builds the residue.
+        ### Measuring the N-CA bond length.
 
         >>> parser = nanoPDB.Parser()
         >>> structure = parser.fetch("1zhy")
-        >>> chain = structure[0]
+        >>> residue = structure[0][0]
         ...
-        >>> chain.get_residues()
+        >>> residue.atom("N").distance_to(residue.atom("CA"))
 
         ``` raw
-        [Residue {
-            number: -1,
-            name: "MET",
-        }, Residue {
-            number: 0,
-            name: "ASP",
-        }, Residue {
-            number: 1,
-            name: "PRO",
-        }
-        ...
+        1.489...
         ```
         """
 
+    def mass(self) -> Optional[float]:
+        """
+        Returns the standard atomic weight of the atom's element.
 
-class Structure:
-    """
-    Structure - a class that represents a PDB structure.
-    """
+        The element symbol is matched case-insensitively (e.g. "ZN" and "Zn"). Atoms with a
+        blank or unknown element return None rather than raising, so masses of whole
+        structures can skip them explicitly.
 
-    pdbid: str
-    """[`str`] PDB ID of a structure."""
 
-    classification: str
-    """[`str`] Classifications of a macromolecule."""
+        # Returns
+        `float | None`
+            The atomic mass (in daltons), None for unknown elements.
 
-    date: str
-    """[`str`] Deposition date."""
 
-    unit_cell: UnitCell
-    """[`UnitCell`] The unit cell of the structure."""
+        # Examples
+        ### Retrieving the mass of a nitrogen atom.
 
-    # -----------------------------------------------------------------------------------------
-    # Special methods
-    # -----------------------------------------------------------------------------------------
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> atom = structure[0][0][0]
+        ...
+        >>> atom.mass()
 
-    def __getitem__(self, index: int) -> Chain: ...
+        ``` raw
+        14.007
+        ```
+        """
 
-    def __iter__(self) -> 'Structure': ...
+    def set_b_factor(self, value: float) -> None:
+        """
+        Sets the temperature (B-)factor of the atom.
 
-    def __len__(self) -> int: ...
 
-    def __next__(self) -> Chain: ...
+        # Parameters
+        `value` : float
+            The new B-factor.
 
-    def __repr__(self) -> str: ...
 
-    # -----------------------------------------------------------------------------------------
-    # Methods
-    # -----------------------------------------------------------------------------------------
+        # Examples
+        ### Annotating an atom before exporting the structure.
 
-    def get_atoms(self) -> List[Atom]:
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> atom = structure[0][0][0]
+        ...
+        >>> atom.set_b_factor(50.0)
         """
-        Returns a list of atoms that builds the structure.
 
+    def set_occupancy(self, value: float) -> None:
+        """
+        Sets the occupancy of the atom.
 
-        # Returns
-        `list[Atom]`
-            The list of atoms that build the structure.
+
+        # Parameters
+        `value` : float
+            The new occupancy, a ValueError is raised when it is outside of [0.0, 1.0].
 
 
         # Examples
-        ### Retrieving the list of atoms that builds the structure.
+        ### Lowering the occupancy of an atom.
 
         >>> parser = nanoPDB.Parser()
         >>> structure = parser.fetch("1zhy")
+        >>> atom = structure[0][0][0]
         ...
-        >>> structure.get_atoms()
+        >>> atom.set_occupancy(0.5)
+        >>> atom.occupancy
 
         ``` raw
-        [Atom {
-            label: "ATOM",
-            number: 1,
-            name: "N",
-            element: "N",
-            position: (
-                42.854,
-                36.56,
-                10.394,
-            ),
-            occupancy: 1.0,
-        }, Atom {
-            label: "ATOM",
-            number: 2,
-            name: "CA",
-            element: "C",
-            position: (
-                42.25,
-                35.232,
-                10.096,
-            ),
-            occupancy: 1.0,
-        }, Atom {
-            label: "ATOM",
-            number: 3,
-            name: "C",
-            element: "C",
-            position: (
-                41.642,
-                34.623,
-                11.355,
-            ),
-            occupancy: 1.0,
-        }
-        ...
+        0.5
         ```
         """
 
-    def get_chains(self) -> List[Chain]:
+    def squared_distance_to(self, other: Atom) -> float:
         """
-        Returns the list of chains that builds the chain.
+        Returns the squared distance between the atom and the other atom.
+
+        Faster than distance_to (no square root), for comparing against a squared cutoff in
+        tight loops.
+
+
+        # Parameters
+        `other` : Atom
+            The other atom.
 
 
         # Returns
-        `list[Chain]`
-            The list of chains that builds the structure.
+        `float`
+            The squared distance (in square Angstroms).
 
 
         # Examples
-        ### Retrieving the list of chains that builds the structure.
+        ### Checking the N-CA contact against a 2.0 Angstrom cutoff.
 
         >>> parser = nanoPDB.Parser()
         >>> structure = parser.fetch("1zhy")
+        >>> residue = structure[0][0]
         ...
-        >>> structure.get_chains()
+        >>> residue.atom("N").squared_distance_to(residue.atom("CA")) < 2.0 ** 2
 
         ``` raw
-        [Chain {
-            name: 'A',
-        }]
+        True
         ```
         """
 
-    def get_residues(self) -> List[Residue]:
+    def vdw_radius(self) -> Optional[float]:
         """
-        Returns the list of residues that builds the structure.
+        Returns the van der Waals radius of the atom's element.
+
+        The element symbol is matched case-insensitively (e.g. "ZN" and "Zn"). Atoms with a
+        blank or unknown element return None rather than raising (largest_cavity_radius of
+        the structure falls back to the carbon radius for them).
 
 
         # Returns
-        `list[Residue]`
-            The list of residues that builds the structure.
+        `float | None`
+            The van der Waals radius (in Angstroms), None for unknown elements.
 
 
         # Examples
-        ### Retrieving the list of residues that builds the structure.
+        ### Retrieving the radius of a nitrogen atom.
 
         >>> parser = nanoPDB.Parser()
         >>> structure = parser.fetch("1zhy")
+        >>> atom = structure[0][0][0]
         ...
-        >>> structure.get_residues()
+        >>> atom.vdw_radius()
 
         ``` raw
-        [Residue {
-            number: -1,
-            name: "MET",
-        }, Residue {
-            number: 0,
-            name: "ASP",
-        }, Residue {
-            number: 1,
-            name: "PRO",
-        }
-        ...
+        1.55
         ```
         """
 
 
-class Parser:
+class Residue:
     """
-    Parser - a class for parsing structures in PDB format.
+    Residue - a class that represents a residue of a PDB structure.
     """
 
-    # -----------------------------------------------------------------------------------------
-    # Special methods
-    # -----------------------------------------------------------------------------------------
+    number: int
+    """[`int`] Residue number."""
 
-    def __init__(self) -> None: ...
+    insertion_code: Optional[str]
+    """[`str | None`] Residue insertion code (None when blank)."""
+
+    name: str
+    """[`str`] Residue name."""
 
     # -----------------------------------------------------------------------------------------
-    # Methods
+    # Special methods
     # -----------------------------------------------------------------------------------------
 
-    def fetch(self, pdbid: str) -> Structure:
-        """
-        Fetches structure from RCSB PDB database, parses it and returns Structure object.
+    def __contains__(self, name: str) -> bool: ...
 
+    def __getitem__(self, index: int) -> Atom: ...
 
-        # Parameters
-        `pdbid` : str
-            PDB ID of structure from RCSB PDB.
+    def __iter__(self) -> 'AtomIterator': ...
 
+    def __len__(self) -> int: ...
 
-        # Returns
-        `Structure`
-            Parsed structure.
+    def __reduce__(self) -> Tuple[Any, ...]: ...
+
+    def __repr__(self) -> str:
+        """
+        Returns the concise representation with the name, number (and insertion code)
+        and the count of atoms.
 
 
         # Examples
-        ### Fetching structure from RCSB PDB database.
+        ### Inspecting the residue in the interactive session.
 
         >>> parser = nanoPDB.Parser()
         >>> structure = parser.fetch("1zhy")
+        >>> residue = structure[0][0]
         ...
-        >>> structure
+        >>> residue
 
         ``` raw
-        Structure {
-            pdbid: "1ZHY",
-            classification: "LIPID BINDING PROTEIN",
+        <Residue MET -1: 8 atoms>
+        ```
+        """
+
+    def __str__(self) -> str:
+        """
+        Returns the verbose representation with the number (and insertion code) and name.
+        """
+
+    # -----------------------------------------------------------------------------------------
+    # Methods
+    # -----------------------------------------------------------------------------------------
+
+    def atom(self, name: str) -> Atom:
+        """
+        Returns the atom with the given name (e.g. "CA").
+
+        Surrounding whitespace is ignored, so " CA " and "CA" are the same name. The first
+        matching atom is returned (the first alternate location of disordered atoms). The
+        atom name is also checked by `name in residue`.
+
+
+        # Parameters
+        `name` : str
+            The atom name.
+
+
+        # Returns
+        `Atom`
+            The atom, a KeyError is raised if there is no such atom.
+
+
+        # Examples
+        ### Retrieving the alpha carbon of the residue.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> residue = structure[0][0]
+        ...
+        >>> residue.atom("CA").number
+
+        ``` raw
+        2
+        ```
+        """
+
+    def backbone(self) -> List[Atom]:
+        """
+        Returns the protein backbone atoms of the residue: N, CA, C and O (in that order).
+
+        Missing atoms are left out, so the list is shorter for incomplete residues (see
+        is_complete_backbone). Only amino acids (see is_amino_acid) have a backbone, the list
+        is empty for other residues (the backbone of nucleotides is not covered). The first
+        alternate location of disordered atoms is returned.
+
+
+        # Returns
+        `list[Atom]`
+            The list of the backbone atoms present in the residue.
+
+
+        # Examples
+        ### Retrieving the backbone atom names of the residue.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> residue = structure[0][0]
+        ...
+        >>> [atom.name for atom in residue.backbone()]
+
+        ``` raw
+        ['N', 'CA', 'C', 'O']
+        ```
+        """
+
+    def bounding_box(
+        self,
+    ) -> Tuple[Tuple[float, float, float], Tuple[float, float, float]]:
+        """
+        Returns the axis-aligned bounding box of the residue (computed in a single pass).
+
+        Useful for setting up grids, docking boxes and visualization viewports.
+
+
+        # Returns
+        `((float, float, float), (float, float, float))`
+            The minimum and maximum corners, a ValueError is raised for a residue without
+            atoms.
+
+
+        # Examples
+        ### Computing the bounding box of the residue.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> residue = structure[0][0]
+        ...
+        >>> residue.bounding_box()
+
+        ``` raw
+        ((41.642, 32.997, 9.357), (46.947, 36.56, 12.461))
+        ```
+        """
+
+    def center_of_geometry(self) -> Tuple[float, float, float]:
+        """
+        Returns the center of geometry (mean of the atom positions) of the residue.
+
+
+        # Returns
+        `(float, float, float)`
+            The center of geometry, a ValueError is raised for a residue without atoms.
+
+
+        # Examples
+        ### Computing the center of geometry of the residue.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> residue = structure[0][0]
+        ...
+        >>> residue.center_of_geometry()
+
+        ``` raw
+        (43.632..., 34.636, 10.422...)
+        ```
+        """
+
+    def center_of_mass(self) -> Tuple[float, float, float]:
+        """
+        Returns the center of mass of the residue.
+
+        Atoms are weighted by the atomic masses of their elements. Atoms of unknown elements
+        have no weight and are reported with a UserWarning.
+
+
+        # Returns
+        `(float, float, float)`
+            The center of mass, a ValueError is raised when no atom has a known mass.
+
+
+        # Examples
+        ### Computing the center of mass of the residue.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> residue = structure[0][0]
+        ...
+        >>> residue.center_of_mass()
+
+        ``` raw
+        (43.912..., 34.414..., 10.370...)
+        ```
+        """
+
+    def deduplicate_atoms(self) -> int:
+        """
+        Removes duplicated atoms (same name and exactly the same position) from the residue.
+
+        The first occurrence of every duplicated atom is kept.
+
+
+        # Returns
+        `int`
+            The number of removed atoms.
+
+
+        # Examples
+        ### Cleaning up the residue.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> residue = structure[0][0]
+        ...
+        >>> residue.deduplicate_atoms()
+
+        ``` raw
+        0
+        ```
+        """
+
+    def get_atoms(self) -> List[Atom]:
+        """
+        Returns a list of atoms that builds the residue.
+
+
+        # Returns
+        `list[Atom]`:
+            The list of atoms that builds the residue.
+
+
+        # Examples
+        ### Retrieving the list of atoms that builds the residue.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> residue = structure[0][0]
+        ...
+        >>> residue.get_atoms()
+
+        ``` raw
+        [<Atom N 1: (42.854, 36.56, 10.394)>,
+         <Atom CA 2: (42.25, 35.232, 10.096)>,
+         <Atom C 3: (41.642, 34.623, 11.355)>,
+        ...
+        ```
+        """
+
+    def hydrophobicity(self) -> Optional[float]:
+        """
+        Returns the Kyte-Doolittle hydropathy of the residue.
+
+        Modified amino acids (e.g. MSE) take the value of their parent amino acid.
+
+
+        # Returns
+        `float | None`
+            The hydropathy score, None for residues that are not amino acids.
+
+
+        # Examples
+        ### Retrieving the hydropathy of the residue.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> residue = structure[0][0]
+        ...
+        >>> residue.hydrophobicity()
+
+        ``` raw
+        1.9
+        ```
+        """
+
+    def is_amino_acid(self) -> bool:
+        """
+        Checks whether the residue is an amino acid (one of the 20 standard ones, SEC, PYL or a
+        modified amino acid like MSE or SEP).
+
+
+        # Returns
+        `bool`
+            True if the residue is an amino acid.
+
+
+        # Examples
+        ### Checking the first residue.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure[0][0].is_amino_acid()
+
+        ``` raw
+        True
+        ```
+        """
+
+    def is_complete_backbone(self) -> bool:
+        """
+        Checks whether the residue is an amino acid with all backbone atoms (N, CA, C and O).
+
+
+        # Returns
+        `bool`
+            True if none of the backbone atoms is missing, False for residues other than
+            amino acids.
+
+
+        # Examples
+        ### Counting the residues with complete backbones.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> sum(residue.is_complete_backbone() for residue in structure.residues())
+
+        ``` raw
+        436
+        ```
+        """
+
+    def is_nucleic(self) -> bool:
+        """
+        Checks whether the residue is a nucleotide (A, C, G, U of RNA or DA, DC, DG, DT, DU of
+        DNA).
+
+
+        # Returns
+        `bool`
+            True if the residue is a nucleotide.
+
+
+        # Examples
+        ### Checking the first residue.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure[0][0].is_nucleic()
+
+        ``` raw
+        False
+        ```
+        """
+
+    def is_water(self) -> bool:
+        """
+        Checks whether the residue is a water (HOH, WAT or DOD, in any case).
+
+
+        # Returns
+        `bool`
+            True if the residue is a water.
+
+
+        # Examples
+        ### Checking the last residue of the structure.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.get_residues()[-1].is_water()
+
+        ``` raw
+        True
+        ```
+        """
+
+    def kind(self) -> str:
+        """
+        Returns the kind of the residue, based on its name.
+
+        Amino acids (standard and modified, e.g. MSE or SEP), nucleotides (DNA and RNA) and
+        waters are recognized by their names, any other residue (ions, cofactors, small
+        molecules) is a ligand.
+
+
+        # Returns
+        `str`
+            "amino_acid", "nucleotide", "water" or "ligand".
+
+
+        # Examples
+        ### Counting the kinds of residues.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> collections.Counter(residue.kind() for residue in structure.residues())
+
+        ``` raw
+        Counter({'amino_acid': 436, 'water': 262, 'ligand': 3})
+        ```
+        """
+
+    def molecular_weight(
+        self, include_water: bool = False, include_hydrogens: bool = True
+    ) -> float:
+        """
+        Returns the molecular weight of the residue, the sum of the atomic masses of its atoms.
+
+        Only the atoms present in the file are counted, missing hydrogens are not added
+        implicitly. Atoms of unknown elements have no weight and are reported with a
+        UserWarning. Waters weigh 0.0 unless included.
+
+
+        # Parameters
+        `include_water` : bool
+            Whether water molecules are counted (default False).
+        `include_hydrogens` : bool
+            Whether hydrogen (and deuterium) atoms are counted (default True).
+
+
+        # Returns
+        `float`
+            The molecular weight (in daltons).
+
+
+        # Examples
+        ### Weighing the first residue.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure[0][0].molecular_weight()
+
+        ``` raw
+        122.121
+        ```
+        """
+
+
+class Chain:
+    """
+    Chain - a class that represents a chain of a PDB structure.
+    """
+
+    name: str
+    """[`str`] Chain name."""
+
+    # -----------------------------------------------------------------------------------------
+    # Special methods
+    # -----------------------------------------------------------------------------------------
+
+    def __contains__(self, number: int) -> bool: ...
+
+    def __getitem__(self, index: int) -> Residue: ...
+
+    def __iter__(self) -> 'ResidueIterator': ...
+
+    def __len__(self) -> int: ...
+
+    def __reduce__(self) -> Tuple[Any, ...]: ...
+
+    def __repr__(self) -> str:
+        """
+        Returns the concise representation with the name and the count of residues.
+
+
+        # Examples
+        ### Inspecting the chain in the interactive session.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> chain = structure[0]
+        ...
+        >>> chain
+
+        ``` raw
+        <Chain A: 701 residues>
+        ```
+        """
+
+    def __str__(self) -> str:
+        """
+        Returns the verbose representation with the name.
+        """
+
+    # -----------------------------------------------------------------------------------------
+    # Methods
+    # -----------------------------------------------------------------------------------------
+
+    def bounding_box(
+        self,
+    ) -> Tuple[Tuple[float, float, float], Tuple[float, float, float]]:
+        """
+        Returns the axis-aligned bounding box of the chain (computed in a single pass).
+
+        Useful for setting up grids, docking boxes and visualization viewports.
+
+
+        # Returns
+        `((float, float, float), (float, float, float))`
+            The minimum and maximum corners, a ValueError is raised for a chain without
+            atoms.
+
+
+        # Examples
+        ### Computing the bounding box of the chain.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> chain = structure[0]
+        ...
+        >>> chain.bounding_box()
+
+        ``` raw
+        ((30.841, 6.707, 3.466), (91.927, 64.985, 75.795))
+        ```
+        """
+
+    def center_of_geometry(self) -> Tuple[float, float, float]:
+        """
+        Returns the center of geometry (mean of the atom positions) of the chain.
+
+
+        # Returns
+        `(float, float, float)`
+            The center of geometry, a ValueError is raised for a chain without atoms.
+
+
+        # Examples
+        ### Computing the center of geometry of the chain.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> chain = structure[0]
+        ...
+        >>> chain.center_of_geometry()
+
+        ``` raw
+        (57.025..., 36.565..., 39.136...)
+        ```
+        """
+
+    def center_of_mass(self) -> Tuple[float, float, float]:
+        """
+        Returns the center of mass of the chain.
+
+        Atoms are weighted by the atomic masses of their elements. Atoms of unknown elements
+        have no weight and are reported with a UserWarning.
+
+
+        # Returns
+        `(float, float, float)`
+            The center of mass, a ValueError is raised when no atom has a known mass.
+
+
+        # Examples
+        ### Computing the center of mass of the chain.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> chain = structure[0]
+        ...
+        >>> chain.center_of_mass()
+
+        ``` raw
+        (57.096..., 36.546..., 39.111...)
+        ```
+        """
+
+    def get_atoms(self) -> List[Atom]:
+        """
+        Returns a list of atoms that builds the chain.
+
+
+        # Returns
+        `list[Atom]`
+            The list of atoms that build the chain.
+
+
+        # Examples
+        ### Retrieving the list of atoms that builds the chain.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> chain = structure[0]
+        ...
+        >>> chain.get_atoms()
+
+        ``` raw
+        [<Atom N 1: (42.854, 36.56, 10.394)>,
+         <Atom CA 2: (42.25, 35.232, 10.096)>,
+         <Atom C 3: (41.642, 34.623, 11.355)>,
+        ...
+        ```
+        """
+
+    def get_residues(self) -> List[Residue]:
+        """
+        Returns the list of residues that builds the chain.
+
+
+        # Returns
+        `list[Residue]`
+            The list of residues that builds the chain.
+
+
+        # Examples
+        ### Retrieving the list of residues that builds the residue.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> chain = structure[0]
+        ...
+        >>> chain.get_residues()
+
+        ``` raw
+        [<Residue MET -1: 8 atoms>, <Residue ASP 0: 8 atoms>, <Residue PRO 1: 7 atoms>,
+        ...
+        ```
+        """
+
+    def hydrophobicity_profile(self) -> List[Tuple[int, float]]:
+        """
+        Returns the Kyte-Doolittle hydropathy profile of the chain.
+
+        Residues that are not amino acids (waters, ligands, nucleotides) are skipped.
+
+
+        # Returns
+        `list[(int, float)]`
+            The list of (residue number, hydropathy score) tuples.
+
+
+        # Examples
+        ### Retrieving the hydropathy profile of the chain.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> chain = structure[0]
+        ...
+        >>> chain.hydrophobicity_profile()
+
+        ``` raw
+        [(-1, 1.9), (0, -3.5), (1, -1.6), (2, -0.8), (3, -3.5), ...]
+        ```
+        """
+
+    def molecular_weight(
+        self, include_water: bool = False, include_hydrogens: bool = True
+    ) -> float:
+        """
+        Returns the molecular weight of the chain, the sum of the atomic masses of its atoms.
+
+        Only the atoms present in the file are counted, missing hydrogens are not added
+        implicitly. Atoms of unknown elements have no weight and are reported with a
+        UserWarning.
+
+
+        # Parameters
+        `include_water` : bool
+            Whether water molecules are counted (default False).
+        `include_hydrogens` : bool
+            Whether hydrogen (and deuterium) atoms are counted (default True).
+
+
+        # Returns
+        `float`
+            The molecular weight (in daltons).
+
+
+        # Examples
+        ### Weighing the chain with its waters.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> chain = structure[0]
+        ...
+        >>> round(chain.molecular_weight(True), 1)
+
+        ``` raw
+        51151.0
+        ```
+        """
+
+    def net_charge(self) -> int:
+        """
+        Returns the net formal charge of the chain.
+
+        Charges are the formal charges read from columns 79-80 of the PDB file, atoms with a
+        blank charge field are neutral.
+
+
+        # Returns
+        `int`
+            The sum of the formal charges of the atoms in the chain.
+
+
+        # Examples
+        ### Computing the net charge of the chain (1ZHY has no charges in the file).
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> chain = structure[0]
+        ...
+        >>> chain.net_charge()
+
+        ``` raw
+        0
+        ```
+        """
+
+    def nucleotide_sequence(self) -> str:
+        """
+        Returns the nucleic acid sequence of the chain.
+
+        Nucleotides (DA, DC, DG, DT, DU, A, C, G, U) are mapped to the one-letter base codes.
+        Other polymer (ATOM) residues are reported as 'N', non-polymer residues (waters,
+        ligands) are skipped.
+
+
+        # Returns
+        `str`
+            The base sequence.
+
+
+        # Examples
+        ### Retrieving the sequence of a DNA strand.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1bna")
+        >>> chain = structure[0]
+        ...
+        >>> chain.nucleotide_sequence()
+
+        ``` raw
+        'CGCGAATTCGCG'
+        ```
+        """
+
+    def num_atoms(self) -> int:
+        """
+        Returns the number of atoms that builds the chain.
+
+
+        # Returns
+        `int`
+            The number of atoms in the chain.
+
+
+        # Examples
+        ### Counting the atoms of the chain.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> chain = structure[0]
+        ...
+        >>> chain.num_atoms()
+
+        ``` raw
+        3806
+        ```
+        """
+
+    def num_residues(self) -> int:
+        """
+        Returns the number of residues that builds the chain (same as len(chain)).
+
+
+        # Returns
+        `int`
+            The number of residues in the chain.
+
+
+        # Examples
+        ### Counting the residues of the chain.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> chain = structure[0]
+        ...
+        >>> chain.num_residues()
+
+        ``` raw
+        701
+        ```
+        """
+
+    def phi_psi(self) -> List[Tuple[int, Optional[float], Optional[float]]]:
+        """
+        Returns the phi and psi backbone torsions of the residues of the chain.
+
+        The torsions are computed from the N, CA and C atoms of consecutive residues: phi from
+        C(i-1), N, CA, C and psi from N, CA, C, N(i+1). Residues lacking any of the N, CA, C
+        atoms are skipped (and leave the torsions of their neighbours toward them undefined).
+        A chain break, two consecutive residues with the C -> N distance over 2.0 Angstroms,
+        is treated like the chain end: the phi of the first residue of the chain (or after a
+        break) and the psi of the last residue (or before a break) are None.
+
+
+        # Returns
+        `list[(int, float | None, float | None)]`
+            The list of (residue number, phi, psi) tuples, angles in degrees (-180 to 180).
+
+
+        # Examples
+        ### Retrieving the torsions of the first residues.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> chain = structure[0]
+        ...
+        >>> chain.phi_psi()[:3]
+
+        ``` raw
+        [(-1, None, 169.69...), (0, -79.71..., 148.10...), (1, -53.91..., -28.21...)]
+        ```
+        """
+
+    def polymer_type(self) -> str:
+        """
+        Returns the type of the polymer that builds the chain.
+
+        Residues are classified by name as amino acids (standard or modified), ribonucleotides
+        (A, C, G, U) or deoxyribonucleotides (DA, DC, DG, DT, DU). Non-polymer (HETATM only)
+        residues with other names, like waters and ligands, are ignored. A chain built only of
+        amino acids is "protein", only of deoxyribonucleotides "dna" and only of ribonucleotides
+        "rna". Mixed chains, chains with unknown polymer residues and chains without any polymer
+        residue are "other".
+
+
+        # Returns
+        `str`
+            One of "protein", "dna", "rna" or "other".
+
+
+        # Examples
+        ### Checking the polymer type of the chain.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> chain = structure[0]
+        ...
+        >>> chain.polymer_type()
+
+        ``` raw
+        'protein'
+        ```
+        """
+
+    def residue(self, number: int, insertion_code: Optional[str] = None) -> Residue:
+        """
+        Returns the residue with the given number (and insertion code).
+
+        Unlike positional indexing, the lookup is not affected by missing residues (e.g.
+        disordered loops). The insertion code tells apart residues with the same number (e.g.
+        100 and 100A), without it only a residue with no insertion code matches. The
+        residue number is also checked by `number in chain`.
+
+
+        # Parameters
+        `number` : int
+            The residue number.
+        `insertion_code` : str, optional
+            The insertion code of the residue, None for residues without one.
+
+
+        # Returns
+        `Residue`
+            The residue, a KeyError is raised if there is no such residue.
+
+
+        # Examples
+        ### Retrieving the residue number 10 of the chain.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> chain = structure[0]
+        ...
+        >>> chain.residue(10)
+
+        ``` raw
+        <Residue TRP 10: 14 atoms>
+        ```
+        """
+
+    def seqres(self) -> List[str]:
+        """
+        Returns the residue names of the SEQRES records of the chain.
+
+        The SEQRES records list the sequence of the whole chain, including the residues that
+        were not modeled (e.g. disordered loops and termini), so comparing it with the
+        residues of the chain reveals the gaps. Empty for chains without SEQRES records (and
+        for mmCIF files).
+
+
+        # Returns
+        `list[str]`
+            The list of three-letter residue names.
+
+
+        # Examples
+        ### Counting the residues missing from the coordinates.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> chain = structure[0]
+        ...
+        >>> len(chain.seqres()) - sum(residue.is_amino_acid() for residue in chain)
+
+        ``` raw
+        2
+        ```
+        """
+
+    def sequence(self) -> str:
+        """
+        Returns the one-letter sequence of the chain.
+
+        The 20 standard amino acids (and SEC, PYL) are mapped to their one-letter codes,
+        common modified amino acids (e.g. MSE, SEP, HYP) to the codes of their parents and
+        nucleotides to the base codes. Other polymer (ATOM) residues are reported as 'X',
+        non-polymer residues (waters, ligands) are skipped.
+
+
+        # Returns
+        `str`
+            The one-letter sequence.
+
+
+        # Examples
+        ### Retrieving the beginning of the sequence of the chain.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> chain = structure[0]
+        ...
+        >>> chain.sequence()[:10]
+
+        ``` raw
+        'MDPSQYASSS'
+        ```
+        """
+
+    def sequence_hash(self) -> str:
+        """
+        Returns the checksum of the one-letter sequence of the chain.
+
+        The checksum is the CRC-64 (ISO 3309 polynomial, as used by SWISS-PROT/UniProt) of
+        the ASCII one-letter sequence, formatted as 16 uppercase hexadecimal digits. Chains
+        with equal sequences have equal checksums, so they can be compared across files
+        without comparing whole sequences.
+
+
+        # Returns
+        `str`
+            The sequence checksum.
+
+
+        # Examples
+        ### Computing the sequence checksum of the chain.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> chain = structure[0]
+        ...
+        >>> chain.sequence_hash()
+
+        ``` raw
+        'DF6AEF59DCD2892F'
+        ```
+        """
+
+
+class AtomIterator:
+    """
+    AtomIterator - an iterator over the atoms of a residue.
+    """
+
+    # -----------------------------------------------------------------------------------------
+    # Special methods
+    # -----------------------------------------------------------------------------------------
+
+    def __iter__(self) -> 'AtomIterator': ...
+
+    def __next__(self) -> Atom: ...
+
+
+class ResidueIterator:
+    """
+    ResidueIterator - an iterator over the residues of a chain.
+    """
+
+    # -----------------------------------------------------------------------------------------
+    # Special methods
+    # -----------------------------------------------------------------------------------------
+
+    def __iter__(self) -> 'ResidueIterator': ...
+
+    def __next__(self) -> Residue: ...
+
+
+class ChainIterator:
+    """
+    ChainIterator - an iterator over the chains of a structure.
+    """
+
+    # -----------------------------------------------------------------------------------------
+    # Special methods
+    # -----------------------------------------------------------------------------------------
+
+    def __iter__(self) -> 'ChainIterator': ...
+
+    def __next__(self) -> Chain: ...
+
+
+class ElementIterator:
+    """
+    ElementIterator - a lazy iterator over the atoms of one chemical element.
+    """
+
+    # -----------------------------------------------------------------------------------------
+    # Special methods
+    # -----------------------------------------------------------------------------------------
+
+    def __iter__(self) -> 'ElementIterator': ...
+
+    def __next__(self) -> Atom: ...
+
+
+class StructureAtomIterator:
+    """
+    StructureAtomIterator - a lazy iterator over all atoms of a structure (in file order).
+    """
+
+    # -----------------------------------------------------------------------------------------
+    # Special methods
+    # -----------------------------------------------------------------------------------------
+
+    def __iter__(self) -> 'StructureAtomIterator': ...
+
+    def __next__(self) -> Atom: ...
+
+
+class StructureResidueIterator:
+    """
+    StructureResidueIterator - a lazy iterator over all residues of a structure (in file
+    order).
+    """
+
+    # -----------------------------------------------------------------------------------------
+    # Special methods
+    # -----------------------------------------------------------------------------------------
+
+    def __iter__(self) -> 'StructureResidueIterator': ...
+
+    def __next__(self) -> Residue: ...
+
+
+class NeighborSearch:
+    """
+    NeighborSearch - a spatial index over the atoms of a structure, built once and queried
+    repeatedly.
+    """
+
+    # -----------------------------------------------------------------------------------------
+    # Special methods
+    # -----------------------------------------------------------------------------------------
+
+    def __len__(self) -> int: ...
+
+    # -----------------------------------------------------------------------------------------
+    # Methods
+    # -----------------------------------------------------------------------------------------
+
+    def atoms_within(self, center: Tuple[float, float, float], radius: float) -> List[Atom]:
+        """
+        Returns the atoms within the radius of a point.
+
+        The atoms are returned in file order, including the atoms exactly at the radius. The
+        positions are the ones at the time the index was built, so the index has to be built
+        again after the structure is moved.
+
+
+        # Parameters
+        `center` : (float, float, float)
+            The point to search around.
+        `radius` : float
+            The search radius (in Angstroms).
+
+
+        # Returns
+        `list[Atom]`
+            The list of atoms within the radius.
+
+
+        # Examples
+        ### Querying the same index around several points.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> search = structure.neighbor_search()
+        ...
+        >>> [len(search.atoms_within(atom.position, 5.0)) for atom in structure[0][0]]
+
+        ``` raw
+        [12, 17, 21, 20, 19, 24, 25, 14]
+        ```
+        """
+
+    def neighbors(self, atom: Atom, radius: float) -> List[Atom]:
+        """
+        Returns the atoms within the radius of an atom, without the atom itself.
+
+
+        # Parameters
+        `atom` : Atom
+            The atom to search around (of the indexed structure or any other).
+        `radius` : float
+            The search radius (in Angstroms).
+
+
+        # Returns
+        `list[Atom]`
+            The list of neighboring atoms (in file order).
+
+
+        # Examples
+        ### Finding the atoms around the first atom.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> search = structure.neighbor_search()
+        ...
+        >>> [atom.name for atom in search.neighbors(structure[0][0][0], 2.0)]
+
+        ``` raw
+        ['CA']
+        ```
+        """
+
+
+class Model:
+    """
+    Model - a class that represents a model (e.g. a frame of an NMR ensemble) of a PDB
+    structure.
+    """
+
+    number: int
+    """[`int`] Model number (from the MODEL record, 1 for files without MODEL records)."""
+
+    # -----------------------------------------------------------------------------------------
+    # Special methods
+    # -----------------------------------------------------------------------------------------
+
+    def __getitem__(self, index: int) -> Chain: ...
+
+    def __len__(self) -> int: ...
+
+    def __repr__(self) -> str:
+        """
+        Returns the concise representation with the number and the count of chains.
+
+
+        # Examples
+        ### Inspecting the model in the interactive session.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> model = structure.models[0]
+        ...
+        >>> model
+
+        ``` raw
+        <Model 1: 1 chains>
+        ```
+        """
+
+    def __str__(self) -> str:
+        """
+        Returns the verbose representation with the number.
+        """
+
+    # -----------------------------------------------------------------------------------------
+    # Methods
+    # -----------------------------------------------------------------------------------------
+
+    def get_chains(self) -> List[Chain]:
+        """
+        Returns a list of chains that builds the model.
+
+
+        # Returns
+        `list[Chain]`
+            The list of chains that builds the model.
+
+
+        # Examples
+        ### Retrieving the chains of the second model of an NMR ensemble.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1d3z")
+        ...
+        >>> structure.model(1).get_chains()
+
+        ``` raw
+        [<Chain A: 76 residues>]
+        ```
+        """
+
+
+class Structure:
+    """
+    Structure - a class that represents a PDB structure.
+    """
+
+    pdbid: str
+    """[`str`] PDB ID of a structure."""
+
+    classification: str
+    """[`str`] Classifications of a macromolecule."""
+
+    date: str
+    """[`str`] Deposition date."""
+
+    title: str
+    """
+    [`str`] Title of the experiment or analysis (the text of all TITLE records, continuation
+    records joined with single spaces).
+    """
+
+    experimental_method: str
+    """[`str`] Experimental technique used for the structure determination."""
+
+    resolution: Optional[float]
+    """[`float | None`] Resolution (in Angstroms), None if not applicable."""
+
+    models: List[Model]
+    """
+    [`list[Model]`] Models of the structure (a single model for files without MODEL
+    records). The first model shares its chains with the structure.
+    """
+
+    bonds: List[Tuple[int, int]]
+    """
+    [`list[(int, int)]`] Bonds from the CONECT records, as pairs of atom serial numbers
+    (smaller first, sorted).
+    """
+
+    warnings: List[str]
+    """
+    [`list[str]`] Records skipped by lenient parsing (with strict disabled), with the line
+    numbers and the reasons.
+    """
+
+    unit_cell: UnitCell
+    """[`UnitCell`] The unit cell of the structure."""
+
+    # -----------------------------------------------------------------------------------------
+    # Special methods
+    # -----------------------------------------------------------------------------------------
+
+    def __copy__(self) -> Structure: ...
+
+    def __deepcopy__(self, memo: Dict[int, Any]) -> Structure: ...
+
+    def __getitem__(self, key: Union[int, str]) -> Chain: ...
+
+    def __iter__(self) -> 'ChainIterator': ...
+
+    def __len__(self) -> int:
+        """
+        Returns the number of models (1 for files without MODEL records). Indexing and
+        iteration go over the chains of the first model, so len(structure) may differ from
+        the number of chains (see num_chains).
+
+
+        # Examples
+        ### Counting the models of an NMR ensemble.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1d3z")
+        ...
+        >>> len(structure), structure.num_chains()
+
+        ``` raw
+        (10, 1)
+        ```
+        """
+
+    def __reduce__(self) -> Tuple[Any, ...]: ...
+
+    def __repr__(self) -> str:
+        """
+        Returns the concise representation with the PDB ID (left out when empty) and the
+        counts of chains and atoms. The atom count is cached, so the representation of
+        large structures is cheap to show repeatedly.
+
+
+        # Examples
+        ### Inspecting the structure in the interactive session.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure
+
+        ``` raw
+        <Structure 1ZHY: 1 chains, 3806 atoms>
+        ```
+        """
+
+    def __str__(self) -> str:
+        """
+        Returns the verbose representation with the header fields.
+        """
+
+    # -----------------------------------------------------------------------------------------
+    # Methods
+    # -----------------------------------------------------------------------------------------
+
+    def asphericity(self) -> float:
+        """
+        Returns the relative asphericity of the structure.
+
+        Computed from the eigenvalues l1 <= l2 <= l3 of the gyration tensor of the atom
+        positions as (l3 - (l1 + l2) / 2) / (l1 + l2 + l3), the asphericity normalized by the
+        squared radius of gyration. It is 0.0 for spherically symmetric structures and 1.0 for
+        rods. A ValueError is raised when the structure has no extent (no atoms or all atoms
+        in one place).
+
+
+        # Returns
+        `float`
+            The relative asphericity (dimensionless, range 0.0-1.0).
+
+
+        # Examples
+        ### Describing the shape of the structure.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.asphericity()
+
+        ``` raw
+        0.2306...
+        ```
+        """
+
+    def atom_tuples(self) -> List[Tuple[int, str, str, int, str, float, float, float]]:
+        """
+        Returns the atoms of the structure as plain tuples.
+
+        The tuples are built in a single walk over the structure, without creating Atom
+        objects on the Python side, which makes this a lightweight way of getting the whole
+        structure as plain Python data.
+
+
+        # Returns
+        `list[(int, str, str, int, str, float, float, float)]`
+            The list of (atom number, atom name, residue name, residue number, chain name,
+            x, y, z) tuples.
+
+
+        # Examples
+        ### Retrieving the atoms as tuples.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.atom_tuples()
+
+        ``` raw
+        [(1, 'N', 'MET', -1, 'A', 42.854, 36.56, 10.394),
+         (2, 'CA', 'MET', -1, 'A', 42.25, 35.232, 10.096),
+         (3, 'C', 'MET', -1, 'A', 41.642, 34.623, 11.355),
+        ...
+        ```
+        """
+
+    def atoms(self) -> 'StructureAtomIterator':
+        """
+        Returns a lazy iterator over all atoms of the structure (in file order).
+
+        The atoms of all chains and residues are yielded one at a time, replacing the nested
+        loops over chains, residues and atoms. No list is built (unlike get_atoms).
+
+
+        # Returns
+        `StructureAtomIterator`
+            The iterator over the atoms of the structure.
+
+
+        # Examples
+        ### Extracting the coordinates of all atoms.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> positions = [atom.position for atom in structure.atoms()]
+        >>> positions[:2]
+
+        ``` raw
+        [(42.854, 36.56, 10.394), (42.25, 35.232, 10.096)]
+        ```
+        """
+
+    def atoms_within(self, center: Tuple[float, float, float], radius: float) -> List[Atom]:
+        """
+        Returns the atoms within the radius of a point.
+
+        A neighbor grid is built for the single query, use neighbor_search to build it once
+        and query it repeatedly. The atoms are returned in file order, including the atoms
+        exactly at the radius.
+
+
+        # Parameters
+        `center` : (float, float, float)
+            The point to search around.
+        `radius` : float
+            The search radius (in Angstroms).
+
+
+        # Returns
+        `list[Atom]`
+            The list of atoms within the radius.
+
+
+        # Examples
+        ### Finding the atoms around the center of the structure.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> atoms = structure.atoms_within(structure.center_of_geometry(), 6.0)
+        >>> len(atoms)
+
+        ``` raw
+        44
+        ```
+        """
+
+    def average_altlocs(self) -> 'Structure':
+        """
+        Returns a new structure with alternate conformations merged into single atoms.
+
+        Alternate locations of an atom (atoms of a residue sharing the same name, all with an
+        alternate location indicator) are replaced with a single atom without the indicator,
+        placed at the occupancy-weighted average position (plain average when all occupancies
+        are zero) and with the B-factor averaged the same way. The occupancy of the merged atom
+        is the sum of the conformer occupancies capped at 1.0, other fields are taken from the
+        first conformer. The original structure is not modified.
+
+
+        # Returns
+        `Structure`
+            The structure with averaged alternate conformations.
+
+
+        # Examples
+        ### Averaging alternate conformations.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.num_atoms(), structure.average_altlocs().num_atoms()
+
+        ``` raw
+        (3806, 3806)
+        ```
+        """
+
+    def base_pairs(self, cutoff: float = 3.5) -> List[Tuple[int, int]]:
+        """
+        Returns the likely Watson-Crick base pairs of the nucleic acid chains.
+
+        This is a distance heuristic: a purine (A, G) and a pyrimidine (C, T, U) are paired when
+        the bases are complementary (A with T or U, G with C) and the purine N1 atom lies within
+        the cutoff of the pyrimidine N3 atom. Every base is in at most one pair: candidates are
+        accepted in order of increasing distance, skipping bases that are already paired. Each
+        pair is reported once, with the residue appearing first in the file first.
+
+
+        # Parameters
+        `cutoff` : float
+            The maximum N1-N3 distance (in Angstroms, default 3.5).
+
+
+        # Returns
+        `list[(int, int)]`
+            The list of paired residue numbers.
+
+
+        # Examples
+        ### Finding the base pairs of a DNA double helix.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1bna")
+        ...
+        >>> structure.base_pairs()
+
+        ``` raw
+        [(1, 24), (2, 23), (3, 22), (4, 21), (5, 20), (6, 19), ...]
+        ```
+        """
+
+    def binding_site(self, ligand_resname: str, radius: float) -> 'Structure':
+        """
+        Returns the binding site of a ligand as a new structure.
+
+        The ligand is every HETATM residue with the given name. The binding site consists of
+        the ligand and all residues (including waters and other ligands) with at least one
+        atom within the radius of any ligand atom. A ValueError is raised when no such ligand
+        is found. The original structure is not modified.
+
+
+        # Parameters
+        `ligand_resname` : str
+            The residue name of the ligand.
+        `radius` : float
+            The distance from the ligand atoms in Angstroms.
+
+
+        # Returns
+        `Structure`
+            The structure with the ligand and the surrounding residues.
+
+
+        # Examples
+        ### Extracting the cholesterol binding pocket.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> pocket = structure.binding_site("CLR", 5.0)
+        >>> pocket.num_atoms()
+
+        ``` raw
+        255
+        ```
+        """
+
+    def bonded_to(self, serial: int) -> List[int]:
+        """
+        Returns the serial numbers of the atoms bonded to the atom (from the CONECT records).
+
+
+        # Parameters
+        `serial` : int
+            The serial number of the atom.
+
+
+        # Returns
+        `list[int]`
+            The sorted serial numbers of the bonded atoms (empty when the atom has no bonds).
+
+
+        # Examples
+        ### Finding the neighbors of a cholesterol atom.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.bonded_to(3542)
+
+        ``` raw
+        [3541, 3543, 3544]
+        ```
+        """
+
+    def bounding_box(
+        self,
+    ) -> Tuple[Tuple[float, float, float], Tuple[float, float, float]]:
+        """
+        Returns the axis-aligned bounding box of the structure (computed in a single pass).
+
+        Useful for setting up grids, docking boxes and visualization viewports.
+
+
+        # Returns
+        `((float, float, float), (float, float, float))`
+            The minimum and maximum corners, a ValueError is raised for a structure without
+            atoms.
+
+
+        # Examples
+        ### Computing the bounding box of the structure.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.bounding_box()
+
+        ``` raw
+        ((30.841, 6.707, 3.466), (91.927, 64.985, 75.795))
+        ```
+        """
+
+    def center_of_geometry(self) -> Tuple[float, float, float]:
+        """
+        Returns the center of geometry (mean of the atom positions) of the structure.
+
+
+        # Returns
+        `(float, float, float)`
+            The center of geometry, a ValueError is raised for a structure without atoms.
+
+
+        # Examples
+        ### Computing the center of geometry of the structure.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.center_of_geometry()
+
+        ``` raw
+        (57.025..., 36.565..., 39.136...)
+        ```
+        """
+
+    def center_of_mass(self) -> Tuple[float, float, float]:
+        """
+        Returns the center of mass of the structure.
+
+        Atoms are weighted by the atomic masses of their elements. Atoms of unknown elements
+        have no weight and are reported with a UserWarning.
+
+
+        # Returns
+        `(float, float, float)`
+            The center of mass, a ValueError is raised when no atom has a known mass.
+
+
+        # Examples
+        ### Computing the center of mass of the structure.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.center_of_mass()
+
+        ``` raw
+        (57.096..., 36.546..., 39.111...)
+        ```
+        """
+
+    def center_on(self, atoms: List[Atom]) -> None:
+        """
+        Translates the whole structure so the center of the selected atoms is at the origin.
+
+        The center is the center of geometry (plain mean of the positions) of the given atoms,
+        all atoms of the structure are moved by the same vector.
+
+
+        # Parameters
+        `atoms` : list[Atom]
+            The selected atoms (at least one).
+
+
+        # Examples
+        ### Centering the structure on the gamma atoms.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.center_on(structure.select_atoms_glob("*G*"))
+        """
+
+    def chain(self, name: str) -> Chain:
+        """
+        Returns the chain with the given name (chain ID), same as structure[name].
+
+
+        # Parameters
+        `name` : str
+            The chain name (a single character).
+
+
+        # Returns
+        `Chain`
+            The chain with the name, a KeyError is raised if there is no such chain.
+
+
+        # Examples
+        ### Retrieving the chain A of the structure.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.chain("A")
+
+        ``` raw
+        <Chain A: 701 residues>
+        ```
+        """
+
+    def chain_com_distances(self) -> List[Tuple[Tuple[str, str], float]]:
+        """
+        Returns the distances between the centers of mass of every pair of chains.
+
+        Atomic masses are looked up by element, atoms of unknown elements have no weight.
+        Chains without any atom of known mass are skipped.
+
+
+        # Returns
+        `list[((str, str), float)]`
+            The list of ((chain name, chain name), distance) tuples.
+
+
+        # Examples
+        ### Measuring the distances between chains.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("4hhb")
+        ...
+        >>> structure.chain_com_distances()
+
+        ``` raw
+        [(('A', 'B'), ...), (('A', 'C'), ...), (('A', 'D'), ...), (('B', 'C'), ...), ...]
+        ```
+        """
+
+    def cis_peptides(self, tolerance: float = 30.0) -> List[int]:
+        """
+        Returns the numbers of residues preceded by a cis peptide bond.
+
+        A peptide bond is cis when its omega torsion (CA-C-N-CA) lies within the tolerance
+        around 0 degrees (trans bonds have omega close to 180 degrees). Bonds across chain
+        breaks and residues missing backbone atoms are skipped.
+
+
+        # Parameters
+        `tolerance` : float
+            Maximum deviation of omega from 0 degrees (default 30.0).
+
+
+        # Returns
+        `list[int]`
+            The list of residue numbers following a cis peptide bond.
+
+
+        # Examples
+        ### Finding cis peptide bonds (usually preceding a proline).
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.cis_peptides()
+
+        ``` raw
+        [145]
+        ```
+        """
+
+    def contacts(
+        self, cutoff: float = 4.0, exclude_same_residue: bool = True
+    ) -> List[Tuple[int, int]]:
+        """
+        Returns the pairs of atoms that are in contact.
+
+        Two atoms are in contact when they are at most the cutoff apart. The atoms are put
+        into a neighbor grid with cells as wide as the cutoff, so every atom is only compared
+        with the atoms of its own and the 26 adjacent cells, and the search scales linearly
+        with the number of atoms (instead of comparing every pair). Atoms are identified by
+        their index in the get_atoms() list, every pair is reported once (lower index first)
+        and the pairs are sorted. The GIL is released during the search.
+
+
+        # Parameters
+        `cutoff` : float
+            The maximum distance (in Angstroms, default 4.0).
+        `exclude_same_residue` : bool
+            Whether atoms of the same residue are skipped (default True).
+
+
+        # Returns
+        `list[(int, int)]`
+            The list of (atom index, atom index) tuples.
+
+
+        # Examples
+        ### Counting the contacts between residues.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> len(structure.contacts(4.0))
+
+        ``` raw
+        13059
+        ```
+        """
+
+    def convex_hull(self) -> Tuple[List[Tuple[float, float, float]], float]:
+        """
+        Returns the convex hull of the atom positions.
+
+        The hull is computed with the incremental algorithm. For degenerate inputs (fewer
+        than four atoms, or all atoms collinear or coplanar) all distinct atom positions are
+        returned and the volume is 0.0.
+
+
+        # Returns
+        `(list[(float, float, float)], float)`
+            The positions of the hull vertices and the volume enclosed by the hull
+            (in cubic Angstroms).
+
+
+        # Examples
+        ### Computing the convex hull of the structure.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> vertices, volume = structure.convex_hull()
+        >>> volume
+
+        ``` raw
+        105984.91...
+        ```
+        """
+
+    def coordinates(self) -> Any:
+        """
+        Returns the coordinates of all atoms as a NumPy array.
+
+        The array is built in one go, which is much faster than reading the atom positions
+        one by one. Rows follow the file order, so they align with the atoms yielded by
+        atoms().
+
+
+        # Returns
+        `numpy.ndarray`
+            The (N, 3) array of atom coordinates (float64).
+
+
+        # Examples
+        ### Computing the centroid of the structure.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> coordinates = structure.coordinates()
+        >>> coordinates.shape
+
+        ``` raw
+        (3806, 3)
+        ```
+        """
+
+    def coordinates_of(self, atoms: List[Atom]) -> Any:
+        """
+        Returns the coordinates of the selected atoms as a NumPy array.
+
+
+        # Parameters
+        `atoms` : list[Atom]
+            The selected atoms (e.g. from select_atoms_glob).
+
+
+        # Returns
+        `numpy.ndarray`
+            The (N, 3) array of atom coordinates (float64), in the order of the selection.
+
+
+        # Examples
+        ### Retrieving the coordinates of the gamma atoms.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.coordinates_of(structure.select_atoms_glob("*G*"))[0]
+
+        ``` raw
+        array([44.444, 33.949, 10.488])
+        ```
+        """
+
+    def copy(self) -> Structure:
+        """
+        Returns an independent copy of the structure.
+
+        All models, chains, residues and atoms are copied, so transforming the copy (e.g. with
+        translate or rotate) leaves the original untouched. copy.copy and copy.deepcopy make
+        the same full copy.
+
+
+        # Returns
+        `Structure`
+            The copy of the structure.
+
+
+        # Examples
+        ### Transforming a copy of the structure.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> moved = structure.copy()
+        >>> moved.translate((10.0, 0.0, 0.0))
+        >>> structure[0][0][0].position, moved[0][0][0].position
+
+        ``` raw
+        ((42.854, 36.56, 10.394), (52.854, 36.56, 10.394))
+        ```
+        """
+
+    def extract_region(self, selections: List[Tuple[str, int, int]]) -> Structure:
+        """
+        Returns a new structure with only the selected residue ranges.
+
+        Every selection is a (chain name, first residue number, last residue number) triple, both
+        ends inclusive. A residue is kept when any selection covers it, so overlapping selections
+        merge without duplicating residues. Residues keep their order, the header and unit cell
+        are copied, and chains without selected residues are dropped. The atoms are copies, so
+        the new structure is independent of this one.
+
+
+        # Parameters
+        `selections` : list[(str, int, int)]
+            The list of (chain name, first residue number, last residue number) triples.
+
+
+        # Returns
+        `Structure`
+            The structure with the selected residues.
+
+
+        # Examples
+        ### Carving out two overlapping regions.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> region = structure.extract_region([("A", 10, 30), ("A", 25, 40)])
+        >>> len(region.get_residues())
+
+        ``` raw
+        31
+        ```
+        """
+
+    def formula(self, include_hetatm: bool = True) -> str:
+        """
+        Returns the molecular formula of the structure in the Hill system.
+
+        Atoms are counted by element. Carbon comes first, then hydrogen, then all other elements
+        in alphabetical order (without carbon, all elements are in alphabetical order). Counts
+        of 1 are omitted. Atoms without an element symbol are not counted.
+
+
+        # Parameters
+        `include_hetatm` : bool
+            Whether HETATM records (ligands, ions and waters) are counted (default True).
+
+
+        # Returns
+        `str`
+            The formula, e.g. "C1200 H1800 N310 O350 S12".
+
+
+        # Examples
+        ### Retrieving the composition of the protein alone.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.formula(False)
+
+        ``` raw
+        C2251 N578 O678 S7
+        ```
+        """
+
+    def get_atoms(self) -> List[Atom]:
+        """
+        Returns a list of atoms that builds the structure.
+
+
+        # Returns
+        `list[Atom]`
+            The list of atoms that build the structure.
+
+
+        # Examples
+        ### Retrieving the list of atoms that builds the structure.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.get_atoms()
+
+        ``` raw
+        [<Atom N 1: (42.854, 36.56, 10.394)>,
+         <Atom CA 2: (42.25, 35.232, 10.096)>,
+         <Atom C 3: (41.642, 34.623, 11.355)>,
+        ...
+        ```
+        """
+
+    def get_chains(self) -> List[Chain]:
+        """
+        Returns the list of chains that builds the chain.
+
+
+        # Returns
+        `list[Chain]`
+            The list of chains that builds the structure.
+
+
+        # Examples
+        ### Retrieving the list of chains that builds the structure.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.get_chains()
+
+        ``` raw
+        [<Chain A: 701 residues>]
+        ```
+        """
+
+    def get_residues(self) -> List[Residue]:
+        """
+        Returns the list of residues that builds the structure.
+
+
+        # Returns
+        `list[Residue]`
+            The list of residues that builds the structure.
+
+
+        # Examples
+        ### Retrieving the list of residues that builds the structure.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.get_residues()
+
+        ``` raw
+        [<Residue MET -1: 8 atoms>, <Residue ASP 0: 8 atoms>, <Residue PRO 1: 7 atoms>,
+        ...
+        ```
+        """
+
+    def hbond_graph(
+        self, cutoff: float = 3.5, include_waters: bool = True
+    ) -> List[Tuple[int, int]]:
+        """
+        Returns the hydrogen bond network as a list of donor-acceptor edges.
+
+        Hydrogen bonds are assigned geometrically (hydrogens are not required) between a donor
+        and an acceptor closer than the cutoff, found with a neighbor grid. Amino acids donate
+        through the backbone N (except PRO) and the side chains of ARG, ASN, GLN, HIS, LYS, SER,
+        THR, TRP and TYR, and accept through the backbone O and OXT and the side chains of ASN,
+        ASP, GLN, GLU, HIS, SER, THR and TYR. Water oxygens and the nitrogens and oxygens of
+        other residues act as both. Atoms of the same residue are not paired and every pair is
+        reported once. Atoms are identified by their index in the get_atoms() list, so the edge
+        list can be passed directly to e.g. networkx.Graph.
+
+
+        # Parameters
+        `cutoff` : float
+            The maximum donor-acceptor distance (in Angstroms, default 3.5).
+        `include_waters` : bool
+            Whether water molecules take part, which adds water-mediated paths to the
+            network (default True).
+
+
+        # Returns
+        `list[(int, int)]`
+            The list of (donor index, acceptor index) tuples.
+
+
+        # Examples
+        ### Building the hydrogen bond network of the protein alone.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> edges = structure.hbond_graph(3.5, False)
+        >>> len(edges)
+
+        ``` raw
+        1144
+        ```
+        """
+
+    def is_isomorphic(self, other: Structure) -> bool:
+        """
+        Checks whether both structures describe the same molecule, ignoring the coordinates.
+
+        The structures are compared with a canonical traversal: chains in order (by chain
+        name), residues in order (by residue number and name), and the atom names of each
+        residue regardless of the order of the atoms within the residue. Two conformations
+        of the same molecule are isomorphic, structures differing by any chain, residue or
+        atom are not.
+
+
+        # Parameters
+        `other` : Structure
+            The structure to compare with.
+
+
+        # Returns
+        `bool`
+            True if the structures have the same topology.
+
+
+        # Examples
+        ### Comparing a structure with its copy with averaged alternate conformations.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.is_isomorphic(structure.average_altlocs())
+
+        ``` raw
+        True
+        ```
+        """
+
+    def iter_element(self, element: str) -> 'ElementIterator':
+        """
+        Returns a lazy iterator over the atoms of the given chemical element.
+
+        The atoms are found one at a time while iterating, no list is built, which makes it
+        memory-friendly for sparse elements (e.g. metals) in large structures.
+
+
+        # Parameters
+        `element` : str
+            Chemical element symbol (case-insensitive).
+
+
+        # Returns
+        `ElementIterator`
+            The iterator over atoms of the element.
+
+
+        # Examples
+        ### Iterating over the sulfur atoms of the structure.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> for atom in structure.iter_element("S"):
+        ...     print(atom.number)
+
+        ``` raw
+        7
+        559
+        668
+        ...
+        ```
+        """
+
+    def largest_cavity_radius(self, grid_spacing: float = 1.0) -> float:
+        """
+        Returns the approximate radius of the largest internal cavity.
+
+        The bounding box of the structure is covered with a regular grid and, for every grid
+        point inside the convex hull, the distance to the nearest atom surface (van der Waals
+        radius, 1.70 for unknown elements) and to the hull boundary is measured. The result
+        is the radius of the largest empty sphere fully inside the hull. The accuracy is
+        about the grid spacing, while the computation time grows with the cube of the inverse
+        spacing (halving the spacing makes it roughly 8 times slower). The GIL is released
+        during the computation.
+
+
+        # Parameters
+        `grid_spacing` : float
+            The distance between grid points (in Angstroms, default 1.0).
+
+
+        # Returns
+        `float`
+            The cavity radius (in Angstroms), 0.0 if there is no cavity.
+
+
+        # Examples
+        ### Estimating the largest cavity of the structure.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.largest_cavity_radius(1.0)
+
+        ``` raw
+        5.73...
+        ```
+        """
+
+    def mark_exposure(self, radius: float = 10.0, threshold: int = 120) -> None:
+        """
+        Marks the solvent exposed atoms (see Atom.is_exposed).
+
+        A cheap alternative to the solvent accessible surface: an atom is exposed when fewer
+        than threshold other atoms lie within the radius of it.
+
+
+        # Parameters
+        `radius` : float
+            The radius of the neighbor search in Angstroms (default 10.0).
+        `threshold` : int
+            The number of neighbors from which an atom is buried (default 120).
+
+
+        # Examples
+        ### Selecting the surface atoms.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.mark_exposure()
+        >>> surface = [atom for atom in structure.get_atoms() if atom.is_exposed]
+        >>> len(surface)
+
+        ``` raw
+        968
+        ```
+        """
+
+    def match_numbering(self, reference: Structure, residues: bool = False) -> None:
+        """
+        Renumbers the atoms (and optionally the residues) to match the reference structure.
+
+        Both structures have to describe the same molecule (see is_isomorphic; when residues are
+        renumbered too, residue numbers are not compared), otherwise a ValueError is raised.
+        Atoms are matched by chain, residue and atom name, so the order of the atoms within the
+        residues does not matter. Coordinates are not changed.
+
+
+        # Parameters
+        `reference` : Structure
+            The structure with the numbering to copy.
+        `residues` : bool
+            Whether the residue numbers are copied as well (default False).
+
+
+        # Examples
+        ### Copying the numbering of a deposited model to a refined one.
+
+        >>> parser = nanoPDB.Parser()
+        >>> deposited = parser.parse("deposited.pdb")
+        >>> refined = parser.parse("refined.pdb")
+        ...
+        >>> refined.match_numbering(deposited, True)
+        """
+
+    def max_diameter(self) -> float:
+        """
+        Returns the largest distance between any two atoms (the molecular diameter).
+
+        The two most distant atoms always lie on the convex hull, so the hull is computed
+        first (incremental algorithm) and only its vertices are compared pairwise, which is
+        much faster than comparing all pairs of atoms.
+
+
+        # Returns
+        `float`
+            The diameter (in Angstroms).
+
+
+        # Examples
+        ### Measuring the size of the structure.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.max_diameter()
+
+        ``` raw
+        73.73...
+        ```
+        """
+
+    def metadata_dict(self) -> Dict[str, Any]:
+        """
+        Returns the metadata of the structure as a small dictionary, without coordinates.
+
+        Intended for lightweight API responses and catalog indexing: only the header fields,
+        the chain names and the counts are included, no atom data is read.
+
+
+        # Returns
+        `dict`
+            The dictionary with keys: "pdbid", "classification", "date", "title",
+            "experimental_method", "resolution", "chain_ids", "num_chains", "num_residues" and
+            "num_atoms".
+
+
+        # Examples
+        ### Indexing the structure.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.metadata_dict()
+
+        ``` raw
+        {'pdbid': '1ZHY', 'classification': 'LIPID BINDING PROTEIN', 'date': '26-APR-05',
+         'title': 'STRUCTURE OF YEAST OXYSTEROL BINDING PROTEIN OSH4 IN COMPLEX WITH
+         CHOLESTEROL', 'experimental_method': 'X-RAY DIFFRACTION', 'resolution': 1.6,
+         'chain_ids': ['A'], 'num_chains': 1, 'num_residues': 701, 'num_atoms': 3806}
+        ```
+        """
+
+    def model(self, index: int) -> Model:
+        """
+        Returns the model with the given index.
+
+        Files without MODEL records have a single model. All other structure methods work on
+        the first model (index 0), whose chains are shared with the structure.
+
+
+        # Parameters
+        `index` : int
+            The index of the model (0-based), an IndexError is raised when out of range.
+
+
+        # Returns
+        `Model`
+            The model.
+
+
+        # Examples
+        ### Accessing a frame of an NMR ensemble.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1d3z")
+        ...
+        >>> len(structure.models), structure.model(1)
+
+        ``` raw
+        (10, <Model 2: 1 chains>)
+        ```
+        """
+
+    def molecular_weight(
+        self, include_water: bool = False, include_hydrogens: bool = True
+    ) -> float:
+        """
+        Returns the molecular weight of the structure, the sum of the atomic masses of its
+        atoms.
+
+        Only the atoms present in the file are counted, missing hydrogens are not added
+        implicitly (X-ray structures rarely contain them, so their weight is typically below
+        the weight computed from the sequence). Atoms of unknown elements have no weight and
+        are reported with a UserWarning.
+
+
+        # Parameters
+        `include_water` : bool
+            Whether water molecules are counted (default False).
+        `include_hydrogens` : bool
+            Whether hydrogen (and deuterium) atoms are counted (default True).
+
+
+        # Returns
+        `float`
+            The molecular weight (in daltons).
+
+
+        # Examples
+        ### Weighing the structure without its waters.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> round(structure.molecular_weight(), 1)
+
+        ``` raw
+        46959.2
+        ```
+        """
+
+    def neighbor_search(self, cell_size: float = 5.0) -> NeighborSearch:
+        """
+        Returns a spatial index over the atoms of the structure.
+
+        The atoms are put into a neighbor grid once, so the index answers any number of
+        atoms_within and neighbors queries without scanning all atoms. A cell size close to the
+        typical query radius is the fastest. The index keeps the positions at the time it was
+        built, so it has to be built again after the structure is moved.
+
+
+        # Parameters
+        `cell_size` : float
+            The width of the grid cells (in Angstroms, default 5.0).
+
+
+        # Returns
+        `NeighborSearch`
+            The index over the atoms of the structure.
+
+
+        # Examples
+        ### Counting the atoms around every atom of the ligands.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> search = structure.neighbor_search()
+        ...
+        >>> ligands = [residue for residue in structure.residues() if residue.kind() == "ligand"]
+        >>> sum(len(search.neighbors(atom, 4.0)) for residue in ligands for atom in residue)
+
+        ``` raw
+        330
+        ```
+        """
+
+    def neighbors(self, atom: Atom, radius: float) -> List[Atom]:
+        """
+        Returns the atoms of the structure within the radius of an atom, without the atom
+        itself.
+
+        Atoms do not know the structure they belong to, so the search starts from the
+        structure. A neighbor grid is built for the single query, use neighbor_search for
+        repeated queries.
+
+
+        # Parameters
+        `atom` : Atom
+            The atom to search around.
+        `radius` : float
+            The search radius (in Angstroms).
+
+
+        # Returns
+        `list[Atom]`
+            The list of neighboring atoms (in file order).
+
+
+        # Examples
+        ### Finding the atoms bonded to the first alpha carbon.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> [atom.name for atom in structure.neighbors(structure[0][0][1], 1.7)]
+
+        ``` raw
+        ['N', 'C', 'CB']
+        ```
+        """
+
+    def num_atoms(self) -> int:
+        """
+        Returns the number of atoms that builds the structure.
+
+
+        # Returns
+        `int`
+            The number of atoms in the structure.
+
+
+        # Examples
+        ### Counting the atoms of the structure.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.num_atoms()
+
+        ``` raw
+        3806
+        ```
+        """
+
+    def num_chains(self) -> int:
+        """
+        Returns the number of chains that builds the structure (of the first model).
+
+
+        # Returns
+        `int`
+            The number of chains in the structure.
+
+
+        # Examples
+        ### Counting the chains of the structure.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.num_chains()
+
+        ``` raw
+        1
+        ```
+        """
+
+    def num_residues(self) -> int:
+        """
+        Returns the number of residues (including waters and ligands) in the structure.
+
+
+        # Returns
+        `int`
+            The number of residues in the structure.
+
+
+        # Examples
+        ### Counting the residues of the structure.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.num_residues()
+
+        ``` raw
+        701
+        ```
+        """
+
+    def ramachandran(self) -> List[Tuple[int, float, float]]:
+        """
+        Returns the data for a Ramachandran plot (backbone torsions of the structure).
+
+        Only residues with both phi and psi defined are reported, so the chain termini,
+        residues next to chain breaks and residues missing any of the N, CA, C atoms are
+        skipped.
+
+
+        # Returns
+        `list[(int, float, float)]`
+            The list of (residue number, phi, psi) tuples, angles in degrees.
+
+
+        # Examples
+        ### Retrieving the Ramachandran plot data.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.ramachandran()
+
+        ``` raw
+        [(0, -79.71..., 148.10...), (1, -53.91..., -28.21...), (2, -62.95..., -18.62...), ...]
+        ```
+        """
+
+    def remove_water(self) -> Structure:
+        """
+        Returns a new structure without the water residues.
+
+        Waters are the residues named HOH, WAT or DOD (in any case), all other HETATM groups
+        (ligands, ions) are kept. Chains left without residues are dropped. The atoms are
+        copies, so the new structure is independent of this one.
+
+
+        # Returns
+        `Structure`
+            The structure without waters.
+
+
+        # Examples
+        ### Removing the ordered waters.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.num_atoms(), structure.remove_water().num_atoms()
+
+        ``` raw
+        (3806, 3544)
+        ```
+        """
+
+    def residue_b_factors(self) -> Tuple[Any, List[int]]:
+        """
+        Returns the mean B-factor of every residue along with the residue numbers.
+
+        Both outputs follow the order of the residues in the structure, so the B-factor at a
+        given index belongs to the residue number at the same index.
+
+
+        # Returns
+        `(numpy.ndarray, list[int])`
+            The array of mean B-factors and the list of residue numbers.
+
+
+        # Examples
+        ### Retrieving the B-factor profile of a structure.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> b_factors, numbers = structure.residue_b_factors()
+        >>> numbers[:5]
+
+        ``` raw
+        [-1, 0, 1, 2, 3]
+        ```
+        """
+
+    def residues(self) -> 'StructureResidueIterator':
+        """
+        Returns a lazy iterator over all residues of the structure (in file order).
+
+        The residues of all chains are yielded one at a time, without caring about the chain
+        boundaries. No list is built (unlike get_residues).
+
+
+        # Returns
+        `StructureResidueIterator`
+            The iterator over the residues of the structure.
+
+
+        # Examples
+        ### Iterating over the residues of the structure.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> for residue in structure.residues():
+        ...     print(residue.name, residue.number)
+
+        ``` raw
+        MET -1
+        ASP 0
+        ...
+        ```
+        """
+
+    def rmsd(self, other: Structure, selection: str = "CA") -> float:
+        """
+        Returns the root-mean-square deviation between the atoms of two structures.
+
+        Atoms of the selection are paired in file order and compared as they are, without
+        superposition (see superpose). Both structures must have the same number of selected
+        atoms, otherwise a ValueError is raised. Only the first models are compared.
+
+
+        # Parameters
+        `other` : Structure
+            The structure to compare with.
+        `selection` : str
+            "CA" for the alpha carbons (default), "backbone" for the N, CA, C and O atoms of
+            amino acids or "all" for all atoms.
+
+
+        # Returns
+        `float`
+            The RMSD (in Angstroms).
+
+
+        # Examples
+        ### Comparing the structure with a shifted copy.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> shifted = structure.copy()
+        ...
+        >>> shifted.translate((1.0, 0.0, 0.0))
+        >>> structure.rmsd(shifted, "backbone")
+
+        ``` raw
+        1.0
+        ```
+        """
+
+    def rotate(self, matrix: List[List[float]]) -> None:
+        """
+        Rotates the structure in place (all models) by the rotation matrix.
+
+        Every atom position p becomes matrix @ p (rotation about the origin, combine with translate
+        to rotate about another point). The structure is modified, use copy to keep the original.
+
+
+        # Parameters
+        `matrix` : list[list[float]]
+            The 3x3 rotation matrix (orthonormal rows, determinant 1), a ValueError is raised for
+            other matrices.
+
+
+        # Examples
+        ### Rotating the structure by 90 degrees about the z axis.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.rotate([[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]])
+        >>> structure[0][0][0].position
+
+        ``` raw
+        (-36.56, 42.854, 10.394)
+        ```
+        """
+
+    def save(self, path: str) -> None:
+        """
+        Writes the structure to a PDB file (see to_pdb).
+
+
+        # Parameters
+        `path` : str
+            The path to the output file.
+
+
+        # Examples
+        ### Saving a downloaded structure.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.save("1zhy.pdb")
+        """
+
+    def select_atoms(self, label: str) -> Structure:
+        """
+        Returns a new structure with only the atoms of the record type.
+
+        Selecting "ATOM" keeps the polymer (e.g. the protein without ligands, ions and
+        waters), selecting "HETATM" keeps the heteroatom groups. Chains and residues keep
+        their grouping for the kept atoms, residues and chains left without atoms are dropped.
+        The atoms are copies, so the new structure is independent of this one.
+
+
+        # Parameters
+        `label` : str
+            The record type of the kept atoms, "ATOM" or "HETATM" (other values raise a
+            ValueError).
+
+
+        # Returns
+        `Structure`
+            The structure with the selected atoms.
+
+
+        # Examples
+        ### Separating the protein from the heteroatom groups.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.select_atoms("ATOM").num_atoms(), structure.select_atoms("HETATM").num_atoms()
+
+        ``` raw
+        (3514, 292)
+        ```
+        """
+
+    def select_atoms_glob(self, pattern: str) -> List[Atom]:
+        """
+        Returns the atoms whose names match the glob pattern.
+
+        The pattern is matched against the whole (trimmed) atom name, `*` matches any sequence
+        of characters (including an empty one) and `?` matches exactly one character, other
+        characters match literally (case-sensitive).
+
+
+        # Parameters
+        `pattern` : str
+            The glob pattern, e.g. "H*" for hydrogens or "*G*" for gamma atoms.
+
+
+        # Returns
+        `list[Atom]`
+            The list of matching atoms (in file order), empty if no atom matches.
+
+
+        # Examples
+        ### Selecting the gamma atoms.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> len(structure.select_atoms_glob("*G*"))
+
+        ``` raw
+        451
+        ```
+        """
+
+    def shape_anisotropy(self) -> float:
+        """
+        Returns the relative shape anisotropy of the structure.
+
+        Computed from the eigenvalues l1, l2, l3 of the gyration tensor of the atom positions
+        as 1 - 3 * (l1 * l2 + l2 * l3 + l3 * l1) / (l1 + l2 + l3) ** 2. It is 0.0 for
+        spherically symmetric structures (and other highly symmetric ones, e.g. cubes), 0.25
+        for planar symmetric ones and 1.0 for rods. A ValueError is raised when the structure
+        has no extent (no atoms or all atoms in one place).
+
+
+        # Returns
+        `float`
+            The relative shape anisotropy (dimensionless, range 0.0-1.0).
+
+
+        # Examples
+        ### Describing the shape of the structure.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.shape_anisotropy()
+
+        ``` raw
+        0.0572...
+        ```
+        """
+
+    def split_on_gaps(self, gap_threshold: int) -> None:
+        """
+        Splits chains wherever consecutive residue numbers jump by more than the threshold.
+
+        The first segment of a split chain keeps the chain (and its name), every further segment
+        becomes a new chain placed right after it. New chains are named with the first unused
+        name from A-Z, then a-z, then 0-9. Jumps are measured in both directions, so restarted
+        numbering also splits the chain. Note that waters and ligands numbered far from the
+        polymer end up in chains of their own.
+
+
+        # Parameters
+        `gap_threshold` : int
+            The largest allowed difference of consecutive residue numbers (at least 1).
+
+
+        # Examples
+        ### Separating segments of a fused chain.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.parse("fused.pdb")
+        ...
+        >>> structure.split_on_gaps(100)
+        >>> [chain.name for chain in structure]
+
+        ``` raw
+        ['A', 'C', 'B']
+        ```
+        """
+
+    def summary(self) -> Dict[str, Any]:
+        """
+        Returns the summary of the structure metadata and contents.
+
+
+        # Returns
+        `dict`
+            The dictionary with keys: "pdbid", "classification", "date", "title",
+            "experimental_method", "resolution", "num_chains", "num_residues", "num_atoms"
+            and "has_unit_cell".
+
+
+        # Examples
+        ### Retrieving the summary of the structure.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.summary()
+
+        ``` raw
+        {'pdbid': '1ZHY', 'classification': 'LIPID BINDING PROTEIN', 'date': '26-APR-05',
+         'title': 'STRUCTURE OF YEAST OXYSTEROL BINDING PROTEIN OSH4 IN COMPLEX WITH
+         CHOLESTEROL', 'experimental_method': 'X-RAY DIFFRACTION', 'resolution': 1.6,
+         'num_chains': 1, 'num_residues': 701, 'num_atoms': 3806, 'has_unit_cell': True}
+        ```
+        """
+
+    def superpose(self, reference: Structure, selection: str = "CA") -> float:
+        """
+        Superposes the structure onto the reference in place (all models) and returns the
+        RMSD after the superposition.
+
+        The rotation and translation minimizing the RMSD between the selected atoms (paired
+        in file order, see rmsd) are computed with the Kabsch algorithm and applied to every
+        atom of the structure. The fit is always a proper rotation, mirror images are never
+        produced. Both structures must have the same number of selected atoms, otherwise a
+        ValueError is raised. The reference is not modified.
+
+
+        # Parameters
+        `reference` : Structure
+            The structure to superpose onto.
+        `selection` : str
+            "CA" for the alpha carbons (default), "backbone" for the N, CA, C and O atoms of
+            amino acids or "all" for all atoms.
+
+
+        # Returns
+        `float`
+            The RMSD (in Angstroms) of the selected atoms after the superposition.
+
+
+        # Examples
+        ### Undoing a rotation and a translation of a copy.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        >>> moved = structure.copy()
+        ...
+        >>> moved.rotate([[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]])
+        >>> moved.translate((10.0, 0.0, 0.0))
+        >>> round(moved.superpose(structure), 6)
+
+        ``` raw
+        0.0
+        ```
+        """
+
+    def to_pdb(self) -> str:
+        """
+        Returns the structure in the PDB format.
+
+        The text consists of the HEADER and CRYST1 records (when present in the structure)
+        and the ATOM/HETATM records of all atoms, with their original serial numbers. Each
+        chain ends with a TER record, structures with more than one model have every model
+        enclosed in MODEL/ENDMDL records. Parsing the text gives back the same atoms.
+
+
+        # Returns
+        `str`
+            The PDB text.
+
+
+        # Examples
+        ### Converting the structure back to the PDB format.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> print(structure.to_pdb())
+
+        ``` raw
+        HEADER    LIPID BINDING PROTEIN                   26-APR-05   1ZHY
+        CRYST1   82.760   94.210   65.412  90.00  96.27  90.00
+        ATOM      1  N   MET A  -1      42.854  36.560  10.394  1.00 37.27           N
+        ATOM      2  CA  MET A  -1      42.250  35.232  10.096  1.00 37.04           C
+        ...
+        ```
+        """
+
+    def to_pdbqt_string(self) -> str:
+        """
+        Returns the structure in the AutoDock PDBQT format.
+
+        This is a best-effort conversion. Charges are the formal charges read from columns
+        79-80 of the PDB file, so every atom needs one (neutral atoms as "0+"), a ValueError is
+        raised when any atom has a blank charge field. Atom types follow AutoDock 4: carbons are aromatic
+        (A) in the rings of PHE, TYR, TRP and HIS and aliphatic (C) otherwise, nitrogens are
+        acceptors (NA) in the HIS ring and N otherwise, oxygens are OA, sulfurs SA, hydrogens
+        are HD when the nearest heavy atom of the residue is a nitrogen or an oxygen and H
+        otherwise. Other elements keep their symbol (e.g. Zn, Fe, Mg). Each chain ends with
+        a TER record.
+
+
+        # Returns
+        `str`
+            The PDBQT text.
+
+
+        # Examples
+        ### Converting a structure with charges to PDBQT.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.parse("receptor.pdb")
+        ...
+        >>> print(structure.to_pdbqt_string())
+
+        ``` raw
+        ATOM      1  N   MET A   1      42.854  36.560  10.394  1.00 37.27    +1.000 N
+        ATOM      2  CA  MET A   1      42.250  35.232  10.096  1.00 37.04    +0.000 C
+        ...
+        ```
+        """
+
+    def translate(self, vector: Tuple[float, float, float]) -> None:
+        """
+        Translates the structure in place (all models) by the vector.
+
+        The structure is modified, use copy to keep the original.
+
+
+        # Parameters
+        `vector` : (float, float, float)
+            The translation vector.
+
+
+        # Examples
+        ### Moving the structure along the x axis.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> structure.translate((10.0, 0.0, 0.0))
+        >>> structure[0][0][0].position
+
+        ``` raw
+        (52.854, 36.56, 10.394)
+        ```
+        """
+
+    def waters(self) -> List[Residue]:
+        """
+        Returns the water molecules of the structure.
+
+        Waters are recognized by the residue name (HOH, WAT or DOD).
+
+
+        # Returns
+        `list[Residue]`
+            The list of water residues (empty for a desolvated structure).
+
+
+        # Examples
+        ### Counting the ordered waters.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> len(structure.waters())
+
+        ``` raw
+        262
+        ```
+        """
+
+    def write_selection(self, path: str, atoms: List[Atom], renumber: bool = True) -> None:
+        """
+        Writes the selected atoms to a PDB file.
+
+        Records keep the residue and chain of every atom, atoms are written in the order of
+        the structure (not of the selection) and each chain ends with a TER record. The atoms
+        have to belong to the structure, otherwise a ValueError is raised.
+
+
+        # Parameters
+        `path` : str
+            The path to the output file.
+        `atoms` : list[Atom]
+            The atoms to write.
+        `renumber` : bool
+            Whether the atoms are renumbered contiguously from 1 (default True), otherwise the
+            original atom numbers are kept.
+
+
+        # Examples
+        ### Saving the carbon atoms of the structure.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> carbons = [atom for atom in structure.get_atoms() if atom.element == "C"]
+        >>> structure.write_selection("carbons.pdb", carbons)
+        """
+
+
+class Parser:
+    """
+    Parser - a class for parsing structures in PDB format.
+
+    The alt_loc option (default "all") chooses how alternate conformations are handled:
+    "all" keeps every conformer, "best" keeps only the highest-occupancy conformer of every
+    atom (the first one on ties).
+
+    The guess_elements option (default True) fills in the elements of atoms with blank (or
+    missing) element columns 77-78 from the atom names. Element symbols are right-justified
+    in columns 13-14 of the atom name, so " CA " is a carbon (alpha carbon) and "CA  " a
+    calcium; with the option disabled such atoms keep a blank element.
+
+    The timeout option sets the time limit of a single download in seconds (default None,
+    the 30 seconds limit of the HTTP client), the retries option (default 0) the number of
+    retries of downloads that failed transiently (connection errors, timeouts and 5xx
+    responses), each after a short delay.
+
+    The cache_dir option (default None) is a directory for downloaded structures: fetch
+    (and fetch_many, fetch_async, parse of a PDB ID) reads {cache_dir}/{PDBID}.pdb
+    ({cache_dir}/{PDBID}.cif for fetch_cif) when it exists and parses, otherwise downloads
+    the structure and writes it there.
+
+    The skip_water option (default False) drops water residues (HOH, WAT and DOD, in any
+    case) while parsing, all other HETATM groups are kept.
+
+    Chains are also separated on TER records: a polymer (ATOM records) that follows a TER
+    record and reuses the name of an earlier chain is put into a new chain, named with the
+    first chain name unused in the file (or model). The same applies to HETATM records after
+    a TER that closed a non-water HETATM segment, waters keep the chain before them.
+
+    Atom serial numbers and residue numbers too large for their columns (over 99999 atoms or
+    9999 residues) are read in the hybrid-36 encoding, e.g. "A0000" is the atom 100000.
+    """
+
+    # -----------------------------------------------------------------------------------------
+    # Special methods
+    # -----------------------------------------------------------------------------------------
+
+    def __init__(
+        self,
+        alt_loc: str = "all",
+        guess_elements: bool = True,
+        timeout: Optional[float] = None,
+        retries: int = 0,
+        cache_dir: Optional[str] = None,
+        skip_water: bool = False,
+    ) -> None: ...
+
+    # -----------------------------------------------------------------------------------------
+    # Methods
+    # -----------------------------------------------------------------------------------------
+
+    def benchmark_parse(self, path: str, iterations: int) -> float:
+        """
+        Measures the parsing performance and returns the mean parse time in milliseconds.
+
+        The file is read (and decompressed, if gzip-compressed) once, then its content is parsed
+        the given number of times. Every parsed structure is released before the next run, so
+        memory use does not grow with the number of iterations. File reading is not included in
+        the measured time.
+
+
+        # Parameters
+        `path` : str
+            The path to the PDB file.
+        `iterations` : int
+            The number of parse runs (at least 1).
+
+
+        # Returns
+        `float`
+            The mean parse time (in milliseconds).
+
+
+        # Examples
+        ### Measuring the parse time of a file.
+
+        >>> parser = nanoPDB.Parser()
+        >>> parser.benchmark_parse("tests/1zhy.pdb", 100)
+
+        ``` raw
+        12.34...
+        ```
+        """
+
+    def fetch(self, pdbid: str, /, strict: bool = True) -> Structure:
+        """
+        Fetches structure from RCSB PDB database, parses it and returns Structure object.
+
+        The GIL is released during the download, so other Python threads keep running.
+
+
+        # Parameters
+        `pdbid` : str
+            PDB ID of structure from RCSB PDB.
+        `strict` : bool
+            Whether a malformed record raises an exception (default True), otherwise it is
+            skipped and recorded in Structure.warnings.
+
+
+        # Returns
+        `Structure`
+            Parsed structure.
+
+
+        # Examples
+        ### Fetching structure from RCSB PDB database.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch("1zhy")
+        ...
+        >>> print(structure)
+
+        ``` raw
+        Structure {
+            pdbid: "1ZHY",
+            classification: "LIPID BINDING PROTEIN",
             date: "26-APR-05",
         }
         ```
         """
 
-    def parse(self, path: str) -> Structure:
+    def fetch_async(self, pdbid: str, /, strict: bool = True) -> Awaitable[Structure]:
         """
-        Parses PDB file and returns the Structure object.
+        Fetches structure from RCSB PDB database without blocking the asyncio event loop.
+
+        The structure is fetched with fetch in the default executor of the running event loop,
+        so it has to be called from a coroutine (asyncio raises a RuntimeError otherwise).
+        Several structures can be downloaded concurrently, e.g. with asyncio.gather.
+
+
+        # Parameters
+        `pdbid` : str
+            PDB ID of structure from RCSB PDB.
+        `strict` : bool
+            Whether a malformed record raises an exception (default True), otherwise it is
+            skipped and recorded in Structure.warnings.
+
+
+        # Returns
+        `Awaitable[Structure]`
+            The awaitable resolving to the parsed structure.
+
+
+        # Examples
+        ### Fetching two structures concurrently.
+
+        >>> async def main():
+        ...     parser = nanoPDB.Parser()
+        ...     return await asyncio.gather(parser.fetch_async("1zhy"), parser.fetch_async("4hhb"))
+        >>> structures = asyncio.run(main())
+        >>> [structure.pdbid for structure in structures]
+
+        ``` raw
+        ['1ZHY', '4HHB']
+        ```
+        """
+
+    def fetch_cif(self, pdbid: str) -> Structure:
+        """
+        Fetches structure in mmCIF format from RCSB PDB database, parses it and returns
+        Structure object.
+
+        mmCIF is the only complete format of large structures (more than 62 chains or 99999
+        atoms). The header is read from the _entry, _struct, _struct_keywords,
+        _pdbx_database_status, _exptl and _refine items, the unit cell from the _cell items
+        and the atoms from the _atom_site loop, using the author atom, residue and chain
+        names and numbers (as in PDB files). Chain names longer than one character are
+        replaced by unused chain names. The GIL is released during the download.
+
+
+        # Parameters
+        `pdbid` : str
+            PDB ID of structure from RCSB PDB.
+
+
+        # Returns
+        `Structure`
+            Parsed structure.
+
+
+        # Examples
+        ### Fetching structure in mmCIF format.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.fetch_cif("1zhy")
+        ...
+        >>> print(structure)
+
+        ``` raw
+        Structure {
+            pdbid: "1ZHY",
+            classification: "LIPID BINDING PROTEIN",
+            date: "26-APR-05",
+        }
+        ```
+        """
+
+    def fetch_many(self, ids: List[str]) -> List[Structure]:
+        """
+        Fetches several structures from RCSB PDB database concurrently and parses them.
+
+        Up to 8 structures are downloaded at the same time (without holding the GIL), which is
+        much faster than calling fetch in a loop. An error of any download or parse is raised
+        with the PDB ID it concerns.
+
+
+        # Parameters
+        `ids` : list[str]
+            PDB IDs of structures from RCSB PDB.
+
+
+        # Returns
+        `list[Structure]`
+            Parsed structures (in the order of the PDB IDs).
+
+
+        # Examples
+        ### Fetching a small dataset.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structures = parser.fetch_many(["1zhy", "4hhb", "1crn"])
+        ...
+        >>> [structure.pdbid for structure in structures]
+
+        ``` raw
+        ['1ZHY', '4HHB', '1CRN']
+        ```
+        """
+
+    def for_each_atom(self, path: str, callback: Callable[[tuple], Optional[bool]]) -> int:
+        """
+        Parses PDB file in a single pass, calling the callback with the fields of every
+        ATOM/HETATM record.
+
+        Meant for one-pass computations (e.g. a centroid) over files of any size: the file is
+        read line by line, atoms are not retained and no Structure is returned, so memory use
+        stays constant. Works exactly like parse_streaming: the callback receives one tuple per
+        atom (label, atom number, atom name, residue name, residue number, chain name, x, y, z,
+        occupancy, element), returning False from the callback stops parsing and exceptions
+        raised by the callback are propagated.
 
 
         # Parameters
         `path` : str
             The path to the PDB file.
+        `callback` : Callable[[tuple], bool | None]
+            The function called for every atom.
+
+
+        # Returns
+        `int`
+            The number of atoms passed to the callback.
+
+
+        # Examples
+        ### Computing the centroid of the file without building the Structure.
+
+        >>> parser = nanoPDB.Parser()
+        >>> total = [0.0, 0.0, 0.0]
+        >>> def add(atom):
+        ...     total[0] += atom[6]
+        ...     total[1] += atom[7]
+        ...     total[2] += atom[8]
+        >>> count = parser.for_each_atom("tests/1zhy.pdb", add)
+        >>> count, [round(value / count, 3) for value in total]
+
+        ``` raw
+        (3806, [57.025, 36.566, 39.137])
+        ```
+        """
+
+    def parse(
+        self,
+        path: str,
+        /,
+        records: Optional[set[str]] = None,
+        strict: bool = True,
+        parallel: bool = False,
+    ) -> Structure:
+        """
+        Parses PDB file and returns the Structure object.
+
+        The argument is inspected to find the source: an http:// or https:// URL is
+        downloaded (plain or gzip-compressed content), a 4-character PDB ID (that is not an
+        existing file) is fetched from RCSB PDB like with fetch, anything else is read as
+        a path to a file. Gzip-compressed files (e.g. .pdb.gz) are detected by their content
+        and decompressed transparently. Plain files are memory-mapped rather than read, so
+        large files are not held in memory twice.
+
+        Parsing can be limited to selected record types (e.g. only "ATOM" for speed, skipping
+        HETATM and metadata records). Supported record types are ATOM, HETATM, HEADER,
+        CRYST1, TITLE, EXPDTA, REMARK (resolution), CONECT, SEQRES and ANISOU, other names
+        raise a ValueError.
+
+
+        # Parameters
+        `path` : str
+            The path to the PDB file (plain or gzip-compressed), a URL or a PDB ID.
+        `records` : set[str], optional
+            Record types to parse, all supported records are parsed if not given.
+        `strict` : bool
+            Whether a malformed record raises an exception (default True), otherwise it is
+            skipped and recorded in Structure.warnings.
+        `parallel` : bool
+            Whether the atom records are parsed on multiple threads (default False), models
+            (MODEL/ENDMDL blocks) are parsed concurrently. The result is the same as of the
+            single-threaded parsing, models keep their order in the file. The Python objects
+            of the structure are still built on the calling thread (they need the GIL).
 
 
         # Returns
@@ -493,7 +3857,200 @@ class Parser:
         >>> parser = nanoPDB.Parser()
         >>> structure = parser.parse("tests/1zhy.pdb")
         ...
-        >>> structure
+        >>> print(structure)
+
+        ``` raw
+        Structure {
+            pdbid: "1ZHY",
+            classification: "LIPID BINDING PROTEIN",
+            date: "26-APR-05",
+        }
+        ```
+
+        ### Loading structure from URL.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.parse("https://files.rcsb.org/download/1zhy.pdb.gz")
+        ...
+        >>> structure.pdbid
+
+        ``` raw
+        '1ZHY'
+        ```
+
+        ### Loading only the ATOM records.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.parse("tests/1zhy.pdb", {"ATOM"})
+        >>> structure.num_atoms()
+
+        ``` raw
+        3514
+        ```
+
+        ### Loading a file with malformed records.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.parse("tests/malformed_records.pdb", None, False)
+        >>> structure.warnings
+
+        ``` raw
+        ['error in line: 2, cannot parse numeric', 'error in line: 4, ATOM/HETATM line to short']
+        ```
+
+        ### Loading a large NMR ensemble on multiple threads.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.parse("1d3z", parallel=True)
+        ...
+        >>> len(structure.models)
+
+        ``` raw
+        10
+        ```
+        """
+
+    def parse_bytes(self, data: bytes) -> Structure:
+        """
+        Parses PDB data (plain or gzip-compressed) and returns the Structure object.
+
+
+        # Parameters
+        `data` : bytes
+            The content of the PDB file, gzip compression is detected automatically.
+
+
+        # Returns
+        `Structure`
+            Parsed structure.
+
+
+        # Examples
+        ### Loading structure from bytes.
+
+        >>> parser = nanoPDB.Parser()
+        >>> with open("tests/1zhy.pdb", "rb") as file:
+        ...     structure = parser.parse_bytes(file.read())
+        >>> print(structure)
+
+        ``` raw
+        Structure {
+            pdbid: "1ZHY",
+            classification: "LIPID BINDING PROTEIN",
+            date: "26-APR-05",
+        }
+        ```
+        """
+
+    def parse_cif(self, path: str) -> Structure:
+        """
+        Parses mmCIF file and returns the Structure object.
+
+        The file is read like with fetch_cif: the header from the _entry, _struct,
+        _struct_keywords, _pdbx_database_status, _exptl and _refine items, the unit cell from
+        the _cell items and the atoms from the _atom_site loop. Columns are found by their
+        names (in any order), values may be quoted and the null markers '.' and '?' stand for
+        missing values (default occupancy 1.0 and B-factor 0.0, no charge, alternate location
+        or insertion code). Gzip-compressed files (e.g. .cif.gz) are decompressed
+        transparently. Only the first data block is read.
+
+
+        # Parameters
+        `path` : str
+            The path to the mmCIF file (.cif or .mmcif, plain or gzip-compressed).
+
+
+        # Returns
+        `Structure`
+            Parsed structure.
+
+
+        # Examples
+        ### Loading structure from mmCIF file.
+
+        >>> parser = nanoPDB.Parser()
+        >>> structure = parser.parse_cif("tests/1abc.cif")
+        >>> print(structure)
+
+        ``` raw
+        Structure {
+            pdbid: "1ABC",
+            classification: "DE NOVO PROTEIN",
+            date: "26-APR-05",
+        }
+        ```
+        """
+
+    def parse_streaming(self, path: str, on_atom: Callable[[tuple], Optional[bool]]) -> int:
+        """
+        Parses PDB file atom by atom, passing every ATOM/HETATM record to the callback.
+
+        The file is read line by line and no Structure is built, so memory use does not
+        depend on the file size. The callback receives one tuple per atom: (label, atom
+        number, atom name, residue name, residue number, chain name, x, y, z, occupancy,
+        element). Returning False from the callback stops parsing, exceptions raised by the
+        callback are propagated.
+
+
+        # Parameters
+        `path` : str
+            The path to the PDB file.
+        `on_atom` : Callable[[tuple], bool | None]
+            The function called for every atom.
+
+
+        # Returns
+        `int`
+            The number of atoms passed to the callback.
+
+
+        # Examples
+        ### Printing the first three atoms of the file.
+
+        >>> parser = nanoPDB.Parser()
+        >>> def on_atom(atom):
+        ...     print(atom)
+        ...     return atom[1] < 3
+        >>> parser.parse_streaming("tests/1zhy.pdb", on_atom)
+
+        ``` raw
+        ('ATOM', 1, 'N', 'MET', -1, 'A', 42.854, 36.56, 10.394, 1.0, 'N')
+        ('ATOM', 2, 'CA', 'MET', -1, 'A', 42.25, 35.232, 10.096, 1.0, 'C')
+        ('ATOM', 3, 'C', 'MET', -1, 'A', 41.642, 34.623, 11.355, 1.0, 'C')
+        3
+        ```
+        """
+
+    def parse_string(
+        self, content: str, /, records: Optional[set[str]] = None, strict: bool = True
+    ) -> Structure:
+        """
+        Parses PDB content held in memory (e.g. received from a database or generated) and
+        returns the Structure object. The content is parsed exactly like a file with parse.
+
+
+        # Parameters
+        `content` : str
+            The content of the PDB file.
+        `records` : set[str], optional
+            Record types to parse, all supported records are parsed if not given.
+        `strict` : bool
+            Whether a malformed record raises an exception (default True), otherwise it is
+            skipped and recorded in Structure.warnings.
+
+
+        # Returns
+        `Structure`
+            Parsed structure.
+
+
+        # Examples
+        ### Loading structure from a string.
+
+        >>> parser = nanoPDB.Parser()
+        >>> with open("tests/1zhy.pdb") as file:
+        ...     structure = parser.parse_string(file.read())
+        >>> print(structure)
 
         ``` raw
         Structure {
@@ -503,3 +4060,34 @@ class Parser:
         }
         ```
         """
+
+    def validate_file(self, path: str) -> List[str]:
+        """
+        Checks the PDB file against the fixed-column format and returns the found problems.
+
+        The file is not parsed into a Structure. ATOM/HETATM records are checked for
+        non-numeric atom and residue numbers, misaligned or non-numeric coordinates,
+        occupancy and B-factor (blank occupancy and B-factor are allowed), and a missing
+        element symbol; HEADER and CRYST1 records are checked for their minimal length.
+
+
+        # Parameters
+        `path` : str
+            The path to the PDB file.
+
+
+        # Returns
+        `list[str]`
+            The list of warnings (with line numbers), empty for a valid file.
+
+
+        # Examples
+        ### Checking the PDB file.
+
+        >>> parser = nanoPDB.Parser()
+        >>> parser.validate_file("tests/1zhy.pdb")
+
+        ``` raw
+        []
+        ```
+        """
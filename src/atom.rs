@@ -1,7 +1,16 @@
-use pyo3::{pyclass, pymethods};
+use crate::{
+    elements,
+    geometry::{self, Vector},
+};
 
-use heapless;
+use pyo3::{
+    exceptions::{PyUserWarning, PyValueError},
+    pyclass, pymethods,
+    types::{PyString, PyType},
+    Py, PyCell, PyErr, PyObject, PyRef, PyResult, Python,
+};
 
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Clone)]
 pub enum AtomType {
     ATOM,
@@ -21,7 +30,24 @@ impl std::fmt::Display for AtomType {
     }
 }
 
-#[pyclass(module = "nanoPDB", frozen)]
+/// Pickled state of an atom: label, number, name, alternate location, element, position,
+/// occupancy, B-factor, charge, exposure and anisotropic displacement parameters.
+pub type AtomState = (
+    String,
+    i32,
+    String,
+    Option<char>,
+    String,
+    (f64, f64, f64),
+    f64,
+    f64,
+    Option<i8>,
+    Option<bool>,
+    Option<[f64; 6]>,
+);
+
+#[pyclass(module = "nanoPDB")]
+#[derive(Clone)]
 pub struct Atom {
     pub label: AtomType,
 
@@ -30,6 +56,7 @@ pub struct Atom {
     pub number: i32,
 
     pub name: heapless::String<4>,
+    pub alt_loc: Option<char>,
     pub element: heapless::String<4>,
 
     /// [(float, float, float)] Position of an atom in 3D space.
@@ -39,6 +66,17 @@ pub struct Atom {
     /// [float] Atom occupancy.
     #[pyo3(get)]
     pub occupancy: f64,
+
+    /// [float] Temperature (B-)factor.
+    #[pyo3(get)]
+    pub b_factor: f64,
+
+    /// [int | None] Formal charge (None when the charge field is blank).
+    #[pyo3(get)]
+    pub charge: Option<i8>,
+
+    pub exposed: Option<bool>,
+    pub u_aniso: Option<[f64; 6]>,
 }
 
 /// Atom - a class that represents an atom of a PDB structure.
@@ -48,10 +86,26 @@ impl Atom {
     // Getters
     // ----------------------------------------------------------------------------------------
 
+    /// [str | None] Alternate location indicator (None for atoms without alternate
+    /// conformations).
+    #[getter]
+    pub fn alt_loc(&self) -> Option<char> {
+        self.alt_loc
+    }
+
     /// [str] Chemical element name.
     #[getter]
-    pub fn element(&self) -> String {
-        self.element.to_string()
+    pub fn element(&self, python: Python) -> Py<PyString> {
+        PyString::intern(python, &self.element).into()
+    }
+
+    /// [bool] Whether the atom is solvent exposed, computed by Structure.mark_exposure (a
+    /// ValueError is raised before it is called).
+    #[getter]
+    pub fn is_exposed(&self) -> PyResult<bool> {
+        self.exposed.ok_or_else(|| {
+            PyValueError::new_err("exposure not computed, call Structure.mark_exposure first")
+        })
     }
 
     /// [str] Indicates the type of atom.
@@ -62,40 +116,528 @@ impl Atom {
 
     /// [str] Atom name.
     #[getter]
-    pub fn name(&self) -> String {
-        self.name.to_string()
+    pub fn name(&self, python: Python) -> Py<PyString> {
+        // Names repeat throughout the structure, so all atoms share one (interned) str
+        // object per distinct name instead of a new str object for every access:
+        PyString::intern(python, &self.name).into()
+    }
+
+    /// [(float, float, float, float, float, float) | None] Anisotropic displacement
+    /// parameters U11, U22, U33, U12, U13 and U23 (in square Angstroms) from the ANISOU
+    /// record (None for atoms without one).
+    #[getter]
+    pub fn u_aniso(&self) -> Option<(f64, f64, f64, f64, f64, f64)> {
+        self.u_aniso
+            .map(|[u11, u22, u33, u12, u13, u23]| (u11, u22, u33, u12, u13, u23))
     }
 
     // ----------------------------------------------------------------------------------------
     // Special methods
     // ----------------------------------------------------------------------------------------
 
+    pub fn __reduce__(slf: &PyCell<Self>) -> PyResult<(PyObject, (AtomState,))> {
+        Ok((
+            slf.get_type().getattr("_from_state")?.into(),
+            (slf.borrow().state(),),
+        ))
+    }
+
     pub fn __repr__(&self) -> String {
+        format!("<Atom {} {}: {:?}>", self.name, self.number, self.position)
+    }
+
+    pub fn __str__(&self) -> String {
         format!("{:#}", self)
     }
+
+    // ----------------------------------------------------------------------------------------
+    // Methods
+    // ----------------------------------------------------------------------------------------
+
+    /// Rebuilds the atom from its pickled state (see __reduce__).
+    #[classmethod]
+    #[pyo3(name = "_from_state", signature = (state, /))]
+    pub fn py_from_state(_class: &PyType, state: AtomState) -> Self {
+        Atom::from_state(state)
+    }
+
+    /// Returns the bond angle at the atom b between the atoms a and c.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// a : Atom
+    ///     The first atom.
+    /// b : Atom
+    ///     The vertex atom.
+    /// c : Atom
+    ///     The third atom.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// float
+    ///     The angle (in degrees, 0 to 180).
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Measuring the N-CA-C angle of a residue.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> residue = structure[0][1]
+    /// ...
+    /// >>> nanoPDB.Atom.angle(residue.atom("N"), residue.atom("CA"), residue.atom("C"))
+    ///
+    /// 107.74...
+    #[staticmethod]
+    #[pyo3(signature = (a, b, c, /))]
+    pub fn angle(a: PyRef<Atom>, b: PyRef<Atom>, c: PyRef<Atom>) -> f64 {
+        geometry::angle(a.position, b.position, c.position)
+    }
+
+    /// Returns the CPK color of the atom's element.
+    ///
+    /// Colors follow the RasMol CPK convention (carbon light gray, oxygen red, nitrogen
+    /// light blue, sulfur yellow, ...). Elements without a conventional color are deep pink.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// (int, int, int)
+    ///     The RGB color (0-255 components).
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Retrieving the color of an atom.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> atom = structure[0][0][0]
+    /// ...
+    /// >>> atom.cpk_color()
+    ///
+    /// (143, 143, 255)
+    #[pyo3(signature = (/))]
+    pub fn cpk_color(&self) -> (u8, u8, u8) {
+        elements::cpk_color(&self.element)
+    }
+
+    /// Returns the torsion (dihedral) angle of the atoms a, b, c and d around the b -> c bond.
+    ///
+    /// The sign follows the IUPAC convention: the angle is positive when, looking along the
+    /// b -> c bond, the a -> b bond has to be rotated clockwise (by less than 180 degrees) to
+    /// eclipse the c -> d bond. Cis arrangements are near 0, trans near 180 (or -180).
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// a : Atom
+    ///     The first atom.
+    /// b : Atom
+    ///     The second atom (first atom of the central bond).
+    /// c : Atom
+    ///     The third atom (second atom of the central bond).
+    /// d : Atom
+    ///     The fourth atom.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// float
+    ///     The torsion angle (in degrees, -180 to 180).
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Computing the phi torsion of the second residue.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> previous, residue = structure[0][0], structure[0][1]
+    /// ...
+    /// >>> nanoPDB.Atom.dihedral(
+    /// ...     previous.atom("C"), residue.atom("N"), residue.atom("CA"), residue.atom("C")
+    /// ... )
+    ///
+    /// -79.71...
+    #[staticmethod]
+    #[pyo3(signature = (a, b, c, d, /))]
+    pub fn dihedral(a: PyRef<Atom>, b: PyRef<Atom>, c: PyRef<Atom>, d: PyRef<Atom>) -> f64 {
+        geometry::dihedral(a.position, b.position, c.position, d.position)
+    }
+
+    /// Returns the distance between the atom and the other atom.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// other : Atom
+    ///     The other atom.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// float
+    ///     The distance (in Angstroms).
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Measuring the N-CA bond length.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> residue = structure[0][0]
+    /// ...
+    /// >>> residue.atom("N").distance_to(residue.atom("CA"))
+    ///
+    /// 1.489...
+    #[pyo3(signature = (other, /))]
+    pub fn distance_to(&self, other: PyRef<Atom>) -> f64 {
+        geometry::distance(self.position, other.position)
+    }
+
+    /// Returns the standard atomic weight of the atom's element.
+    ///
+    /// The element symbol is matched case-insensitively (e.g. "ZN" and "Zn"). Atoms with a
+    /// blank or unknown element return None rather than raising, so masses of whole
+    /// structures can skip them explicitly.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// float | None
+    ///     The atomic mass (in daltons), None for unknown elements.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Retrieving the mass of a nitrogen atom.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> atom = structure[0][0][0]
+    /// ...
+    /// >>> atom.mass()
+    ///
+    /// 14.007
+    #[pyo3(signature = (/))]
+    pub fn mass(&self) -> Option<f64> {
+        elements::atomic_mass(&self.element)
+    }
+
+    /// Sets the temperature (B-)factor of the atom.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// value : float
+    ///     The new B-factor.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Annotating an atom before exporting the structure.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> atom = structure[0][0][0]
+    /// ...
+    /// >>> atom.set_b_factor(50.0)
+    #[pyo3(signature = (value, /))]
+    pub fn set_b_factor(&mut self, value: f64) {
+        self.b_factor = value;
+    }
+
+    /// Sets the occupancy of the atom.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// value : float
+    ///     The new occupancy, a ValueError is raised when it is outside of [0.0, 1.0].
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Lowering the occupancy of an atom.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> atom = structure[0][0][0]
+    /// ...
+    /// >>> atom.set_occupancy(0.5)
+    /// >>> atom.occupancy
+    ///
+    /// 0.5
+    #[pyo3(signature = (value, /))]
+    pub fn set_occupancy(&mut self, value: f64) -> PyResult<()> {
+        if !(0.0..=1.0).contains(&value) {
+            return Err(PyValueError::new_err(format!(
+                "occupancy must be in range [0.0, 1.0], got: {}",
+                value
+            )));
+        }
+
+        self.occupancy = value;
+
+        Ok(())
+    }
+
+    /// Returns the squared distance between the atom and the other atom.
+    ///
+    /// Faster than distance_to (no square root), for comparing against a squared cutoff in
+    /// tight loops.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// other : Atom
+    ///     The other atom.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// float
+    ///     The squared distance (in square Angstroms).
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Checking the N-CA contact against a 2.0 Angstrom cutoff.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> residue = structure[0][0]
+    /// ...
+    /// >>> residue.atom("N").squared_distance_to(residue.atom("CA")) < 2.0 ** 2
+    ///
+    /// True
+    #[pyo3(signature = (other, /))]
+    pub fn squared_distance_to(&self, other: PyRef<Atom>) -> f64 {
+        geometry::squared_distance(self.position, other.position)
+    }
+
+    /// Returns the van der Waals radius of the atom's element.
+    ///
+    /// The element symbol is matched case-insensitively (e.g. "ZN" and "Zn"). Atoms with a
+    /// blank or unknown element return None rather than raising (largest_cavity_radius of
+    /// the structure falls back to the carbon radius for them).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// float | None
+    ///     The van der Waals radius (in Angstroms), None for unknown elements.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Retrieving the radius of a nitrogen atom.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> atom = structure[0][0][0]
+    /// ...
+    /// >>> atom.vdw_radius()
+    ///
+    /// 1.55
+    #[pyo3(signature = (/))]
+    pub fn vdw_radius(&self) -> Option<f64> {
+        elements::vdw_radius(&self.element)
+    }
 }
 
 impl Atom {
+    pub fn state(&self) -> AtomState {
+        (
+            self.label(),
+            self.number,
+            self.name.to_string(),
+            self.alt_loc,
+            self.element.to_string(),
+            self.position,
+            self.occupancy,
+            self.b_factor,
+            self.charge,
+            self.exposed,
+            self.u_aniso,
+        )
+    }
+
+    pub fn from_state(state: AtomState) -> Self {
+        let (
+            label,
+            number,
+            name,
+            alt_loc,
+            element,
+            position,
+            occupancy,
+            b_factor,
+            charge,
+            exposed,
+            u_aniso,
+        ) = state;
+
+        let label = match label.as_str() {
+            "HETATM" => AtomType::HETATM,
+            _ => AtomType::ATOM,
+        };
+
+        let mut atom = Atom::new(
+            label,
+            number,
+            name_field(&name),
+            alt_loc,
+            name_field(&element),
+            position,
+            occupancy,
+            b_factor,
+            charge,
+        );
+        atom.exposed = exposed;
+        atom.u_aniso = u_aniso;
+
+        atom
+    }
+
+    /// True if both atoms have the same name and exactly the same position.
     #[inline(always)]
+    pub fn is_duplicate_of(&self, other: &Atom) -> bool {
+        self.name == other.name && self.position == other.position
+    }
+
+    #[inline(always)]
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         label: AtomType,
         number: i32,
         name: &str,
+        alt_loc: Option<char>,
         element: &str,
         position: (f64, f64, f64),
         occupancy: f64,
+        b_factor: f64,
+        charge: Option<i8>,
     ) -> Self {
         Atom {
             label,
             number,
             name: name.into(),
+            alt_loc,
             element: element.into(),
             position,
             occupancy,
+            b_factor,
+            charge,
+            exposed: None,
+            u_aniso: None,
         }
     }
 }
 
+/// Beginning of the value that fits into a name field of the tree (at most 4 bytes).
+#[inline(always)]
+pub fn name_field(value: &str) -> &str {
+    let mut end = value.len().min(4);
+
+    while !value.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    &value[..end]
+}
+
+/// Center of geometry (plain mean of the positions) of the atoms, raises a ValueError when
+/// there are no atoms.
+pub fn center_of_geometry(python: Python, atoms: &[Py<Atom>]) -> PyResult<Vector> {
+    let points: Vec<(Vector, f64)> = atoms
+        .iter()
+        .map(|atom| (atom.borrow(python).position, 1.0))
+        .collect();
+
+    geometry::weighted_center(&points).ok_or_else(|| PyValueError::new_err("no atoms"))
+}
+
+/// Minimum and maximum corners of the axis-aligned bounding box of the atoms, raises a
+/// ValueError when there are no atoms.
+pub fn bounding_box(python: Python, atoms: &[Py<Atom>]) -> PyResult<(Vector, Vector)> {
+    let positions: Vec<Vector> = atoms
+        .iter()
+        .map(|atom| atom.borrow(python).position)
+        .collect();
+
+    geometry::bounds(&positions).ok_or_else(|| PyValueError::new_err("no atoms"))
+}
+
+/// Positions of the atoms paired with their atomic masses. Atoms of unknown elements have no
+/// weight and are reported with a UserWarning.
+fn mass_points(python: Python, atoms: &[Py<Atom>]) -> PyResult<Vec<(Vector, f64)>> {
+    let mut unknown: Vec<String> = Vec::new();
+
+    let points: Vec<(Vector, f64)> = atoms
+        .iter()
+        .map(|atom| {
+            let atom = atom.borrow(python);
+            let mass = elements::atomic_mass(&atom.element).unwrap_or_else(|| {
+                let element = elements::normalize(&atom.element);
+
+                if !unknown.contains(&element) {
+                    unknown.push(element);
+                }
+
+                0.0
+            });
+
+            (atom.position, mass)
+        })
+        .collect();
+
+    if !unknown.is_empty() {
+        PyErr::warn(
+            python,
+            python.get_type::<PyUserWarning>(),
+            &format!("unknown elements (mass 0.0): {}", unknown.join(", ")),
+            1,
+        )?;
+    }
+
+    Ok(points)
+}
+
+/// Center of mass of the atoms. Atoms of unknown elements have no weight and are reported
+/// with a UserWarning, a ValueError is raised when no atom has a known mass.
+pub fn center_of_mass(python: Python, atoms: &[Py<Atom>]) -> PyResult<Vector> {
+    geometry::weighted_center(&mass_points(python, atoms)?)
+        .ok_or_else(|| PyValueError::new_err("no atoms of known mass"))
+}
+
+/// Sum of the atomic masses of the atoms (hydrogens and deuteriums are skipped unless
+/// included). Atoms of unknown elements have no weight and are reported with a UserWarning.
+pub fn molecular_weight(
+    python: Python,
+    atoms: &[Py<Atom>],
+    include_hydrogens: bool,
+) -> PyResult<f64> {
+    let atoms: Vec<Py<Atom>> = atoms
+        .iter()
+        .filter(|atom| {
+            include_hydrogens
+                || !["H", "D"].contains(&elements::normalize(&atom.borrow(python).element).as_str())
+        })
+        .map(|atom| atom.clone_ref(python))
+        .collect();
+
+    Ok(mass_points(python, &atoms)?
+        .iter()
+        .map(|(_, mass)| mass)
+        .sum())
+}
+
 impl std::fmt::Display for Atom {
     fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         formatter
@@ -106,6 +648,8 @@ impl std::fmt::Display for Atom {
             .field("element", &self.element)
             .field("position", &self.position)
             .field("occupancy", &self.occupancy)
+            .field("b_factor", &self.b_factor)
+            .field("charge", &self.charge)
             .finish()
     }
 }
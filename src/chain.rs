@@ -1,13 +1,54 @@
-use crate::residue::Residue;
+use crate::{
+    atom::{self, Atom},
+    elements,
+    geometry::{dihedral, distance, Vector},
+    iterators::ResidueIterator,
+    residue::{Residue, ResidueState},
+    residue_names,
+};
 /*  */
 use pyo3::{
-    exceptions::PyIndexError, pyclass, pymethods, types::PyList, Py, PyRefMut, PyResult,
-    PyTraverseError, PyVisit, Python,
+    exceptions::{PyIndexError, PyKeyError},
+    pyclass, pymethods,
+    types::{PyList, PyType},
+    Py, PyCell, PyObject, PyRef, PyResult, PyTraverseError, PyVisit, Python,
 };
 
-use heapless;
 use indexmap::IndexMap;
 
+/// Maximum C -> N distance (in Angstroms) for which two consecutive residues are considered
+/// bonded; longer distances are treated as chain breaks.
+pub const PEPTIDE_BOND_CUTOFF: f64 = 2.0;
+
+/// CRC-64 with the ISO 3309 polynomial (reflected, zero initial value, no final XOR), the
+/// sequence checksum used by SWISS-PROT/UniProt.
+fn crc64(data: &[u8]) -> u64 {
+    let mut table = [0u64; 256];
+
+    for (index, entry) in table.iter_mut().enumerate() {
+        let mut value = index as u64;
+
+        for _ in 0..8 {
+            value = match value & 1 {
+                1 => (value >> 1) ^ 0xD800_0000_0000_0000,
+                _ => value >> 1,
+            };
+        }
+
+        *entry = value;
+    }
+
+    data.iter().fold(0, |crc, byte| {
+        table[((crc ^ *byte as u64) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
+
+/// Key of a residue within a chain: name, number and insertion code.
+pub type ResidueKey = (heapless::String<4>, i32, Option<char>);
+
+/// Pickled state of a chain: name, the states of the residues and the SEQRES residue names.
+pub type ChainState = (char, Vec<ResidueState>, Vec<String>);
+
 /// Chain - a class that represents a chain of a PDB structure.
 #[pyclass(module = "nanoPDB")]
 pub struct Chain {
@@ -15,8 +56,11 @@ pub struct Chain {
     #[pyo3(get)]
     pub name: char,
 
-    pub residues: IndexMap<(heapless::String<4>, i32), Option<Py<Residue>>>,
-    pub current_index: usize,
+    pub residues: IndexMap<ResidueKey, Option<Py<Residue>>>,
+
+    /// Residue names of the SEQRES records (the sequence of the whole chain, including the
+    /// residues missing from the coordinates).
+    pub seqres: Vec<String>,
 }
 
 #[pymethods]
@@ -31,6 +75,10 @@ impl Chain {
         }
     }
 
+    pub fn __contains__(&self, number: i32) -> bool {
+        self.find_residue(number, None).is_some()
+    }
+
     pub fn __getitem__(&self, python: Python, index: usize) -> PyResult<Py<Residue>> {
         if index < self.residues.len() {
             Ok(self.residues[index]
@@ -43,41 +91,32 @@ impl Chain {
         }
     }
 
-    pub fn __iter__(mut slf: PyRefMut<Self>) -> PyRefMut<Self> {
-        slf.current_index = 0;
-
-        slf
+    pub fn __iter__(slf: PyRef<Self>) -> ResidueIterator {
+        ResidueIterator::new(slf.into())
     }
 
     pub fn __len__(&self) -> usize {
         self.residues.len()
     }
 
-    pub fn __next__(&mut self, python: Python) -> Option<Py<Residue>> {
-        if self.current_index < self.residues.len() {
-            self.current_index += 1;
-
-            Some(
-                self.residues[self.current_index - 1]
-                    .as_ref()
-                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
-                    .as_ref(python)
-                    .into(),
-            )
-        } else {
-            None
-        }
+    pub fn __reduce__(slf: &PyCell<Self>, python: Python) -> PyResult<(PyObject, (ChainState,))> {
+        Ok((
+            slf.get_type().getattr("_from_state")?.into(),
+            (slf.borrow().state(python),),
+        ))
     }
 
     pub fn __repr__(&self) -> String {
+        format!("<Chain {}: {} residues>", self.name, self.residues.len())
+    }
+
+    pub fn __str__(&self) -> String {
         format!("{:#}", self)
     }
 
     pub fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
-        for residue in self.residues.values() {
-            if let Some(residue) = residue {
-                visit.call(residue)?;
-            }
+        for residue in self.residues.values().flatten() {
+            visit.call(residue)?;
         }
 
         Ok(())
@@ -87,6 +126,94 @@ impl Chain {
     // Methods
     // ----------------------------------------------------------------------------------------
 
+    /// Rebuilds the chain from its pickled state (see __reduce__).
+    #[classmethod]
+    #[pyo3(name = "_from_state", signature = (state, /))]
+    pub fn py_from_state(_class: &PyType, python: Python, state: ChainState) -> PyResult<Self> {
+        Chain::from_state(python, state)
+    }
+
+    /// Returns the axis-aligned bounding box of the chain (computed in a single pass).
+    ///
+    /// Useful for setting up grids, docking boxes and visualization viewports.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// ((float, float, float), (float, float, float))
+    ///     The minimum and maximum corners, a ValueError is raised for a chain without
+    ///     atoms.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Computing the bounding box of the chain.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> chain = structure[0]
+    /// ...
+    /// >>> chain.bounding_box()
+    ///
+    /// ((30.841, 6.707, 3.466), (91.927, 64.985, 75.795))
+    #[pyo3(signature = (/))]
+    pub fn bounding_box(&self, python: Python) -> PyResult<(Vector, Vector)> {
+        atom::bounding_box(python, &self.collect_atoms(python))
+    }
+
+    /// Returns the center of geometry (mean of the atom positions) of the chain.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// (float, float, float)
+    ///     The center of geometry, a ValueError is raised for a chain without atoms.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Computing the center of geometry of the chain.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> chain = structure[0]
+    /// ...
+    /// >>> chain.center_of_geometry()
+    ///
+    /// (57.025..., 36.565..., 39.136...)
+    #[pyo3(signature = (/))]
+    pub fn center_of_geometry(&self, python: Python) -> PyResult<Vector> {
+        atom::center_of_geometry(python, &self.collect_atoms(python))
+    }
+
+    /// Returns the center of mass of the chain.
+    ///
+    /// Atoms are weighted by the atomic masses of their elements. Atoms of unknown elements
+    /// have no weight and are reported with a UserWarning.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// (float, float, float)
+    ///     The center of mass, a ValueError is raised when no atom has a known mass.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Computing the center of mass of the chain.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> chain = structure[0]
+    /// ...
+    /// >>> chain.center_of_mass()
+    ///
+    /// (57.096..., 36.546..., 39.111...)
+    #[pyo3(signature = (/))]
+    pub fn center_of_mass(&self, python: Python) -> PyResult<Vector> {
+        atom::center_of_mass(python, &self.collect_atoms(python))
+    }
+
     /// Returns a list of atoms that builds the chain.
     ///
     ///
@@ -106,40 +233,9 @@ impl Chain {
     /// ...
     /// >>> chain.get_atoms()
     ///
-    /// [Atom {
-    ///     label: "ATOM",
-    ///     number: 1,
-    ///     name: "N",
-    ///     element: "N",
-    ///     position: (
-    ///         42.854,
-    ///         36.56,
-    ///         10.394,
-    ///     ),
-    ///     occupancy: 1.0,
-    /// }, Atom {
-    ///     label: "ATOM",
-    ///     number: 2,
-    ///     name: "CA",
-    ///     element: "C",
-    ///     position: (
-    ///         42.25,
-    ///         35.232,
-    ///         10.096,
-    ///     ),
-    ///     occupancy: 1.0,
-    /// }, Atom {
-    ///     label: "ATOM",
-    ///     number: 3,
-    ///     name: "C",
-    ///     element: "C",
-    ///     position: (
-    ///         41.642,
-    ///         34.623,
-    ///         11.355,
-    ///     ),
-    ///     occupancy: 1.0,
-    /// }
+    /// [<Atom N 1: (42.854, 36.56, 10.394)>,
+    ///  <Atom CA 2: (42.25, 35.232, 10.096)>,
+    ///  <Atom C 3: (41.642, 34.623, 11.355)>,
     /// ...
     #[pyo3(signature = (/))]
     pub fn get_atoms(&self, python: Python) -> PyResult<Py<PyList>> {
@@ -181,16 +277,7 @@ impl Chain {
     /// ...
     /// >>> chain.get_residues()
     ///
-    /// [Residue {
-    ///     number: -1,
-    ///     name: "MET",
-    /// }, Residue {
-    ///     number: 0,
-    ///     name: "ASP",
-    /// }, Residue {
-    ///     number: 1,
-    ///     name: "PRO",
-    /// }
+    /// [<Residue MET -1: 8 atoms>, <Residue ASP 0: 8 atoms>, <Residue PRO 1: 7 atoms>,
     /// ...
     #[pyo3(signature = (/))]
     pub fn get_residues(&self, python: Python) -> Py<PyList> {
@@ -204,6 +291,472 @@ impl Chain {
         )
         .into()
     }
+
+    /// Returns the Kyte-Doolittle hydropathy profile of the chain.
+    ///
+    /// Residues that are not amino acids (waters, ligands, nucleotides) are skipped.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// list[(int, float)]
+    ///     The list of (residue number, hydropathy score) tuples.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Retrieving the hydropathy profile of the chain.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> chain = structure[0]
+    /// ...
+    /// >>> chain.hydrophobicity_profile()
+    ///
+    /// [(-1, 1.9), (0, -3.5), (1, -1.6), (2, -0.8), (3, -3.5), ...]
+    #[pyo3(signature = (/))]
+    pub fn hydrophobicity_profile(&self, python: Python) -> Vec<(i32, f64)> {
+        self.residues
+            .values()
+            .filter_map(|residue| {
+                let residue = residue
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python);
+
+                Some((residue.number, residue.hydrophobicity()?))
+            })
+            .collect()
+    }
+
+    /// Returns the molecular weight of the chain, the sum of the atomic masses of its atoms.
+    ///
+    /// Only the atoms present in the file are counted, missing hydrogens are not added
+    /// implicitly. Atoms of unknown elements have no weight and are reported with a
+    /// UserWarning.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// include_water : bool
+    ///     Whether water molecules are counted (default False).
+    /// include_hydrogens : bool
+    ///     Whether hydrogen (and deuterium) atoms are counted (default True).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// float
+    ///     The molecular weight (in daltons).
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Weighing the chain with its waters.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> chain = structure[0]
+    /// ...
+    /// >>> round(chain.molecular_weight(True), 1)
+    ///
+    /// 51151.0
+    #[pyo3(signature = (include_water = false, include_hydrogens = true))]
+    pub fn molecular_weight(
+        &self,
+        python: Python,
+        include_water: bool,
+        include_hydrogens: bool,
+    ) -> PyResult<f64> {
+        atom::molecular_weight(
+            python,
+            &self.weighed_atoms(python, include_water),
+            include_hydrogens,
+        )
+    }
+
+    /// Returns the nucleic acid sequence of the chain.
+    ///
+    /// Nucleotides (DA, DC, DG, DT, DU, A, C, G, U) are mapped to the one-letter base codes.
+    /// Other polymer (ATOM) residues are reported as 'N', non-polymer residues (waters,
+    /// ligands) are skipped.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// str
+    ///     The base sequence.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Retrieving the sequence of a DNA strand.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1bna")
+    /// >>> chain = structure[0]
+    /// ...
+    /// >>> chain.nucleotide_sequence()
+    ///
+    /// 'CGCGAATTCGCG'
+    #[pyo3(signature = (/))]
+    pub fn nucleotide_sequence(&self, python: Python) -> String {
+        self.residues
+            .values()
+            .filter_map(|residue| {
+                let residue = residue
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python);
+
+                match residue_names::nucleotide_code(&residue.name) {
+                    Some(code) => Some(code),
+                    None if residue.is_polymer(python) => Some('N'),
+                    None => None,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the net formal charge of the chain.
+    ///
+    /// Charges are the formal charges read from columns 79-80 of the PDB file, atoms with a
+    /// blank charge field are neutral.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// int
+    ///     The sum of the formal charges of the atoms in the chain.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Computing the net charge of the chain (1ZHY has no charges in the file).
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> chain = structure[0]
+    /// ...
+    /// >>> chain.net_charge()
+    ///
+    /// 0
+    #[pyo3(signature = (/))]
+    pub fn net_charge(&self, python: Python) -> i64 {
+        self.residues
+            .values()
+            .map(|residue| {
+                residue
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python)
+                    .atoms
+                    .iter()
+                    .map(|atom| {
+                        atom.as_ref()
+                            .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                            .borrow(python)
+                            .charge
+                            .unwrap_or(0) as i64
+                    })
+                    .sum::<i64>()
+            })
+            .sum()
+    }
+
+    /// Returns the number of atoms that builds the chain.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// int
+    ///     The number of atoms in the chain.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Counting the atoms of the chain.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> chain = structure[0]
+    /// ...
+    /// >>> chain.num_atoms()
+    ///
+    /// 3806
+    #[pyo3(signature = (/))]
+    pub fn num_atoms(&self, python: Python) -> usize {
+        self.residues
+            .values()
+            .map(|residue| {
+                residue
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python)
+                    .atoms
+                    .len()
+            })
+            .sum()
+    }
+
+    /// Returns the number of residues that builds the chain (same as len(chain)).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// int
+    ///     The number of residues in the chain.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Counting the residues of the chain.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> chain = structure[0]
+    /// ...
+    /// >>> chain.num_residues()
+    ///
+    /// 701
+    #[pyo3(signature = (/))]
+    pub fn num_residues(&self) -> usize {
+        self.residues.len()
+    }
+
+    /// Returns the phi and psi backbone torsions of the residues of the chain.
+    ///
+    /// The torsions are computed from the N, CA and C atoms of consecutive residues: phi from
+    /// C(i-1), N, CA, C and psi from N, CA, C, N(i+1). Residues lacking any of the N, CA, C
+    /// atoms are skipped (and leave the torsions of their neighbours toward them undefined).
+    /// A chain break, two consecutive residues with the C -> N distance over 2.0 Angstroms,
+    /// is treated like the chain end: the phi of the first residue of the chain (or after a
+    /// break) and the psi of the last residue (or before a break) are None.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// list[(int, float | None, float | None)]
+    ///     The list of (residue number, phi, psi) tuples, angles in degrees (-180 to 180).
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Retrieving the torsions of the first residues.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> chain = structure[0]
+    /// ...
+    /// >>> chain.phi_psi()[:3]
+    ///
+    /// [(-1, None, 169.69...), (0, -79.71..., 148.10...), (1, -53.91..., -28.21...)]
+    #[pyo3(signature = (/))]
+    pub fn phi_psi(&self, python: Python) -> Vec<(i32, Option<f64>, Option<f64>)> {
+        self.backbone_torsions(python)
+    }
+
+    /// Returns the type of the polymer that builds the chain.
+    ///
+    /// Residues are classified by name as amino acids (standard or modified), ribonucleotides
+    /// (A, C, G, U) or deoxyribonucleotides (DA, DC, DG, DT, DU). Non-polymer (HETATM only)
+    /// residues with other names, like waters and ligands, are ignored. A chain built only of
+    /// amino acids is "protein", only of deoxyribonucleotides "dna" and only of ribonucleotides
+    /// "rna". Mixed chains, chains with unknown polymer residues and chains without any polymer
+    /// residue are "other".
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// str
+    ///     One of "protein", "dna", "rna" or "other".
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Checking the polymer type of the chain.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> chain = structure[0]
+    /// ...
+    /// >>> chain.polymer_type()
+    ///
+    /// 'protein'
+    #[pyo3(signature = (/))]
+    pub fn polymer_type(&self, python: Python) -> &'static str {
+        let mut polymer_type = None;
+
+        for residue in self.residues.values().map(|residue| {
+            residue
+                .as_ref()
+                .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                .borrow(python)
+        }) {
+            let residue_type = if residue_names::amino_acid_code(&residue.name).is_some() {
+                "protein"
+            } else if residue_names::is_deoxyribonucleotide(&residue.name) {
+                "dna"
+            } else if residue_names::is_ribonucleotide(&residue.name) {
+                "rna"
+            } else if residue.is_polymer(python) {
+                return "other";
+            } else {
+                continue;
+            };
+
+            match polymer_type {
+                None => polymer_type = Some(residue_type),
+                Some(current) if current != residue_type => return "other",
+                Some(_) => {}
+            }
+        }
+
+        polymer_type.unwrap_or("other")
+    }
+
+    /// Returns the residue with the given number (and insertion code).
+    ///
+    /// Unlike positional indexing, the lookup is not affected by missing residues (e.g.
+    /// disordered loops). The insertion code tells apart residues with the same number (e.g.
+    /// 100 and 100A), without it only a residue with no insertion code matches. The
+    /// residue number is also checked by `number in chain`.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// number : int
+    ///     The residue number.
+    /// insertion_code : str, optional
+    ///     The insertion code of the residue, None for residues without one.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// Residue
+    ///     The residue, a KeyError is raised if there is no such residue.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Retrieving the residue number 10 of the chain.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> chain = structure[0]
+    /// ...
+    /// >>> chain.residue(10)
+    ///
+    /// <Residue TRP 10: 14 atoms>
+    #[pyo3(signature = (number, /, insertion_code = None))]
+    pub fn residue(
+        &self,
+        python: Python,
+        number: i32,
+        insertion_code: Option<char>,
+    ) -> PyResult<Py<Residue>> {
+        match self.find_residue(number, insertion_code) {
+            Some(residue) => Ok(residue.clone_ref(python)),
+            None => Err(PyKeyError::new_err(format!(
+                "no residue: {}{}",
+                number,
+                insertion_code.map(String::from).unwrap_or_default()
+            ))),
+        }
+    }
+
+    /// Returns the residue names of the SEQRES records of the chain.
+    ///
+    /// The SEQRES records list the sequence of the whole chain, including the residues that
+    /// were not modeled (e.g. disordered loops and termini), so comparing it with the
+    /// residues of the chain reveals the gaps. Empty for chains without SEQRES records (and
+    /// for mmCIF files).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// list[str]
+    ///     The list of three-letter residue names.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Counting the residues missing from the coordinates.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> chain = structure[0]
+    /// ...
+    /// >>> len(chain.seqres()) - sum(residue.is_amino_acid() for residue in chain)
+    ///
+    /// 2
+    #[pyo3(signature = (/))]
+    pub fn seqres(&self) -> Vec<String> {
+        self.seqres.clone()
+    }
+
+    /// Returns the one-letter sequence of the chain.
+    ///
+    /// The 20 standard amino acids (and SEC, PYL) are mapped to their one-letter codes,
+    /// common modified amino acids (e.g. MSE, SEP, HYP) to the codes of their parents and
+    /// nucleotides to the base codes. Other polymer (ATOM) residues are reported as 'X',
+    /// non-polymer residues (waters, ligands) are skipped.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// str
+    ///     The one-letter sequence.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Retrieving the beginning of the sequence of the chain.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> chain = structure[0]
+    /// ...
+    /// >>> chain.sequence()[:10]
+    ///
+    /// 'MDPSQYASSS'
+    #[pyo3(signature = (/))]
+    pub fn sequence(&self, python: Python) -> String {
+        self.one_letter_sequence(python)
+    }
+
+    /// Returns the checksum of the one-letter sequence of the chain.
+    ///
+    /// The checksum is the CRC-64 (ISO 3309 polynomial, as used by SWISS-PROT/UniProt) of
+    /// the ASCII one-letter sequence, formatted as 16 uppercase hexadecimal digits. Chains
+    /// with equal sequences have equal checksums, so they can be compared across files
+    /// without comparing whole sequences.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// str
+    ///     The sequence checksum.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Computing the sequence checksum of the chain.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> chain = structure[0]
+    /// ...
+    /// >>> chain.sequence_hash()
+    ///
+    /// 'DF6AEF59DCD2892F'
+    #[pyo3(signature = (/))]
+    pub fn sequence_hash(&self, python: Python) -> String {
+        format!(
+            "{:016X}",
+            crc64(self.one_letter_sequence(python).as_bytes())
+        )
+    }
 }
 
 impl Chain {
@@ -212,8 +765,219 @@ impl Chain {
         Chain {
             name,
             residues: IndexMap::default(),
-            current_index: 0,
+            seqres: Vec::new(),
+        }
+    }
+
+    pub fn state(&self, python: Python) -> ChainState {
+        (
+            self.name,
+            self.residues
+                .values()
+                .flatten()
+                .map(|residue| residue.borrow(python).state(python))
+                .collect(),
+            self.seqres.clone(),
+        )
+    }
+
+    pub fn from_state(python: Python, state: ChainState) -> PyResult<Self> {
+        let (name, residues, seqres) = state;
+        let mut chain = Chain::new(name);
+        chain.seqres = seqres;
+
+        for residue in residues {
+            let residue = Residue::from_state(python, residue)?;
+            chain
+                .residues
+                .insert(residue.key(), Some(Py::new(python, residue)?));
+        }
+
+        Ok(chain)
+    }
+
+    /// New chain with the same name (and SEQRES sequence) and no residues.
+    pub fn empty_copy(&self) -> Chain {
+        let mut chain = Chain::new(self.name);
+        chain.seqres = self.seqres.clone();
+
+        chain
+    }
+
+    /// Copy of the chain with copies of all its residues and atoms.
+    pub fn deep_copy(&self, python: Python) -> PyResult<Chain> {
+        let mut chain = self.empty_copy();
+
+        for (key, residue) in self.residues.iter() {
+            let residue = residue
+                .as_ref()
+                .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                .borrow(python)
+                .deep_copy(python)?;
+
+            chain
+                .residues
+                .insert(key.clone(), Some(Py::new(python, residue)?));
+        }
+
+        Ok(chain)
+    }
+
+    /// All atoms of the chain (in file order).
+    pub fn collect_atoms(&self, python: Python) -> Vec<Py<Atom>> {
+        self.residues
+            .values()
+            .flat_map(|residue| {
+                residue
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python)
+                    .collect_atoms(python)
+            })
+            .collect()
+    }
+
+    /// All atoms of the chain (in file order), without the atoms of waters unless included.
+    pub fn weighed_atoms(&self, python: Python, include_water: bool) -> Vec<Py<Atom>> {
+        self.residues
+            .values()
+            .map(|residue| {
+                residue
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python)
+            })
+            .filter(|residue| include_water || !residue.is_water())
+            .flat_map(|residue| residue.collect_atoms(python))
+            .collect()
+    }
+
+    /// First residue with the number and insertion code (in file order).
+    pub fn find_residue(&self, number: i32, insertion_code: Option<char>) -> Option<&Py<Residue>> {
+        self.residues
+            .iter()
+            .find(|((_, other_number, other_code), _)| {
+                *other_number == number && *other_code == insertion_code
+            })
+            .map(|(_, residue)| {
+                residue
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+            })
+    }
+
+    /// Positions of the N, CA and C atoms of every residue (None if any of them is missing).
+    pub fn backbones(&self, python: Python) -> Vec<(i32, Option<[Vector; 3]>)> {
+        self.residues
+            .values()
+            .map(|residue| {
+                let residue = residue
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python);
+                let position = |name| {
+                    residue
+                        .find_atom(python, name)
+                        .map(|atom| atom.borrow(python).position)
+                };
+
+                match (position("N"), position("CA"), position("C")) {
+                    (Some(n), Some(ca), Some(c)) => (residue.number, Some([n, ca, c])),
+                    _ => (residue.number, None),
+                }
+            })
+            .collect()
+    }
+
+    /// Positions of all atoms of the chain paired with their atomic masses (0.0 for unknown
+    /// elements).
+    pub fn mass_points(&self, python: Python) -> Vec<(Vector, f64)> {
+        let mut points = Vec::new();
+
+        for residue in self.residues.values().map(|residue| {
+            residue
+                .as_ref()
+                .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                .borrow(python)
+        }) {
+            for atom in residue.atoms.iter().map(|atom| {
+                atom.as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python)
+            }) {
+                points.push((
+                    atom.position,
+                    elements::atomic_mass(&atom.element).unwrap_or(0.0),
+                ));
+            }
+        }
+
+        points
+    }
+
+    /// One-letter sequence of the chain. Polymer (ATOM) residues with an unknown name are
+    /// reported as 'X', unknown non-polymer residues (waters, ligands) are skipped.
+    pub fn one_letter_sequence(&self, python: Python) -> String {
+        self.residues
+            .values()
+            .filter_map(|residue| {
+                let residue = residue
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python);
+
+                match residue_names::one_letter_code(&residue.name) {
+                    Some(code) => Some(code),
+                    None if residue.is_polymer(python) => Some('X'),
+                    None => None,
+                }
+            })
+            .collect()
+    }
+
+    /// Phi and psi torsions (in degrees) of every residue with a complete backbone. A torsion
+    /// is None at the chain termini and next to chain breaks.
+    pub fn backbone_torsions(&self, python: Python) -> Vec<(i32, Option<f64>, Option<f64>)> {
+        let backbones = self.backbones(python);
+        let mut torsions = Vec::with_capacity(backbones.len());
+
+        for (index, (number, backbone)) in backbones.iter().enumerate() {
+            let Some([n, ca, c]) = *backbone else {
+                continue;
+            };
+
+            let previous = match index {
+                0 => None,
+                _ => backbones[index - 1].1,
+            };
+            let next = backbones.get(index + 1).and_then(|(_, backbone)| *backbone);
+
+            let phi = previous
+                .filter(|[_, _, previous_c]| distance(*previous_c, n) <= PEPTIDE_BOND_CUTOFF)
+                .map(|[_, _, previous_c]| dihedral(previous_c, n, ca, c));
+            let psi = next
+                .filter(|[next_n, _, _]| distance(c, *next_n) <= PEPTIDE_BOND_CUTOFF)
+                .map(|[next_n, _, _]| dihedral(n, ca, c, next_n));
+
+            torsions.push((*number, phi, psi));
         }
+
+        torsions
+    }
+
+    /// Omega torsions (in degrees) of the peptide bonds preceding every residue, reported
+    /// with the number of that residue. Chain breaks are skipped.
+    pub fn omega_torsions(&self, python: Python) -> Vec<(i32, f64)> {
+        self.backbones(python)
+            .windows(2)
+            .filter_map(|pair| {
+                let [_, previous_ca, previous_c] = pair[0].1?;
+                let [n, ca, _] = pair[1].1?;
+
+                (distance(previous_c, n) <= PEPTIDE_BOND_CUTOFF)
+                    .then(|| (pair[1].0, dihedral(previous_ca, previous_c, n, ca)))
+            })
+            .collect()
     }
 }
 
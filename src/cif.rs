@@ -0,0 +1,232 @@
+use pyo3::{exceptions::PyException, PyResult};
+
+use indexmap::IndexMap;
+
+use std::borrow::Cow;
+
+/// Single value (or keyword, or tag) of a CIF file.
+struct Token<'a> {
+    text: Cow<'a, str>,
+    /// Quoted values and text fields are never keywords, tags or null markers.
+    quoted: bool,
+}
+
+impl Token<'_> {
+    #[inline(always)]
+    fn is_tag(&self) -> bool {
+        !self.quoted && self.text.starts_with('_')
+    }
+
+    #[inline(always)]
+    fn is_keyword(&self) -> bool {
+        !self.quoted
+            && (self.text.eq_ignore_ascii_case("loop_")
+                || self.text.len() >= 5 && self.text[..5].eq_ignore_ascii_case("data_"))
+    }
+
+    /// Value of the token, None for the null markers '.' (inapplicable) and '?' (unknown).
+    #[inline(always)]
+    fn value(&self) -> Option<&str> {
+        match self.quoted || (self.text != "." && self.text != "?") {
+            true => Some(&self.text),
+            false => None,
+        }
+    }
+}
+
+/// Splits the line into tokens (whitespace separated, optionally quoted with ' or ").
+/// A quote closes the value only when followed by whitespace or the end of the line, so
+/// values like 'O5'' or "N1'" keep their inner quotes. Comments start with '#'.
+fn tokenize_line<'a>(line: &'a str, tokens: &mut Vec<Token<'a>>) {
+    let bytes = line.as_bytes();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            byte if byte.is_ascii_whitespace() => index += 1,
+            b'#' => break,
+            quote @ (b'\'' | b'"') => {
+                let start = index + 1;
+                let mut end = start;
+
+                while end < bytes.len()
+                    && !(bytes[end] == quote
+                        && bytes
+                            .get(end + 1)
+                            .is_none_or(|next| next.is_ascii_whitespace()))
+                {
+                    end += 1;
+                }
+
+                tokens.push(Token {
+                    text: Cow::Borrowed(&line[start..end]),
+                    quoted: true,
+                });
+                index = end + 1;
+            }
+            _ => {
+                let start = index;
+
+                while index < bytes.len() && !bytes[index].is_ascii_whitespace() {
+                    index += 1;
+                }
+
+                tokens.push(Token {
+                    text: Cow::Borrowed(&line[start..index]),
+                    quoted: false,
+                });
+            }
+        }
+    }
+}
+
+/// Splits the content into tokens. Text fields (lines between two lines starting with ';')
+/// become single tokens.
+fn tokenize(content: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut lines = content.lines();
+
+    while let Some(line) = lines.next() {
+        match line.strip_prefix(';') {
+            Some(first) => {
+                let mut text = first.trim().to_string();
+
+                for line in lines.by_ref() {
+                    if line.starts_with(';') {
+                        break;
+                    }
+
+                    if !text.is_empty() {
+                        text.push(' ');
+                    }
+
+                    text.push_str(line.trim());
+                }
+
+                tokens.push(Token {
+                    text: Cow::Owned(text),
+                    quoted: true,
+                });
+            }
+            None => tokenize_line(line, &mut tokens),
+        }
+    }
+
+    tokens
+}
+
+/// Table (loop_) of a CIF file: the tags of the columns and the values row by row.
+pub struct CifLoop<'a> {
+    tags: Vec<String>,
+    values: Vec<Token<'a>>,
+}
+
+impl CifLoop<'_> {
+    /// Index of the column with the tag (e.g. "_atom_site.Cartn_x").
+    pub fn column(&self, tag: &str) -> Option<usize> {
+        self.tags.iter().position(|other| other == tag)
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.values.len() / self.tags.len()
+    }
+
+    /// Value in the row and column, None for null values.
+    #[inline(always)]
+    pub fn value(&self, row: usize, column: usize) -> Option<&str> {
+        self.values[row * self.tags.len() + column].value()
+    }
+}
+
+/// Data items of the first data block of a CIF file. Items outside of loops are gathered
+/// into single-row loops of their categories, so categories are read the same way in both
+/// forms (e.g. the _atom_site of a file with a single atom).
+pub struct CifData<'a> {
+    loops: Vec<CifLoop<'a>>,
+}
+
+impl<'a> CifData<'a> {
+    /// Parses the first data block of the content.
+    pub fn parse(content: &'a str) -> PyResult<Self> {
+        let mut data = CifData { loops: Vec::new() };
+        let mut items: IndexMap<String, CifLoop> = IndexMap::new();
+
+        let mut blocks = 0;
+        let mut tokens = tokenize(content).into_iter().peekable();
+
+        while let Some(token) = tokens.next() {
+            if token.is_keyword() && token.text.eq_ignore_ascii_case("loop_") {
+                let mut tags = Vec::new();
+
+                while let Some(tag) = tokens.next_if(|token| token.is_tag()) {
+                    tags.push(tag.text.into_owned());
+                }
+
+                let mut values = Vec::new();
+
+                while let Some(value) =
+                    tokens.next_if(|token| !token.is_tag() && !token.is_keyword())
+                {
+                    values.push(value);
+                }
+
+                if tags.is_empty() || values.len() % tags.len() != 0 {
+                    return Err(PyException::new_err(format!(
+                        "error in CIF loop: {}, number of values does not match the columns",
+                        tags.first().map(String::as_str).unwrap_or("loop_")
+                    )));
+                }
+
+                data.loops.push(CifLoop { tags, values });
+            } else if token.is_keyword() {
+                // Only the first data block is read:
+                blocks += 1;
+
+                if blocks > 1 {
+                    break;
+                }
+            } else if token.is_tag() {
+                let value = tokens
+                    .next_if(|token| !token.is_tag() && !token.is_keyword())
+                    .ok_or_else(|| {
+                        PyException::new_err(format!(
+                            "error in CIF item: {}, missing value",
+                            token.text
+                        ))
+                    })?;
+
+                let category = token.text.split('.').next().unwrap_or_default();
+                let table = items
+                    .entry(category.to_string())
+                    .or_insert_with(|| CifLoop {
+                        tags: Vec::new(),
+                        values: Vec::new(),
+                    });
+
+                table.tags.push(token.text.into_owned());
+                table.values.push(value);
+            }
+        }
+
+        data.loops.extend(items.into_values());
+
+        Ok(data)
+    }
+
+    /// Value of the item (of a single-row category), None when missing or null.
+    pub fn item(&self, tag: &str) -> Option<&str> {
+        self.loops
+            .iter()
+            .filter(|table| table.num_rows() == 1)
+            .find_map(|table| table.column(tag).and_then(|column| table.value(0, column)))
+    }
+
+    /// Loop of the category (e.g. "_atom_site").
+    pub fn category_loop(&self, category: &str) -> Option<&CifLoop<'a>> {
+        self.loops.iter().find(|table| {
+            table.tags[0]
+                .strip_prefix(category)
+                .is_some_and(|rest| rest.starts_with('.'))
+        })
+    }
+}
@@ -0,0 +1,52 @@
+use pyo3::{types::PyDict, PyObject, PyResult, Python};
+
+// Column ranges (0-based, end exclusive) of the ATOM/HETATM record fields.
+pub const RECORD: (usize, usize) = (0, 6);
+pub const SERIAL: (usize, usize) = (6, 11);
+pub const NAME: (usize, usize) = (12, 16);
+pub const ALT_LOC: (usize, usize) = (16, 17);
+pub const RESIDUE_NAME: (usize, usize) = (17, 20);
+pub const CHAIN: (usize, usize) = (21, 22);
+pub const RESIDUE_NUMBER: (usize, usize) = (22, 26);
+pub const INSERTION_CODE: (usize, usize) = (26, 27);
+pub const X: (usize, usize) = (30, 38);
+pub const Y: (usize, usize) = (38, 46);
+pub const Z: (usize, usize) = (46, 54);
+pub const OCCUPANCY: (usize, usize) = (54, 60);
+pub const B_FACTOR: (usize, usize) = (60, 66);
+pub const ELEMENT: (usize, usize) = (76, 78);
+pub const CHARGE: (usize, usize) = (78, 80);
+
+/// Named column ranges, exposed to Python as nanoPDB.PDB_COLUMNS.
+static PDB_COLUMNS: &[(&str, (usize, usize))] = &[
+    ("record", RECORD),
+    ("serial", SERIAL),
+    ("name", NAME),
+    ("alt_loc", ALT_LOC),
+    ("residue_name", RESIDUE_NAME),
+    ("chain", CHAIN),
+    ("residue_number", RESIDUE_NUMBER),
+    ("insertion_code", INSERTION_CODE),
+    ("x", X),
+    ("y", Y),
+    ("z", Z),
+    ("occupancy", OCCUPANCY),
+    ("b_factor", B_FACTOR),
+    ("element", ELEMENT),
+    ("charge", CHARGE),
+];
+
+/// Read-only mapping (types.MappingProxyType) of field names to column ranges.
+pub fn pdb_columns(python: Python) -> PyResult<PyObject> {
+    let columns = PyDict::new(python);
+
+    for (name, range) in PDB_COLUMNS {
+        columns.set_item(name, range)?;
+    }
+
+    Ok(python
+        .import("types")?
+        .getattr("MappingProxyType")?
+        .call1((columns,))?
+        .into())
+}
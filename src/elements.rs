@@ -0,0 +1,189 @@
+/// Van der Waals radii (in Angstroms).
+pub static VDW_RADII: &[(&str, f64)] = &[
+    ("H", 1.20),
+    ("HE", 1.40),
+    ("LI", 1.82),
+    ("BE", 1.53),
+    ("B", 1.92),
+    ("C", 1.70),
+    ("N", 1.55),
+    ("O", 1.52),
+    ("F", 1.47),
+    ("NE", 1.54),
+    ("NA", 2.27),
+    ("MG", 1.73),
+    ("AL", 1.84),
+    ("SI", 2.10),
+    ("P", 1.80),
+    ("S", 1.80),
+    ("CL", 1.75),
+    ("AR", 1.88),
+    ("K", 2.75),
+    ("CA", 2.31),
+    ("SC", 2.11),
+    ("NI", 1.63),
+    ("CU", 1.40),
+    ("ZN", 1.39),
+    ("GA", 1.87),
+    ("GE", 2.11),
+    ("AS", 1.85),
+    ("SE", 1.90),
+    ("BR", 1.85),
+    ("KR", 2.02),
+    ("RB", 3.03),
+    ("SR", 2.49),
+    ("PD", 1.63),
+    ("AG", 1.72),
+    ("CD", 1.58),
+    ("IN", 1.93),
+    ("SN", 2.17),
+    ("SB", 2.06),
+    ("TE", 2.06),
+    ("I", 1.98),
+    ("XE", 2.16),
+    ("CS", 3.43),
+    ("BA", 2.68),
+    ("PT", 1.75),
+    ("AU", 1.66),
+    ("HG", 1.55),
+    ("TL", 1.96),
+    ("PB", 2.02),
+    ("BI", 2.07),
+    ("PO", 1.97),
+    ("AT", 2.02),
+    ("RN", 2.20),
+    ("FR", 3.48),
+    ("RA", 2.83),
+    ("U", 1.86),
+];
+
+/// Standard atomic weights (in daltons).
+pub static ATOMIC_MASSES: &[(&str, f64)] = &[
+    ("H", 1.008),
+    ("D", 2.014),
+    ("HE", 4.0026),
+    ("LI", 6.94),
+    ("BE", 9.0122),
+    ("B", 10.81),
+    ("C", 12.011),
+    ("N", 14.007),
+    ("O", 15.999),
+    ("F", 18.998),
+    ("NA", 22.990),
+    ("MG", 24.305),
+    ("AL", 26.982),
+    ("SI", 28.085),
+    ("P", 30.974),
+    ("S", 32.06),
+    ("CL", 35.45),
+    ("K", 39.098),
+    ("CA", 40.078),
+    ("V", 50.942),
+    ("CR", 51.996),
+    ("MN", 54.938),
+    ("FE", 55.845),
+    ("CO", 58.933),
+    ("NI", 58.693),
+    ("CU", 63.546),
+    ("ZN", 65.38),
+    ("GA", 69.723),
+    ("AS", 74.922),
+    ("SE", 78.971),
+    ("BR", 79.904),
+    ("RB", 85.468),
+    ("SR", 87.62),
+    ("MO", 95.95),
+    ("AG", 107.87),
+    ("CD", 112.41),
+    ("I", 126.90),
+    ("CS", 132.91),
+    ("BA", 137.33),
+    ("W", 183.84),
+    ("PT", 195.08),
+    ("AU", 196.97),
+    ("HG", 200.59),
+    ("PB", 207.2),
+    ("U", 238.03),
+];
+
+/// Radius used for elements missing from the van der Waals radii table (carbon).
+pub const DEFAULT_VDW_RADIUS: f64 = 1.70;
+
+/// CPK colors (RasMol convention).
+static CPK_COLORS: &[(&str, (u8, u8, u8))] = &[
+    ("H", (255, 255, 255)),
+    ("HE", (255, 192, 203)),
+    ("LI", (178, 34, 34)),
+    ("B", (0, 255, 0)),
+    ("C", (200, 200, 200)),
+    ("N", (143, 143, 255)),
+    ("O", (240, 0, 0)),
+    ("F", (218, 165, 32)),
+    ("NA", (0, 0, 255)),
+    ("MG", (34, 139, 34)),
+    ("AL", (128, 128, 144)),
+    ("SI", (218, 165, 32)),
+    ("P", (255, 165, 0)),
+    ("S", (255, 200, 50)),
+    ("CL", (0, 255, 0)),
+    ("CA", (128, 128, 144)),
+    ("TI", (128, 128, 144)),
+    ("CR", (128, 128, 144)),
+    ("MN", (128, 128, 144)),
+    ("FE", (255, 165, 0)),
+    ("NI", (165, 42, 42)),
+    ("CU", (165, 42, 42)),
+    ("ZN", (165, 42, 42)),
+    ("BR", (165, 42, 42)),
+    ("AG", (128, 128, 144)),
+    ("I", (160, 32, 240)),
+    ("BA", (255, 165, 0)),
+    ("AU", (218, 165, 32)),
+];
+
+/// CPK color of elements missing from the table.
+const CPK_DEFAULT_COLOR: (u8, u8, u8) = (255, 20, 147);
+
+#[inline(always)]
+pub fn normalize(element: &str) -> String {
+    element.trim().to_uppercase()
+}
+
+/// Element symbol in the conventional capitalization (e.g. "ZN" -> "Zn").
+pub fn symbol(element: &str) -> String {
+    let element = normalize(element);
+    let mut characters = element.chars();
+
+    match characters.next() {
+        Some(first) => first.to_string() + &characters.as_str().to_lowercase(),
+        None => String::new(),
+    }
+}
+
+pub fn atomic_mass(element: &str) -> Option<f64> {
+    let element = normalize(element);
+
+    ATOMIC_MASSES
+        .iter()
+        .find(|(symbol, _)| *symbol == element)
+        .map(|(_, mass)| *mass)
+}
+
+pub fn cpk_color(element: &str) -> (u8, u8, u8) {
+    let element = normalize(element);
+
+    CPK_COLORS
+        .iter()
+        .find(|(symbol, _)| *symbol == element)
+        .map(|(_, color)| *color)
+        .unwrap_or(CPK_DEFAULT_COLOR)
+}
+
+pub fn vdw_radius(element: &str) -> Option<f64> {
+    let element = normalize(element);
+
+    VDW_RADII
+        .iter()
+        .find(|(symbol, _)| *symbol == element)
+        .map(|(_, radius)| *radius)
+}
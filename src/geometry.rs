@@ -0,0 +1,479 @@
+use crate::grid::NeighborGrid;
+use nalgebra::{Matrix3, Vector3};
+
+/// Point or vector in 3D space.
+pub type Vector = (f64, f64, f64);
+
+/// Initial search radius (and neighbor grid cell size) for the cavity search.
+const CAVITY_SEARCH_RADIUS: f64 = 4.0;
+
+#[inline(always)]
+pub fn add(a: Vector, b: Vector) -> Vector {
+    (a.0 + b.0, a.1 + b.1, a.2 + b.2)
+}
+
+#[inline(always)]
+pub fn sub(a: Vector, b: Vector) -> Vector {
+    (a.0 - b.0, a.1 - b.1, a.2 - b.2)
+}
+
+#[inline(always)]
+pub fn dot(a: Vector, b: Vector) -> f64 {
+    a.0 * b.0 + a.1 * b.1 + a.2 * b.2
+}
+
+#[inline(always)]
+pub fn cross(a: Vector, b: Vector) -> Vector {
+    (
+        a.1 * b.2 - a.2 * b.1,
+        a.2 * b.0 - a.0 * b.2,
+        a.0 * b.1 - a.1 * b.0,
+    )
+}
+
+#[inline(always)]
+pub fn scale(a: Vector, factor: f64) -> Vector {
+    (a.0 * factor, a.1 * factor, a.2 * factor)
+}
+
+#[inline(always)]
+pub fn norm(a: Vector) -> f64 {
+    dot(a, a).sqrt()
+}
+
+#[inline(always)]
+pub fn distance(a: Vector, b: Vector) -> f64 {
+    norm(sub(a, b))
+}
+
+#[inline(always)]
+pub fn squared_distance(a: Vector, b: Vector) -> f64 {
+    let difference = sub(a, b);
+    dot(difference, difference)
+}
+
+/// Root-mean-square deviation between corresponding points of two sets of the same length,
+/// None if there are no points.
+pub fn rmsd(a: &[Vector], b: &[Vector]) -> Option<f64> {
+    if a.is_empty() {
+        return None;
+    }
+
+    let total: f64 = a
+        .iter()
+        .zip(b)
+        .map(|(first, second)| squared_distance(*first, *second))
+        .sum();
+
+    Some((total / a.len() as f64).sqrt())
+}
+
+/// Weighted mean of the points, None if there are no points or all weights are zero.
+pub fn weighted_center(points: &[(Vector, f64)]) -> Option<Vector> {
+    let total: f64 = points.iter().map(|(_, weight)| *weight).sum();
+
+    if total <= 0.0 {
+        return None;
+    }
+
+    let sum = points.iter().fold((0.0, 0.0, 0.0), |sum, (point, weight)| {
+        add(sum, scale(*point, *weight))
+    });
+
+    Some(scale(sum, 1.0 / total))
+}
+
+/// Angle (in degrees, range 0..180) at the vertex b between the b -> a and b -> c directions.
+pub fn angle(a: Vector, b: Vector, c: Vector) -> f64 {
+    let u = sub(a, b);
+    let v = sub(c, b);
+
+    (dot(u, v) / (norm(u) * norm(v)))
+        .clamp(-1.0, 1.0)
+        .acos()
+        .to_degrees()
+}
+
+/// Torsion angle (in degrees, range -180..180) defined by four points. The sign follows
+/// the IUPAC convention: positive when, looking along b -> c, the a -> b bond has to be
+/// rotated clockwise to eclipse the c -> d bond.
+pub fn dihedral(a: Vector, b: Vector, c: Vector, d: Vector) -> f64 {
+    let b0 = sub(a, b);
+    let b1 = sub(c, b);
+    let b2 = sub(d, c);
+
+    let b1 = scale(b1, 1.0 / norm(b1));
+    let v = sub(b0, scale(b1, dot(b0, b1)));
+    let w = sub(b2, scale(b1, dot(b2, b1)));
+
+    let x = dot(v, w);
+    let y = dot(cross(b1, v), w);
+
+    y.atan2(x).to_degrees()
+}
+
+/// Product of the 3x3 matrix and the vector.
+#[inline(always)]
+pub fn transform(matrix: [[f64; 3]; 3], a: Vector) -> Vector {
+    (
+        matrix[0][0] * a.0 + matrix[0][1] * a.1 + matrix[0][2] * a.2,
+        matrix[1][0] * a.0 + matrix[1][1] * a.1 + matrix[1][2] * a.2,
+        matrix[2][0] * a.0 + matrix[2][1] * a.1 + matrix[2][2] * a.2,
+    )
+}
+
+/// True for proper rotation matrices (orthonormal rows, determinant 1), up to the tolerance
+/// of every element of the product with the transpose.
+pub fn is_rotation(matrix: [[f64; 3]; 3], tolerance: f64) -> bool {
+    let rows = matrix.map(|row| (row[0], row[1], row[2]));
+
+    (0..3).all(|i| {
+        (0..3).all(|j| {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            (dot(rows[i], rows[j]) - expected).abs() <= tolerance
+        })
+    }) && dot(cross(rows[0], rows[1]), rows[2]) > 0.0
+}
+
+/// Gyration tensor (mean outer product of the centered positions) of the points, None if
+/// there are no points.
+pub fn gyration_tensor(points: &[Vector]) -> Option<[[f64; 3]; 3]> {
+    let center = weighted_center(
+        &points
+            .iter()
+            .map(|point| (*point, 1.0))
+            .collect::<Vec<(Vector, f64)>>(),
+    )?;
+
+    let mut tensor = [[0.0; 3]; 3];
+
+    for point in points {
+        let offset = sub(*point, center);
+        let offset = [offset.0, offset.1, offset.2];
+
+        for i in 0..3 {
+            for j in 0..3 {
+                tensor[i][j] += offset[i] * offset[j] / points.len() as f64;
+            }
+        }
+    }
+
+    Some(tensor)
+}
+
+/// Eigenvalues (in ascending order) of a symmetric 3x3 matrix, computed analytically with
+/// the trigonometric method.
+pub fn symmetric_eigenvalues(matrix: [[f64; 3]; 3]) -> [f64; 3] {
+    let off_diagonal = matrix[0][1].powi(2) + matrix[0][2].powi(2) + matrix[1][2].powi(2);
+
+    if off_diagonal == 0.0 {
+        let mut eigenvalues = [matrix[0][0], matrix[1][1], matrix[2][2]];
+        eigenvalues.sort_unstable_by(f64::total_cmp);
+
+        return eigenvalues;
+    }
+
+    let q = (matrix[0][0] + matrix[1][1] + matrix[2][2]) / 3.0;
+    let p = (((matrix[0][0] - q).powi(2)
+        + (matrix[1][1] - q).powi(2)
+        + (matrix[2][2] - q).powi(2)
+        + 2.0 * off_diagonal)
+        / 6.0)
+        .sqrt();
+
+    // Determinant of (matrix - q * I) / p, halved.
+    let b = |i: usize, j: usize| (matrix[i][j] - if i == j { q } else { 0.0 }) / p;
+    let r = (b(0, 0) * (b(1, 1) * b(2, 2) - b(1, 2) * b(2, 1))
+        - b(0, 1) * (b(1, 0) * b(2, 2) - b(1, 2) * b(2, 0))
+        + b(0, 2) * (b(1, 0) * b(2, 1) - b(1, 1) * b(2, 0)))
+        / 2.0;
+
+    let phi = r.clamp(-1.0, 1.0).acos() / 3.0;
+    let largest = q + 2.0 * p * phi.cos();
+    let smallest = q + 2.0 * p * (phi + 2.0 * std::f64::consts::PI / 3.0).cos();
+
+    [smallest, 3.0 * q - largest - smallest, largest]
+}
+
+/// Optimal superposition (least-squares rigid-body fit, the Kabsch problem) of the mobile
+/// points onto the corresponding reference points, as a rotation matrix and a translation
+/// (p becomes rotation @ p + translation). None if there are no points.
+///
+/// The rotation is V @ D @ U^T, where U and V come from the singular value decomposition of
+/// the covariance of the centered points and D flips the last singular vector when the
+/// determinant of V @ U^T is negative, so that mirror images never give a reflection.
+pub fn superposition(mobile: &[Vector], reference: &[Vector]) -> Option<([[f64; 3]; 3], Vector)> {
+    let center = |points: &[Vector]| {
+        weighted_center(
+            &points
+                .iter()
+                .map(|point| (*point, 1.0))
+                .collect::<Vec<(Vector, f64)>>(),
+        )
+    };
+
+    let mobile_center = center(mobile)?;
+    let reference_center = center(reference)?;
+
+    let mut covariance = Matrix3::<f64>::zeros();
+
+    for (first, second) in mobile.iter().zip(reference) {
+        let first = sub(*first, mobile_center);
+        let second = sub(*second, reference_center);
+
+        covariance += Vector3::new(first.0, first.1, first.2)
+            * Vector3::new(second.0, second.1, second.2).transpose();
+    }
+
+    let svd = covariance.svd(true, true);
+    let u = svd.u?;
+    let v = svd.v_t?.transpose();
+
+    let sign = (v * u.transpose()).determinant().signum();
+    let correction = Matrix3::from_diagonal(&Vector3::new(1.0, 1.0, sign));
+    let rotation = v * correction * u.transpose();
+    let rotation: [[f64; 3]; 3] =
+        std::array::from_fn(|i| std::array::from_fn(|j| rotation[(i, j)]));
+
+    Some((
+        rotation,
+        sub(reference_center, transform(rotation, mobile_center)),
+    ))
+}
+
+const HULL_EPSILON: f64 = 1e-9;
+
+#[inline(always)]
+fn face_normal(points: &[Vector], face: [usize; 3]) -> Vector {
+    let normal = cross(
+        sub(points[face[1]], points[face[0]]),
+        sub(points[face[2]], points[face[0]]),
+    );
+
+    scale(normal, 1.0 / norm(normal))
+}
+
+/// Faces of the convex hull of the points computed with the incremental algorithm, as
+/// triangles (point indices) with outward unit normals. Degenerate inputs (fewer than four
+/// points, or all points collinear or coplanar) have no hull (None).
+pub fn hull_faces(points: &[Vector]) -> Option<Vec<([usize; 3], Vector)>> {
+    if points.len() < 4 {
+        return None;
+    }
+
+    // Initial tetrahedron from the most distant points.
+    let farthest = |measure: &dyn Fn(Vector) -> f64| {
+        (0..points.len())
+            .map(|index| (index, measure(points[index])))
+            .fold((0, f64::MIN), |best, current| match current.1 > best.1 {
+                true => current,
+                false => best,
+            })
+    };
+
+    let i0 = 0;
+    let (i1, length) = farthest(&|point| distance(point, points[i0]));
+
+    if length < HULL_EPSILON {
+        return None;
+    }
+
+    let axis = scale(sub(points[i1], points[i0]), 1.0 / length);
+    let (i2, height) = farthest(&|point| norm(cross(sub(point, points[i0]), axis)));
+
+    if height < HULL_EPSILON {
+        return None;
+    }
+
+    let normal = face_normal(points, [i0, i1, i2]);
+    let (i3, depth) = farthest(&|point| dot(sub(point, points[i0]), normal).abs());
+
+    if depth < HULL_EPSILON {
+        return None;
+    }
+
+    let mut faces: Vec<([usize; 3], Vector)> = Vec::new();
+
+    for (face, opposite) in [
+        ([i0, i1, i2], i3),
+        ([i0, i1, i3], i2),
+        ([i0, i2, i3], i1),
+        ([i1, i2, i3], i0),
+    ] {
+        let normal = face_normal(points, face);
+
+        if dot(sub(points[opposite], points[face[0]]), normal) > 0.0 {
+            let face = [face[0], face[2], face[1]];
+            faces.push((face, face_normal(points, face)));
+        } else {
+            faces.push((face, normal));
+        }
+    }
+
+    // Adding the remaining points one by one.
+    for (index, point) in points.iter().enumerate() {
+        if [i0, i1, i2, i3].contains(&index) {
+            continue;
+        }
+
+        let visible: Vec<bool> = faces
+            .iter()
+            .map(|(face, normal)| dot(sub(*point, points[face[0]]), *normal) > HULL_EPSILON)
+            .collect();
+
+        if !visible.contains(&true) {
+            continue;
+        }
+
+        let edges: std::collections::HashSet<(usize, usize)> = faces
+            .iter()
+            .zip(visible.iter())
+            .filter(|(_, visible)| **visible)
+            .flat_map(|(face, _)| {
+                let face = face.0;
+                [(face[0], face[1]), (face[1], face[2]), (face[2], face[0])]
+            })
+            .collect();
+
+        let mut remaining: Vec<([usize; 3], Vector)> = faces
+            .iter()
+            .zip(visible.iter())
+            .filter(|(_, visible)| !**visible)
+            .map(|(face, _)| *face)
+            .collect();
+
+        for (a, b) in edges.iter() {
+            if !edges.contains(&(*b, *a)) {
+                let face = [*a, *b, index];
+                remaining.push((face, face_normal(points, face)));
+            }
+        }
+
+        faces = remaining;
+    }
+
+    Some(faces)
+}
+
+/// Convex hull of the points. Returns the hull vertices (in input order) and the enclosed
+/// volume. Degenerate inputs have no volume, so all distinct points are returned with a
+/// volume of 0.0.
+pub fn convex_hull(points: &[Vector]) -> (Vec<Vector>, f64) {
+    let Some(faces) = hull_faces(points) else {
+        let mut distinct: Vec<Vector> = Vec::new();
+
+        for point in points {
+            if !distinct.contains(point) {
+                distinct.push(*point);
+            }
+        }
+
+        return (distinct, 0.0);
+    };
+
+    let volume = faces
+        .iter()
+        .map(|(face, _)| dot(points[face[0]], cross(points[face[1]], points[face[2]])))
+        .sum::<f64>()
+        / 6.0;
+
+    let mut vertices: Vec<usize> = faces.iter().flat_map(|(face, _)| *face).collect();
+    vertices.sort_unstable();
+    vertices.dedup();
+
+    (
+        vertices.into_iter().map(|index| points[index]).collect(),
+        volume,
+    )
+}
+
+/// Largest distance between any two of the points (0.0 for fewer than two points). The two
+/// most distant points are always vertices of the convex hull, so only the hull vertices
+/// are compared pairwise.
+pub fn diameter(points: &[Vector]) -> f64 {
+    let (vertices, _) = convex_hull(points);
+    let mut largest: f64 = 0.0;
+
+    for (index, first) in vertices.iter().enumerate() {
+        for second in vertices.iter().skip(index + 1) {
+            largest = largest.max(distance(*first, *second));
+        }
+    }
+
+    largest
+}
+
+/// Axis-aligned bounds (minimum and maximum corners) of the points, None if there are none.
+pub fn bounds(points: &[Vector]) -> Option<(Vector, Vector)> {
+    let first = *points.first()?;
+
+    Some(points.iter().fold((first, first), |(min, max), point| {
+        (
+            (min.0.min(point.0), min.1.min(point.1), min.2.min(point.2)),
+            (max.0.max(point.0), max.1.max(point.1), max.2.max(point.2)),
+        )
+    }))
+}
+
+/// Radius of the largest sphere that overlaps no atom (given as position and radius) and
+/// lies entirely inside the convex hull of the atom centers. Candidate centers are the
+/// nodes of a regular grid over the bounding box, so the result is accurate to about the
+/// grid spacing. Returns 0.0 when the hull is degenerate or no such sphere exists.
+pub fn largest_empty_sphere(atoms: &[(Vector, f64)], grid_spacing: f64) -> f64 {
+    let points: Vec<Vector> = atoms.iter().map(|(position, _)| *position).collect();
+
+    let (Some(faces), Some((min, max))) = (hull_faces(&points), bounds(&points)) else {
+        return 0.0;
+    };
+
+    let max_atom_radius = atoms.iter().map(|(_, radius)| *radius).fold(0.0, f64::max);
+    let grid = NeighborGrid::new(points.clone(), CAVITY_SEARCH_RADIUS);
+    let steps = |from: f64, to: f64| ((to - from) / grid_spacing).ceil() as usize + 1;
+
+    let mut largest: f64 = 0.0;
+
+    for i in 0..steps(min.0, max.0) {
+        for j in 0..steps(min.1, max.1) {
+            for k in 0..steps(min.2, max.2) {
+                let center = (
+                    min.0 + i as f64 * grid_spacing,
+                    min.1 + j as f64 * grid_spacing,
+                    min.2 + k as f64 * grid_spacing,
+                );
+
+                // Distance to the hull boundary (negative outside the hull).
+                let boundary = faces
+                    .iter()
+                    .map(|(face, normal)| -dot(sub(center, points[face[0]]), *normal))
+                    .fold(f64::MAX, f64::min);
+
+                if boundary <= largest {
+                    continue;
+                }
+
+                // Distance to the nearest atom surface, widening the search until exact.
+                let mut search_radius = CAVITY_SEARCH_RADIUS;
+
+                let clearance = loop {
+                    let clearance = grid
+                        .within(center, search_radius)
+                        .into_iter()
+                        .map(|index| distance(points[index], center) - atoms[index].1)
+                        .fold(f64::MAX, f64::min);
+
+                    if clearance <= search_radius - max_atom_radius
+                        || search_radius - max_atom_radius >= boundary
+                    {
+                        break clearance;
+                    }
+
+                    search_radius *= 2.0;
+                };
+
+                largest = largest.max(clearance.min(boundary));
+            }
+        }
+    }
+
+    largest
+}
@@ -0,0 +1,84 @@
+use crate::geometry::{distance, Vector};
+
+use std::collections::HashMap;
+
+/// NeighborGrid - a spatial index (uniform grid of cubic cells) over a set of points.
+///
+/// A query with radius r visits the ceil(r / cell_size) neighbouring cells in every
+/// direction, so the cell size should be close to the typical query radius: smaller cells
+/// mean more (mostly empty) cells visited, larger cells mean more points compared.
+pub struct NeighborGrid {
+    pub points: Vec<Vector>,
+    pub cell_size: f64,
+    pub cells: HashMap<(i64, i64, i64), Vec<usize>>,
+}
+
+impl NeighborGrid {
+    pub fn new(points: Vec<Vector>, cell_size: f64) -> Self {
+        let mut cells: HashMap<(i64, i64, i64), Vec<usize>> = HashMap::new();
+
+        for (index, point) in points.iter().enumerate() {
+            cells
+                .entry(Self::cell_of(*point, cell_size))
+                .or_default()
+                .push(index);
+        }
+
+        NeighborGrid {
+            points,
+            cell_size,
+            cells,
+        }
+    }
+
+    #[inline(always)]
+    fn cell_of(point: Vector, cell_size: f64) -> (i64, i64, i64) {
+        (
+            (point.0 / cell_size).floor() as i64,
+            (point.1 / cell_size).floor() as i64,
+            (point.2 / cell_size).floor() as i64,
+        )
+    }
+
+    /// Indices of the points within the radius (inclusive) of the center.
+    pub fn within(&self, center: Vector, radius: f64) -> Vec<usize> {
+        let mut found = Vec::new();
+        let reach = (radius / self.cell_size).ceil() as i64;
+        let (x, y, z) = Self::cell_of(center, self.cell_size);
+
+        for dx in -reach..=reach {
+            for dy in -reach..=reach {
+                for dz in -reach..=reach {
+                    if let Some(indices) = self.cells.get(&(x + dx, y + dy, z + dz)) {
+                        found.extend(
+                            indices
+                                .iter()
+                                .filter(|index| distance(self.points[**index], center) <= radius),
+                        );
+                    }
+                }
+            }
+        }
+
+        found
+    }
+
+    /// Pairs of indices (first lower than second, sorted) of the points within the cutoff
+    /// (inclusive) of each other.
+    pub fn pairs(&self, cutoff: f64) -> Vec<(usize, usize)> {
+        let mut pairs: Vec<(usize, usize)> = self
+            .points
+            .iter()
+            .enumerate()
+            .flat_map(|(first, point)| {
+                self.within(*point, cutoff)
+                    .into_iter()
+                    .filter(move |second| *second > first)
+                    .map(move |second| (first, second))
+            })
+            .collect();
+
+        pairs.sort_unstable();
+        pairs
+    }
+}
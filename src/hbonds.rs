@@ -0,0 +1,80 @@
+use crate::{geometry::Vector, grid::NeighborGrid, residue_names};
+
+/// Side chain hydrogen bond donors of the standard amino acids.
+static SIDE_CHAIN_DONORS: &[(&str, &[&str])] = &[
+    ("ARG", &["NE", "NH1", "NH2"]),
+    ("ASN", &["ND2"]),
+    ("GLN", &["NE2"]),
+    ("HIS", &["ND1", "NE2"]),
+    ("LYS", &["NZ"]),
+    ("SER", &["OG"]),
+    ("THR", &["OG1"]),
+    ("TRP", &["NE1"]),
+    ("TYR", &["OH"]),
+];
+
+/// Side chain hydrogen bond acceptors of the standard amino acids.
+static SIDE_CHAIN_ACCEPTORS: &[(&str, &[&str])] = &[
+    ("ASN", &["OD1"]),
+    ("ASP", &["OD1", "OD2"]),
+    ("GLN", &["OE1"]),
+    ("GLU", &["OE1", "OE2"]),
+    ("HIS", &["ND1", "NE2"]),
+    ("SER", &["OG"]),
+    ("THR", &["OG1"]),
+    ("TYR", &["OH"]),
+];
+
+/// Polar atom taking part in the hydrogen bond search.
+pub struct PolarAtom {
+    pub index: usize,
+    pub residue: (char, usize),
+    pub position: Vector,
+    pub donor: bool,
+    pub acceptor: bool,
+}
+
+/// Donor and acceptor roles (donor, acceptor) of an atom. Amino acids use the backbone
+/// (N donor except in PRO, O and OXT acceptors) and the side chain tables, water oxygens
+/// are both, nitrogens and oxygens of other residues are treated as both.
+pub fn roles(residue_name: &str, atom_name: &str, element: &str) -> (bool, bool) {
+    if residue_names::amino_acid_code(residue_name).is_some() {
+        let donor = (atom_name == "N" && residue_name != "PRO")
+            || residue_names::lists_atom(SIDE_CHAIN_DONORS, residue_name, atom_name);
+        let acceptor = atom_name == "O"
+            || atom_name == "OXT"
+            || residue_names::lists_atom(SIDE_CHAIN_ACCEPTORS, residue_name, atom_name);
+
+        return (donor, acceptor);
+    }
+
+    let polar = element == "N" || element == "O";
+
+    (polar, polar)
+}
+
+/// Donor-acceptor pairs (as atom indices) closer than the cutoff. Atoms of the same residue
+/// are not paired and every pair is reported once, oriented donor first.
+pub fn hydrogen_bonds(atoms: &[PolarAtom], cutoff: f64) -> Vec<(usize, usize)> {
+    let grid = NeighborGrid::new(atoms.iter().map(|atom| atom.position).collect(), cutoff);
+    let mut bonds = Vec::new();
+
+    for (first_index, first) in atoms.iter().enumerate() {
+        for second_index in grid.within(first.position, cutoff) {
+            let second = &atoms[second_index];
+
+            if second_index <= first_index || first.residue == second.residue {
+                continue;
+            }
+
+            if first.donor && second.acceptor {
+                bonds.push((first.index, second.index));
+            } else if second.donor && first.acceptor {
+                bonds.push((second.index, first.index));
+            }
+        }
+    }
+
+    bonds.sort_unstable();
+    bonds
+}
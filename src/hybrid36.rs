@@ -0,0 +1,64 @@
+/// Digits of the upper case and the lower case base-36 numbers.
+const UPPER_DIGITS: &[u8; 36] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const LOWER_DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// Decodes a hybrid-36 field of the width (5 for atom serial numbers, 4 for residue
+/// numbers). Values that fit into the width are plain decimal numbers (e.g. "99999"), larger
+/// ones are base-36 numbers of the full width starting with a letter: upper case numbers
+/// continue the decimal range ("A0000" = 100000 for the width 5), lower case numbers
+/// continue after the upper case ones ("a0000" = 43770016). None for malformed fields.
+pub fn decode(field: &str, width: u32) -> Option<i32> {
+    let field = field.trim();
+    let first = field.chars().next()?;
+
+    if !first.is_ascii_alphabetic() {
+        return field.parse().ok();
+    }
+
+    let upper = first.is_ascii_uppercase();
+
+    if field.len() != width as usize
+        || !field.chars().all(|character| {
+            character.is_ascii_digit()
+                || character.is_ascii_alphabetic() && character.is_ascii_uppercase() == upper
+        })
+    {
+        return None;
+    }
+
+    let value = i64::from_str_radix(field, 36).ok()?;
+    let offset = match upper {
+        true => -10 * 36_i64.pow(width - 1),
+        false => 16 * 36_i64.pow(width - 1),
+    };
+
+    i32::try_from(value + offset + 10_i64.pow(width)).ok()
+}
+
+/// Encodes the value into a hybrid-36 field of the width (right-justified decimal while the
+/// value fits). Values beyond the hybrid-36 range are written in decimal.
+pub fn encode(value: i32, width: u32) -> String {
+    let value = i64::from(value);
+    let decimal_limit = 10_i64.pow(width);
+    let letters_start = 10 * 36_i64.pow(width - 1);
+    let block = 26 * 36_i64.pow(width - 1);
+
+    let (mut number, digits) = if value < decimal_limit {
+        return format!("{:>1$}", value, width as usize);
+    } else if value < decimal_limit + block {
+        (value - decimal_limit + letters_start, UPPER_DIGITS)
+    } else if value < decimal_limit + 2 * block {
+        (value - decimal_limit - block + letters_start, LOWER_DIGITS)
+    } else {
+        return value.to_string();
+    };
+
+    let mut field = vec![b'0'; width as usize];
+
+    for position in field.iter_mut().rev() {
+        *position = digits[(number % 36) as usize];
+        number /= 36;
+    }
+
+    String::from_utf8(field).expect(concat!("encoding error in: ", file!(), ", line: ", line!()))
+}
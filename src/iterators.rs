@@ -0,0 +1,412 @@
+use crate::{atom::Atom, chain::Chain, residue::Residue, structure::Structure};
+
+use pyo3::{pyclass, pymethods, Py, PyRef, PyTraverseError, PyVisit, Python};
+
+/// ChainIterator - an iterator over the chains of a structure.
+#[pyclass(module = "nanoPDB")]
+pub struct ChainIterator {
+    pub structure: Option<Py<Structure>>,
+    pub index: usize,
+}
+
+#[pymethods]
+impl ChainIterator {
+    // ----------------------------------------------------------------------------------------
+    // Special methods
+    // ----------------------------------------------------------------------------------------
+
+    pub fn __clear__(&mut self) {
+        self.structure = None;
+    }
+
+    pub fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    pub fn __next__(&mut self, python: Python) -> Option<Py<Chain>> {
+        let structure = self
+            .structure
+            .as_ref()
+            .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+            .borrow(python);
+
+        let (_, chain) = structure.chains.get_index(self.index)?;
+        self.index += 1;
+
+        Some(
+            chain
+                .as_ref()
+                .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                .clone_ref(python),
+        )
+    }
+
+    pub fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        if let Some(structure) = &self.structure {
+            visit.call(structure)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ChainIterator {
+    #[inline(always)]
+    pub fn new(structure: Py<Structure>) -> Self {
+        ChainIterator {
+            structure: Some(structure),
+            index: 0,
+        }
+    }
+}
+
+/// ResidueIterator - an iterator over the residues of a chain.
+#[pyclass(module = "nanoPDB")]
+pub struct ResidueIterator {
+    pub chain: Option<Py<Chain>>,
+    pub index: usize,
+}
+
+#[pymethods]
+impl ResidueIterator {
+    // ----------------------------------------------------------------------------------------
+    // Special methods
+    // ----------------------------------------------------------------------------------------
+
+    pub fn __clear__(&mut self) {
+        self.chain = None;
+    }
+
+    pub fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    pub fn __next__(&mut self, python: Python) -> Option<Py<Residue>> {
+        let chain = self
+            .chain
+            .as_ref()
+            .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+            .borrow(python);
+
+        let (_, residue) = chain.residues.get_index(self.index)?;
+        self.index += 1;
+
+        Some(
+            residue
+                .as_ref()
+                .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                .clone_ref(python),
+        )
+    }
+
+    pub fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        if let Some(chain) = &self.chain {
+            visit.call(chain)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ResidueIterator {
+    #[inline(always)]
+    pub fn new(chain: Py<Chain>) -> Self {
+        ResidueIterator {
+            chain: Some(chain),
+            index: 0,
+        }
+    }
+}
+
+/// AtomIterator - an iterator over the atoms of a residue.
+#[pyclass(module = "nanoPDB")]
+pub struct AtomIterator {
+    pub residue: Option<Py<Residue>>,
+    pub index: usize,
+}
+
+#[pymethods]
+impl AtomIterator {
+    // ----------------------------------------------------------------------------------------
+    // Special methods
+    // ----------------------------------------------------------------------------------------
+
+    pub fn __clear__(&mut self) {
+        self.residue = None;
+    }
+
+    pub fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    pub fn __next__(&mut self, python: Python) -> Option<Py<Atom>> {
+        let residue = self
+            .residue
+            .as_ref()
+            .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+            .borrow(python);
+
+        let atom = residue.atoms.get(self.index)?;
+        self.index += 1;
+
+        Some(
+            atom.as_ref()
+                .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                .clone_ref(python),
+        )
+    }
+
+    pub fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        if let Some(residue) = &self.residue {
+            visit.call(residue)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl AtomIterator {
+    #[inline(always)]
+    pub fn new(residue: Py<Residue>) -> Self {
+        AtomIterator {
+            residue: Some(residue),
+            index: 0,
+        }
+    }
+}
+
+/// ElementIterator - a lazy iterator over the atoms of one chemical element.
+#[pyclass(module = "nanoPDB")]
+pub struct ElementIterator {
+    pub element: String,
+    pub chains: Vec<Option<Py<Chain>>>,
+    pub chain_index: usize,
+    pub residue_index: usize,
+    pub atom_index: usize,
+}
+
+#[pymethods]
+impl ElementIterator {
+    // ----------------------------------------------------------------------------------------
+    // Special methods
+    // ----------------------------------------------------------------------------------------
+
+    pub fn __clear__(&mut self) {
+        for chain in self.chains.iter_mut() {
+            *chain = None;
+        }
+    }
+
+    pub fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    pub fn __next__(&mut self, python: Python) -> Option<Py<Atom>> {
+        while self.chain_index < self.chains.len() {
+            let chain = self.chains[self.chain_index]
+                .as_ref()
+                .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                .borrow(python);
+
+            while self.residue_index < chain.residues.len() {
+                let residue = chain.residues[self.residue_index]
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python);
+
+                while self.atom_index < residue.atoms.len() {
+                    let atom = residue.atoms[self.atom_index].as_ref().expect(concat!(
+                        "memory error in: ",
+                        file!(),
+                        ", line: ",
+                        line!()
+                    ));
+                    self.atom_index += 1;
+
+                    if atom.borrow(python).element.as_str() == self.element {
+                        return Some(atom.clone_ref(python));
+                    }
+                }
+
+                self.atom_index = 0;
+                self.residue_index += 1;
+            }
+
+            self.residue_index = 0;
+            self.chain_index += 1;
+        }
+
+        None
+    }
+
+    pub fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        for chain in self.chains.iter().flatten() {
+            visit.call(chain)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ElementIterator {
+    #[inline(always)]
+    pub fn new(element: &str, chains: Vec<Option<Py<Chain>>>) -> Self {
+        ElementIterator {
+            element: element.trim().to_uppercase(),
+            chains,
+            chain_index: 0,
+            residue_index: 0,
+            atom_index: 0,
+        }
+    }
+}
+
+/// StructureAtomIterator - a lazy iterator over all atoms of a structure (in file order).
+#[pyclass(module = "nanoPDB")]
+pub struct StructureAtomIterator {
+    pub chains: Vec<Option<Py<Chain>>>,
+    pub chain_index: usize,
+    pub residue_index: usize,
+    pub atom_index: usize,
+}
+
+#[pymethods]
+impl StructureAtomIterator {
+    // ----------------------------------------------------------------------------------------
+    // Special methods
+    // ----------------------------------------------------------------------------------------
+
+    pub fn __clear__(&mut self) {
+        for chain in self.chains.iter_mut() {
+            *chain = None;
+        }
+    }
+
+    pub fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    pub fn __next__(&mut self, python: Python) -> Option<Py<Atom>> {
+        while self.chain_index < self.chains.len() {
+            let chain = self.chains[self.chain_index]
+                .as_ref()
+                .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                .borrow(python);
+
+            while self.residue_index < chain.residues.len() {
+                let residue = chain.residues[self.residue_index]
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python);
+
+                if let Some(atom) = residue.atoms.get(self.atom_index) {
+                    self.atom_index += 1;
+
+                    return Some(
+                        atom.as_ref()
+                            .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                            .clone_ref(python),
+                    );
+                }
+
+                self.atom_index = 0;
+                self.residue_index += 1;
+            }
+
+            self.residue_index = 0;
+            self.chain_index += 1;
+        }
+
+        None
+    }
+
+    pub fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        for chain in self.chains.iter().flatten() {
+            visit.call(chain)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl StructureAtomIterator {
+    #[inline(always)]
+    pub fn new(chains: Vec<Option<Py<Chain>>>) -> Self {
+        StructureAtomIterator {
+            chains,
+            chain_index: 0,
+            residue_index: 0,
+            atom_index: 0,
+        }
+    }
+}
+
+/// StructureResidueIterator - a lazy iterator over all residues of a structure (in file
+/// order).
+#[pyclass(module = "nanoPDB")]
+pub struct StructureResidueIterator {
+    pub chains: Vec<Option<Py<Chain>>>,
+    pub chain_index: usize,
+    pub residue_index: usize,
+}
+
+#[pymethods]
+impl StructureResidueIterator {
+    // ----------------------------------------------------------------------------------------
+    // Special methods
+    // ----------------------------------------------------------------------------------------
+
+    pub fn __clear__(&mut self) {
+        for chain in self.chains.iter_mut() {
+            *chain = None;
+        }
+    }
+
+    pub fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    pub fn __next__(&mut self, python: Python) -> Option<Py<Residue>> {
+        while self.chain_index < self.chains.len() {
+            let chain = self.chains[self.chain_index]
+                .as_ref()
+                .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                .borrow(python);
+
+            if let Some((_, residue)) = chain.residues.get_index(self.residue_index) {
+                self.residue_index += 1;
+
+                return Some(
+                    residue
+                        .as_ref()
+                        .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                        .clone_ref(python),
+                );
+            }
+
+            self.residue_index = 0;
+            self.chain_index += 1;
+        }
+
+        None
+    }
+
+    pub fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        for chain in self.chains.iter().flatten() {
+            visit.call(chain)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl StructureResidueIterator {
+    #[inline(always)]
+    pub fn new(chains: Vec<Option<Py<Chain>>>) -> Self {
+        StructureResidueIterator {
+            chains,
+            chain_index: 0,
+            residue_index: 0,
+        }
+    }
+}
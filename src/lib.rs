@@ -2,23 +2,46 @@
 
 mod atom;
 mod chain;
+mod cif;
+mod columns;
+mod elements;
+mod geometry;
+mod grid;
+mod hbonds;
+mod hybrid36;
+mod iterators;
+mod model;
+mod neighbor_search;
 mod parser;
+mod pattern;
 mod periodic;
 mod residue;
+mod residue_names;
 mod structure;
 mod unit_cell;
+mod writer;
 
 use pyo3::{pymodule, types::PyModule, PyResult, Python};
 
 #[pymodule]
-fn nanoPDB(_python: Python, module: &PyModule) -> PyResult<()> {
+fn nanoPDB(python: Python, module: &PyModule) -> PyResult<()> {
     module.add_class::<atom::Atom>()?;
     module.add_class::<chain::Chain>()?;
+    module.add_class::<iterators::AtomIterator>()?;
+    module.add_class::<iterators::ChainIterator>()?;
+    module.add_class::<iterators::ElementIterator>()?;
+    module.add_class::<iterators::ResidueIterator>()?;
+    module.add_class::<iterators::StructureAtomIterator>()?;
+    module.add_class::<iterators::StructureResidueIterator>()?;
+    module.add_class::<model::Model>()?;
+    module.add_class::<neighbor_search::NeighborSearch>()?;
     module.add_class::<parser::Parser>()?;
     module.add_class::<periodic::Periodic>()?;
     module.add_class::<residue::Residue>()?;
     module.add_class::<structure::Structure>()?;
     module.add_class::<unit_cell::UnitCell>()?;
 
+    module.add("PDB_COLUMNS", columns::pdb_columns(python)?)?;
+
     Ok(())
 }
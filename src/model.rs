@@ -0,0 +1,119 @@
+use crate::chain::Chain;
+
+use pyo3::{
+    exceptions::PyIndexError, pyclass, pymethods, types::PyList, Py, PyResult, PyTraverseError,
+    PyVisit, Python,
+};
+
+use indexmap::IndexMap;
+
+/// Model - a class that represents a model (e.g. a frame of an NMR ensemble) of a PDB
+/// structure.
+#[pyclass(module = "nanoPDB")]
+pub struct Model {
+    /// [int] Model number (from the MODEL record, 1 for files without MODEL records).
+    #[pyo3(get)]
+    pub number: i32,
+
+    pub chains: IndexMap<char, Option<Py<Chain>>>,
+}
+
+#[pymethods]
+impl Model {
+    // ----------------------------------------------------------------------------------------
+    // Special methods
+    // ----------------------------------------------------------------------------------------
+
+    pub fn __clear__(&mut self) {
+        for chain in self.chains.values_mut() {
+            *chain = None;
+        }
+    }
+
+    pub fn __getitem__(&self, python: Python, index: usize) -> PyResult<Py<Chain>> {
+        if index < self.chains.len() {
+            Ok(self.chains[index]
+                .as_ref()
+                .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                .as_ref(python)
+                .into())
+        } else {
+            Err(PyIndexError::new_err("index out of range"))
+        }
+    }
+
+    pub fn __len__(&self) -> usize {
+        self.chains.len()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("<Model {}: {} chains>", self.number, self.chains.len())
+    }
+
+    pub fn __str__(&self) -> String {
+        format!("{:#}", self)
+    }
+
+    pub fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        for chain in self.chains.values().flatten() {
+            visit.call(chain)?;
+        }
+
+        Ok(())
+    }
+
+    // ----------------------------------------------------------------------------------------
+    // Methods
+    // ----------------------------------------------------------------------------------------
+
+    /// Returns a list of chains that builds the model.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// list[Chain]
+    ///     The list of chains that builds the model.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Retrieving the chains of the second model of an NMR ensemble.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1d3z")
+    /// ...
+    /// >>> structure.model(1).get_chains()
+    ///
+    /// [<Chain A: 76 residues>]
+    #[pyo3(signature = (/))]
+    pub fn get_chains(&self, python: Python) -> Py<PyList> {
+        PyList::new(
+            python,
+            self.chains.values().map(|chain| {
+                chain
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+            }),
+        )
+        .into()
+    }
+}
+
+impl Model {
+    #[inline(always)]
+    pub fn new(number: i32) -> Self {
+        Model {
+            number,
+            chains: IndexMap::default(),
+        }
+    }
+}
+
+impl std::fmt::Display for Model {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter
+            .debug_struct("Model")
+            .field("number", &self.number)
+            .finish()
+    }
+}
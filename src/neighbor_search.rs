@@ -0,0 +1,161 @@
+use crate::{atom::Atom, geometry::Vector, grid::NeighborGrid};
+
+use pyo3::{
+    exceptions::PyValueError, pyclass, pymethods, AsPyPointer, Py, PyRef, PyResult,
+    PyTraverseError, PyVisit, Python,
+};
+
+/// NeighborSearch - a spatial index over the atoms of a structure, built once and queried
+/// repeatedly.
+#[pyclass(module = "nanoPDB")]
+pub struct NeighborSearch {
+    pub atoms: Vec<Option<Py<Atom>>>,
+    pub grid: NeighborGrid,
+}
+
+#[pymethods]
+impl NeighborSearch {
+    // ----------------------------------------------------------------------------------------
+    // Special methods
+    // ----------------------------------------------------------------------------------------
+
+    pub fn __clear__(&mut self) {
+        for atom in self.atoms.iter_mut() {
+            *atom = None;
+        }
+    }
+
+    pub fn __len__(&self) -> usize {
+        self.atoms.len()
+    }
+
+    pub fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        for atom in self.atoms.iter().flatten() {
+            visit.call(atom)?;
+        }
+
+        Ok(())
+    }
+
+    // ----------------------------------------------------------------------------------------
+    // Methods
+    // ----------------------------------------------------------------------------------------
+
+    /// Returns the atoms within the radius of a point.
+    ///
+    /// The atoms are returned in file order, including the atoms exactly at the radius. The
+    /// positions are the ones at the time the index was built, so the index has to be built
+    /// again after the structure is moved.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// center : (float, float, float)
+    ///     The point to search around.
+    /// radius : float
+    ///     The search radius (in Angstroms).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// list[Atom]
+    ///     The list of atoms within the radius.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Querying the same index around several points.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> search = structure.neighbor_search()
+    /// ...
+    /// >>> [len(search.atoms_within(atom.position, 5.0)) for atom in structure[0][0]]
+    ///
+    /// [12, 17, 21, 20, 19, 24, 25, 14]
+    #[pyo3(signature = (center, radius, /))]
+    pub fn atoms_within(
+        &self,
+        python: Python,
+        center: Vector,
+        radius: f64,
+    ) -> PyResult<Vec<Py<Atom>>> {
+        if radius < 0.0 {
+            return Err(PyValueError::new_err("radius must not be negative"));
+        }
+
+        let mut indices = self.grid.within(center, radius);
+        indices.sort_unstable();
+
+        Ok(indices
+            .into_iter()
+            .map(|index| {
+                self.atoms[index]
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .clone_ref(python)
+            })
+            .collect())
+    }
+
+    /// Returns the atoms within the radius of an atom, without the atom itself.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// atom : Atom
+    ///     The atom to search around (of the indexed structure or any other).
+    /// radius : float
+    ///     The search radius (in Angstroms).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// list[Atom]
+    ///     The list of neighboring atoms (in file order).
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Finding the atoms around the first atom.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> search = structure.neighbor_search()
+    /// ...
+    /// >>> [atom.name for atom in search.neighbors(structure[0][0][0], 2.0)]
+    ///
+    /// ['CA']
+    #[pyo3(signature = (atom, radius, /))]
+    pub fn neighbors(
+        &self,
+        python: Python,
+        atom: PyRef<Atom>,
+        radius: f64,
+    ) -> PyResult<Vec<Py<Atom>>> {
+        Ok(self
+            .atoms_within(python, atom.position, radius)?
+            .into_iter()
+            .filter(|other| other.as_ptr() != atom.as_ptr())
+            .collect())
+    }
+}
+
+impl NeighborSearch {
+    /// Index over the atoms with grid cells of the given size (a size close to the typical
+    /// query radius is the fastest, see NeighborGrid).
+    pub fn new(python: Python, atoms: Vec<Py<Atom>>, cell_size: f64) -> Self {
+        let grid = NeighborGrid::new(
+            atoms
+                .iter()
+                .map(|atom| atom.borrow(python).position)
+                .collect(),
+            cell_size,
+        );
+
+        NeighborSearch {
+            atoms: atoms.into_iter().map(Some).collect(),
+            grid,
+        }
+    }
+}
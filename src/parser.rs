@@ -1,18 +1,115 @@
 use crate::{
-    atom::{Atom, AtomType},
+    atom::{name_field, Atom, AtomType},
     chain::Chain,
-    residue::Residue,
-    structure::Structure,
+    cif::CifData,
+    columns::{
+        ALT_LOC, B_FACTOR, CHAIN, CHARGE, ELEMENT, INSERTION_CODE, NAME, OCCUPANCY, RESIDUE_NAME,
+        RESIDUE_NUMBER, SERIAL, X, Y, Z,
+    },
+    elements, hybrid36,
+    model::Model,
+    residue::{Residue, ATOMS_GENERATION},
+    residue_names,
+    structure::{Structure, CHAIN_NAMES},
     unit_cell::UnitCell,
 };
 
-use pyo3::{exceptions::PyException, pyclass, pymethods, Py, PyResult, Python};
+use pyo3::{
+    exceptions::{PyException, PyValueError},
+    pyclass, pymethods, Py, PyCell, PyObject, PyResult, Python,
+};
+
+use flate2::read::GzDecoder;
+
+use indexmap::IndexMap;
+
+use memmap2::Mmap;
+
+use rayon::prelude::*;
+
+use std::{
+    collections::{HashMap, HashSet},
+    fs::{self, File},
+    io::{BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{atomic::Ordering, OnceLock},
+    thread,
+    time::{Duration, Instant},
+};
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
 
-use std::{fs::File, io::Read, str::FromStr};
+/// Maximal number of simultaneous downloads in fetch_many.
+const MAX_CONCURRENT_DOWNLOADS: usize = 8;
+
+/// HTTP client shared by all downloads (reusing its connection pool).
+static CLIENT: OnceLock<reqwest::blocking::Client> = OnceLock::new();
+
+/// Delay before the first retry of a failed download, multiplied by the retry number for
+/// the following ones.
+const RETRY_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Timeout and number of retries of the downloads.
+#[derive(Clone, Copy)]
+struct FetchOptions {
+    timeout: Option<Duration>,
+    retries: u32,
+}
+
+/// Record types understood by the parser (REMARK covers only the resolution remark).
+const SUPPORTED_RECORDS: [&str; 10] = [
+    "ATOM", "HETATM", "HEADER", "CRYST1", "TITLE", "EXPDTA", "REMARK", "CONECT", "SEQRES", "ANISOU",
+];
+
+/// Handling of alternate conformations (alternate location indicators).
+#[derive(Clone, Copy)]
+enum AltLocMode {
+    /// Every conformer is kept.
+    All,
+    /// Only the highest-occupancy conformer of every atom is kept.
+    Best,
+}
 
 /// Parser - a class for parsing structures in PDB format.
+///
+/// The alt_loc option (default "all") chooses how alternate conformations are handled:
+/// "all" keeps every conformer, "best" keeps only the highest-occupancy conformer of every
+/// atom (the first one on ties).
+///
+/// The guess_elements option (default True) fills in the elements of atoms with blank (or
+/// missing) element columns 77-78 from the atom names. Element symbols are right-justified
+/// in columns 13-14 of the atom name, so " CA " is a carbon (alpha carbon) and "CA  " a
+/// calcium; with the option disabled such atoms keep a blank element.
+///
+/// The timeout option sets the time limit of a single download in seconds (default None,
+/// the 30 seconds limit of the HTTP client), the retries option (default 0) the number of
+/// retries of downloads that failed transiently (connection errors, timeouts and 5xx
+/// responses), each after a short delay.
+///
+/// The cache_dir option (default None) is a directory for downloaded structures: fetch
+/// (and fetch_many, fetch_async, parse of a PDB ID) reads {cache_dir}/{PDBID}.pdb
+/// ({cache_dir}/{PDBID}.cif for fetch_cif) when it exists and parses, otherwise downloads
+/// the structure and writes it there.
+///
+/// The skip_water option (default False) drops water residues (HOH, WAT and DOD, in any
+/// case) while parsing, all other HETATM groups are kept.
+///
+/// Chains are also separated on TER records: a polymer (ATOM records) that follows a TER
+/// record and reuses the name of an earlier chain is put into a new chain, named with the
+/// first chain name unused in the file (or model). The same applies to HETATM records after
+/// a TER that closed a non-water HETATM segment, waters keep the chain before them.
+///
+/// Atom serial numbers and residue numbers too large for their columns (over 99999 atoms or
+/// 9999 residues) are read in the hybrid-36 encoding, e.g. "A0000" is the atom 100000.
 #[pyclass(module = "nanoPDB", frozen)]
-pub struct Parser;
+pub struct Parser {
+    alt_loc: AltLocMode,
+    guess_elements: bool,
+    fetch_options: FetchOptions,
+    cache_dir: Option<PathBuf>,
+    skip_water: bool,
+}
 
 #[pymethods]
 impl Parser {
@@ -21,21 +118,123 @@ impl Parser {
     // ----------------------------------------------------------------------------------------
 
     #[new]
-    pub fn __new__() -> Self {
-        Parser {}
+    #[pyo3(signature = (
+        alt_loc = "all",
+        guess_elements = true,
+        timeout = None,
+        retries = 0,
+        cache_dir = None,
+        skip_water = false,
+    ))]
+    pub fn __new__(
+        alt_loc: &str,
+        guess_elements: bool,
+        timeout: Option<f64>,
+        retries: u32,
+        cache_dir: Option<PathBuf>,
+        skip_water: bool,
+    ) -> PyResult<Self> {
+        let alt_loc = match alt_loc {
+            "all" => AltLocMode::All,
+            "best" => AltLocMode::Best,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "alt_loc must be \"all\" or \"best\", got: {:?}",
+                    alt_loc
+                )))
+            }
+        };
+
+        let timeout = match timeout.map(Duration::try_from_secs_f64) {
+            Some(Ok(timeout)) if !timeout.is_zero() => Some(timeout),
+            Some(_) => {
+                return Err(PyValueError::new_err(format!(
+                    "timeout must be a positive number of seconds, got: {}",
+                    timeout.unwrap_or_default()
+                )))
+            }
+            None => None,
+        };
+
+        Ok(Parser {
+            alt_loc,
+            guess_elements,
+            fetch_options: FetchOptions { timeout, retries },
+            cache_dir,
+            skip_water,
+        })
     }
 
     // ----------------------------------------------------------------------------------------
     // Methods
     // ----------------------------------------------------------------------------------------
 
+    /// Measures the parsing performance and returns the mean parse time in milliseconds.
+    ///
+    /// The file is read (and decompressed, if gzip-compressed) once, then its content is parsed
+    /// the given number of times. Every parsed structure is released before the next run, so
+    /// memory use does not grow with the number of iterations. File reading is not included in
+    /// the measured time.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// path : str
+    ///     The path to the PDB file.
+    /// iterations : int
+    ///     The number of parse runs (at least 1).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// float
+    ///     The mean parse time (in milliseconds).
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Measuring the parse time of a file.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> parser.benchmark_parse("tests/1zhy.pdb", 100)
+    ///
+    /// 12.34...
+    #[pyo3(signature = (path, iterations, /))]
+    pub fn benchmark_parse(
+        &self,
+        python: Python,
+        path: String,
+        iterations: usize,
+    ) -> PyResult<f64> {
+        if iterations == 0 {
+            return Err(PyValueError::new_err(
+                "number of iterations must be at least 1",
+            ));
+        }
+
+        let content = decode_content(fs::read(path)?)?;
+
+        let start = Instant::now();
+
+        for _ in 0..iterations {
+            drop(self.parse_content(python, &content, None, true, false)?);
+        }
+
+        Ok(start.elapsed().as_secs_f64() * 1000.0 / iterations as f64)
+    }
+
     /// Fetches structure from RCSB PDB database, parses it and returns Structure object.
     ///
+    /// The GIL is released during the download, so other Python threads keep running.
+    ///
     ///
     /// Parameters
     /// ----------
     /// pdbid : str
     ///     PDB ID of structure from RCSB PDB.
+    /// strict : bool
+    ///     Whether a malformed record raises an exception (default True), otherwise it is
+    ///     skipped and recorded in Structure.warnings.
     ///
     ///
     /// Returns
@@ -51,47 +250,83 @@ impl Parser {
     /// >>> parser = nanoPDB.Parser()
     /// >>> structure = parser.fetch("1zhy")
     /// ...
-    /// >>> structure
+    /// >>> print(structure)
     ///
     /// Structure {
     ///     pdbid: "1ZHY",
     ///     classification: "LIPID BINDING PROTEIN",
     ///     date: "26-APR-05",
     /// }
-    #[pyo3(signature = (pdbid, /))]
-    pub fn fetch(&self, python: Python, pdbid: String) -> PyResult<Structure> {
-        let response = match reqwest::blocking::get(format!(
-            "https://files.rcsb.org/download/{}.pdb",
-            pdbid.to_lowercase()
-        )) {
-            Ok(response) => response,
-            Err(error) => return Err(PyException::new_err(format!("{}", error))),
-        };
-
-        let response_status = response.status();
-
-        if response_status != 200 {
-            return Err(PyException::new_err(format!(
-                "connection error, status: {}",
-                response_status
-            )));
-        }
+    #[pyo3(signature = (pdbid, /, strict = true))]
+    pub fn fetch(&self, python: Python, pdbid: String, strict: bool) -> PyResult<Structure> {
+        self.fetch_cached(python, &pdbid, "pdb", |content| {
+            self.parse_content(python, content, None, strict, false)
+        })
+    }
 
-        let content = match response.text() {
-            Ok(content) => content,
-            Err(error) => return Err(PyException::new_err(format!("{}", error))),
-        };
+    /// Fetches structure from RCSB PDB database without blocking the asyncio event loop.
+    ///
+    /// The structure is fetched with fetch in the default executor of the running event loop,
+    /// so it has to be called from a coroutine (asyncio raises a RuntimeError otherwise).
+    /// Several structures can be downloaded concurrently, e.g. with asyncio.gather.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// pdbid : str
+    ///     PDB ID of structure from RCSB PDB.
+    /// strict : bool
+    ///     Whether a malformed record raises an exception (default True), otherwise it is
+    ///     skipped and recorded in Structure.warnings.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// Awaitable[Structure]
+    ///     The awaitable resolving to the parsed structure.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Fetching two structures concurrently.
+    ///
+    /// >>> async def main():
+    /// ...     parser = nanoPDB.Parser()
+    /// ...     return await asyncio.gather(parser.fetch_async("1zhy"), parser.fetch_async("4hhb"))
+    /// >>> structures = asyncio.run(main())
+    /// >>> [structure.pdbid for structure in structures]
+    ///
+    /// ['1ZHY', '4HHB']
+    #[pyo3(signature = (pdbid, /, strict = true))]
+    pub fn fetch_async(
+        slf: &PyCell<Self>,
+        python: Python,
+        pdbid: String,
+        strict: bool,
+    ) -> PyResult<PyObject> {
+        let event_loop = python.import("asyncio")?.call_method0("get_running_loop")?;
+        let fetch = slf.getattr("fetch")?;
 
-        parse_pdb(python, &content)
+        Ok(event_loop
+            .call_method1("run_in_executor", (python.None(), fetch, pdbid, strict))?
+            .into())
     }
 
-    /// Parses PDB file and returns the Structure object.
+    /// Fetches structure in mmCIF format from RCSB PDB database, parses it and returns
+    /// Structure object.
+    ///
+    /// mmCIF is the only complete format of large structures (more than 62 chains or 99999
+    /// atoms). The header is read from the _entry, _struct, _struct_keywords,
+    /// _pdbx_database_status, _exptl and _refine items, the unit cell from the _cell items
+    /// and the atoms from the _atom_site loop, using the author atom, residue and chain
+    /// names and numbers (as in PDB files). Chain names longer than one character are
+    /// replaced by unused chain names. The GIL is released during the download.
     ///
     ///
     /// Parameters
     /// ----------
-    /// path : str
-    ///     The path to the PDB file.
+    /// pdbid : str
+    ///     PDB ID of structure from RCSB PDB.
     ///
     ///
     /// Returns
@@ -102,206 +337,1680 @@ impl Parser {
     ///
     /// Examples
     /// --------
-    /// Loading structure from file.
+    /// Fetching structure in mmCIF format.
     ///
     /// >>> parser = nanoPDB.Parser()
-    /// >>> structure = parser.parse("tests/1zhy.pdb")
-    /// >>> structure
+    /// >>> structure = parser.fetch_cif("1zhy")
+    /// ...
+    /// >>> print(structure)
     ///
     /// Structure {
     ///     pdbid: "1ZHY",
     ///     classification: "LIPID BINDING PROTEIN",
     ///     date: "26-APR-05",
     /// }
-    #[pyo3(signature = (path, /))]
-    pub fn parse(&self, python: Python, path: String) -> PyResult<Structure> {
-        let mut content = String::with_capacity(1024 * 1024 * 4);
-        File::open(path)?.read_to_string(&mut content)?;
-
-        parse_pdb(python, &content)
+    #[pyo3(signature = (pdbid, /))]
+    pub fn fetch_cif(&self, python: Python, pdbid: String) -> PyResult<Structure> {
+        self.fetch_cached(python, &pdbid, "cif", |content| {
+            self.parse_cif_content(python, content)
+        })
     }
-}
-
-#[inline(always)]
-fn parse_numeric<T: FromStr>(
-    line: &str,
-    line_number: usize,
-    from: usize,
-    to: usize,
-) -> PyResult<T> {
-    line[from..to].trim().parse::<T>().map_err(|_| {
-        PyException::new_err(format!(
-            "error in line: {}, cannot parse numeric",
-            line_number + 1
-        ))
-    })
-}
 
-#[inline(always)]
-fn parse_header_into(line: &str, line_number: usize, structure: &mut Structure) -> PyResult<()> {
-    if line.len() < 66 {
-        return Err(PyException::new_err(format!(
-            "error in line: {}, HEADER line to short",
-            line_number + 1
-        )));
-    }
+    /// Fetches several structures from RCSB PDB database concurrently and parses them.
+    ///
+    /// Up to 8 structures are downloaded at the same time (without holding the GIL), which is
+    /// much faster than calling fetch in a loop. An error of any download or parse is raised
+    /// with the PDB ID it concerns.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// ids : list[str]
+    ///     PDB IDs of structures from RCSB PDB.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// list[Structure]
+    ///     Parsed structures (in the order of the PDB IDs).
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Fetching a small dataset.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structures = parser.fetch_many(["1zhy", "4hhb", "1crn"])
+    /// ...
+    /// >>> [structure.pdbid for structure in structures]
+    ///
+    /// ['1ZHY', '4HHB', '1CRN']
+    #[pyo3(signature = (ids, /))]
+    pub fn fetch_many(&self, python: Python, ids: Vec<String>) -> PyResult<Vec<Structure>> {
+        let cached: Vec<Option<Structure>> = ids
+            .iter()
+            .map(|pdbid| {
+                self.cached_content(pdbid, "pdb").and_then(|content| {
+                    self.parse_content(python, &content, None, true, false).ok()
+                })
+            })
+            .collect();
 
-    let pdbid = line[62..66].trim();
-    let classification = line[10..50].trim();
-    let date = line[50..59].trim();
+        let missing: Vec<String> = ids
+            .iter()
+            .zip(cached.iter())
+            .filter(|(_, structure)| structure.is_none())
+            .map(|(pdbid, _)| pdbid.clone())
+            .collect();
 
-    structure.set_header(pdbid, classification, date);
+        let options = self.fetch_options;
+        let mut contents = python
+            .allow_threads(|| fetch_contents(&missing, options))
+            .into_iter();
 
-    Ok(())
-}
+        ids.iter()
+            .zip(cached)
+            .map(|(pdbid, structure)| match structure {
+                Some(structure) => Ok(structure),
+                None => contents
+                    .next()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .and_then(|content| {
+                        let structure = self.parse_content(python, &content, None, true, false)?;
+                        self.store_content(pdbid, "pdb", &content)?;
 
-#[inline(always)]
-fn parse_cryst1_into(
-    python: Python,
-    line: &str,
-    line_number: usize,
-    structure: &mut Structure,
-) -> PyResult<()> {
-    if line.len() < 54 {
-        return Err(PyException::new_err(format!(
-            "error in line: {}, CRYST1 line to short",
-            line_number + 1
-        )));
+                        Ok(structure)
+                    })
+                    .map_err(|error| {
+                        PyException::new_err(format!(
+                            "cannot fetch {}: {}",
+                            pdbid,
+                            error.value(python)
+                        ))
+                    }),
+            })
+            .collect()
     }
 
-    let a = parse_numeric::<f64>(line, line_number, 6, 15)?;
-    let b = parse_numeric::<f64>(line, line_number, 15, 24)?;
-    let c = parse_numeric::<f64>(line, line_number, 24, 33)?;
-    let alpha = parse_numeric::<f64>(line, line_number, 33, 40)?;
-    let beta = parse_numeric::<f64>(line, line_number, 40, 47)?;
-    let gamma = parse_numeric::<f64>(line, line_number, 47, 54)?;
-
-    let unit_cell = UnitCell::new(a, b, c, alpha, beta, gamma);
-    structure.set_unit_cell(python, unit_cell)?;
-
-    Ok(())
-}
-
-#[inline(always)]
-fn parse_atom_into(
-    python: Python,
-    line: &str,
-    line_number: usize,
-    label: AtomType,
-    structure: &mut Structure,
-) -> PyResult<()> {
-    if line.len() < 78 {
-        return Err(PyException::new_err(format!(
-            "error in line: {}, ATOM/HETATM line to short",
-            line_number + 1
-        )));
+    /// Parses PDB file in a single pass, calling the callback with the fields of every
+    /// ATOM/HETATM record.
+    ///
+    /// Meant for one-pass computations (e.g. a centroid) over files of any size: the file is
+    /// read line by line, atoms are not retained and no Structure is returned, so memory use
+    /// stays constant. Works exactly like parse_streaming: the callback receives one tuple per
+    /// atom (label, atom number, atom name, residue name, residue number, chain name, x, y, z,
+    /// occupancy, element), returning False from the callback stops parsing and exceptions
+    /// raised by the callback are propagated.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// path : str
+    ///     The path to the PDB file.
+    /// callback : Callable[[tuple], bool | None]
+    ///     The function called for every atom.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// int
+    ///     The number of atoms passed to the callback.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Computing the centroid of the file without building the Structure.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> total = [0.0, 0.0, 0.0]
+    /// >>> def add(atom):
+    /// ...     total[0] += atom[6]
+    /// ...     total[1] += atom[7]
+    /// ...     total[2] += atom[8]
+    /// >>> count = parser.for_each_atom("tests/1zhy.pdb", add)
+    /// >>> count, [round(value / count, 3) for value in total]
+    ///
+    /// (3806, [57.025, 36.566, 39.137])
+    #[pyo3(signature = (path, callback, /))]
+    pub fn for_each_atom(
+        &self,
+        python: Python,
+        path: String,
+        callback: PyObject,
+    ) -> PyResult<usize> {
+        self.parse_streaming(python, path, callback)
     }
 
-    let atom_number = parse_numeric::<i32>(line, line_number, 6, 11)?;
-    let atom_name = line[12..16].trim();
-    let residue_name = line[17..20].trim();
-    let chain_name = line.chars().nth(21).unwrap();
-    let residue_number = parse_numeric::<i32>(line, line_number, 22, 26)?;
-    let atom_pos_x = parse_numeric::<f64>(line, line_number, 30, 38)?;
-    let atom_pos_y = parse_numeric::<f64>(line, line_number, 38, 46)?;
-    let atom_pos_z = parse_numeric::<f64>(line, line_number, 46, 54)?;
-    let atom_occupancy = parse_numeric::<f64>(line, line_number, 54, 60)?;
-    let atom_element = line[76..78].trim();
-
-    let full_add = match structure.chains.get(&chain_name) {
-        // Chain exists:
-        Some(chain) => {
-            match chain
-                .as_ref()
-                .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
-                .borrow(python)
-                .residues
-                .get(&(residue_name.into(), residue_number))
-            {
-                // Residue exists (adds atom):
-                Some(residue) => {
-                    let atom = Atom::new(
-                        label.clone(),
-                        atom_number,
-                        atom_name,
-                        atom_element,
-                        (atom_pos_x, atom_pos_y, atom_pos_z),
-                        atom_occupancy,
-                    );
+    /// Parses PDB file and returns the Structure object.
+    ///
+    /// The argument is inspected to find the source: an http:// or https:// URL is
+    /// downloaded (plain or gzip-compressed content), a 4-character PDB ID (that is not an
+    /// existing file) is fetched from RCSB PDB like with fetch, anything else is read as
+    /// a path to a file. Gzip-compressed files (e.g. .pdb.gz) are detected by their content
+    /// and decompressed transparently. Plain files are memory-mapped rather than read, so
+    /// large files are not held in memory twice.
+    ///
+    /// Parsing can be limited to selected record types (e.g. only "ATOM" for speed, skipping
+    /// HETATM and metadata records). Supported record types are ATOM, HETATM, HEADER,
+    /// CRYST1, TITLE, EXPDTA, REMARK (resolution), CONECT, SEQRES and ANISOU, other names
+    /// raise a ValueError.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// path : str
+    ///     The path to the PDB file (plain or gzip-compressed), a URL or a PDB ID.
+    /// records : set[str], optional
+    ///     Record types to parse, all supported records are parsed if not given.
+    /// strict : bool
+    ///     Whether a malformed record raises an exception (default True), otherwise it is
+    ///     skipped and recorded in Structure.warnings.
+    /// parallel : bool
+    ///     Whether the atom records are parsed on multiple threads (default False), models
+    ///     (MODEL/ENDMDL blocks) are parsed concurrently. The result is the same as of the
+    ///     single-threaded parsing, models keep their order in the file. The Python objects
+    ///     of the structure are still built on the calling thread (they need the GIL).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// Structure
+    ///     Parsed structure.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Loading structure from file.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.parse("tests/1zhy.pdb")
+    /// >>> print(structure)
+    ///
+    /// Structure {
+    ///     pdbid: "1ZHY",
+    ///     classification: "LIPID BINDING PROTEIN",
+    ///     date: "26-APR-05",
+    /// }
+    ///
+    /// Loading structure from URL.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.parse("https://files.rcsb.org/download/1zhy.pdb.gz")
+    /// ...
+    /// >>> structure.pdbid
+    ///
+    /// '1ZHY'
+    ///
+    /// Loading only the ATOM records.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.parse("tests/1zhy.pdb", {"ATOM"})
+    /// >>> structure.num_atoms()
+    ///
+    /// 3514
+    ///
+    /// Loading a file with malformed records.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.parse("tests/malformed_records.pdb", None, False)
+    /// >>> structure.warnings
+    ///
+    /// ['error in line: 2, cannot parse numeric', 'error in line: 4, ATOM/HETATM line to short']
+    ///
+    /// Loading a large NMR ensemble on multiple threads.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.parse("1d3z", parallel=True)
+    /// ...
+    /// >>> len(structure.models)
+    ///
+    /// 10
+    #[pyo3(signature = (path, /, records = None, strict = true, parallel = false))]
+    pub fn parse(
+        &self,
+        python: Python,
+        path: String,
+        records: Option<HashSet<String>>,
+        strict: bool,
+        parallel: bool,
+    ) -> PyResult<Structure> {
+        check_records(records.as_ref())?;
 
-                    residue
-                        .as_ref()
-                        .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
-                        .borrow_mut(python)
-                        .atoms
-                        .push(Some(Py::new(python, atom)?));
+        let content = if path.starts_with("http://") || path.starts_with("https://") {
+            let options = self.fetch_options;
+            decode_content(python.allow_threads(|| download(&path, options))?)?
+        } else if is_pdbid(&path) && !Path::new(&path).exists() {
+            return self.fetch_cached(python, &path, "pdb", |content| {
+                self.parse_content(python, content, records.as_ref(), strict, parallel)
+            });
+        } else {
+            let file = File::open(&path)?;
 
-                    true
+            // The file is mapped instead of read (the pages are loaded by the system as the
+            // content is parsed), the plain read is kept for the filesystems that do not
+            // support mapping. Safety: the mapping is only read while the file is open here,
+            // modifying the file meanwhile from the outside is not supported:
+            match unsafe { Mmap::map(&file) } {
+                Ok(mapped) if mapped.starts_with(&GZIP_MAGIC) => decompress(&mapped)?,
+                Ok(mapped) => {
+                    return self.parse_content(
+                        python,
+                        decode_mapped(&mapped)?,
+                        records.as_ref(),
+                        strict,
+                        parallel,
+                    );
                 }
-                // Residue does not exist:
-                None => false,
+                Err(_) => decode_content(fs::read(&path)?)?,
             }
-        }
-        // Chain does not exist (adds chain, adds residue, adds atom):
-        None => {
-            let atom = Atom::new(
-                label.clone(),
-                atom_number,
-                atom_name,
-                atom_element,
-                (atom_pos_x, atom_pos_y, atom_pos_z),
-                atom_occupancy,
-            );
-            let mut residue = Residue::new(residue_number, residue_name);
-            let mut chain = Chain::new(chain_name);
-
-            residue.atoms.push(Some(Py::new(python, atom)?));
-            chain.residues.insert(
-                (residue_name.into(), residue_number),
-                Some(Py::new(python, residue)?),
-            );
-            structure
-                .chains
-                .insert(chain_name, Some(Py::new(python, Chain::new(chain_name))?));
-
-            true
-        }
-    };
+        };
 
-    if !full_add {
-        let atom = Atom::new(
-            label,
-            atom_number,
-            atom_name,
-            atom_element,
-            (atom_pos_x, atom_pos_y, atom_pos_z),
-            atom_occupancy,
-        );
-        let mut residue = Residue::new(residue_number, residue_name);
-
-        residue.atoms.push(Some(Py::new(python, atom)?));
-        structure
-            .chains
-            .get(&chain_name)
-            .unwrap()
-            .as_ref()
-            .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
-            .borrow_mut(python)
-            .residues
-            .insert(
-                (residue_name.into(), residue_number),
-                Some(Py::new(python, residue)?),
-            );
+        self.parse_content(python, &content, records.as_ref(), strict, parallel)
     }
 
-    Ok(())
-}
-
+    /// Parses PDB data (plain or gzip-compressed) and returns the Structure object.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// data : bytes
+    ///     The content of the PDB file, gzip compression is detected automatically.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// Structure
+    ///     Parsed structure.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Loading structure from bytes.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> with open("tests/1zhy.pdb", "rb") as file:
+    /// ...     structure = parser.parse_bytes(file.read())
+    /// >>> print(structure)
+    ///
+    /// Structure {
+    ///     pdbid: "1ZHY",
+    ///     classification: "LIPID BINDING PROTEIN",
+    ///     date: "26-APR-05",
+    /// }
+    #[pyo3(signature = (data, /))]
+    pub fn parse_bytes(&self, python: Python, data: &[u8]) -> PyResult<Structure> {
+        let content = decode_content(data.to_vec())?;
+
+        self.parse_content(python, &content, None, true, false)
+    }
+
+    /// Parses mmCIF file and returns the Structure object.
+    ///
+    /// The file is read like with fetch_cif: the header from the _entry, _struct,
+    /// _struct_keywords, _pdbx_database_status, _exptl and _refine items, the unit cell from
+    /// the _cell items and the atoms from the _atom_site loop. Columns are found by their
+    /// names (in any order), values may be quoted and the null markers '.' and '?' stand for
+    /// missing values (default occupancy 1.0 and B-factor 0.0, no charge, alternate location
+    /// or insertion code). Gzip-compressed files (e.g. .cif.gz) are decompressed
+    /// transparently. Only the first data block is read.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// path : str
+    ///     The path to the mmCIF file (.cif or .mmcif, plain or gzip-compressed).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// Structure
+    ///     Parsed structure.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Loading structure from mmCIF file.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.parse_cif("tests/1abc.cif")
+    /// >>> print(structure)
+    ///
+    /// Structure {
+    ///     pdbid: "1ABC",
+    ///     classification: "DE NOVO PROTEIN",
+    ///     date: "26-APR-05",
+    /// }
+    #[pyo3(signature = (path, /))]
+    pub fn parse_cif(&self, python: Python, path: String) -> PyResult<Structure> {
+        self.parse_cif_content(python, &decode_content(fs::read(path)?)?)
+    }
+
+    /// Parses PDB file atom by atom, passing every ATOM/HETATM record to the callback.
+    ///
+    /// The file is read line by line and no Structure is built, so memory use does not
+    /// depend on the file size. The callback receives one tuple per atom: (label, atom
+    /// number, atom name, residue name, residue number, chain name, x, y, z, occupancy,
+    /// element). Returning False from the callback stops parsing, exceptions raised by the
+    /// callback are propagated.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// path : str
+    ///     The path to the PDB file.
+    /// on_atom : Callable[[tuple], bool | None]
+    ///     The function called for every atom.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// int
+    ///     The number of atoms passed to the callback.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Printing the first three atoms of the file.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> def on_atom(atom):
+    /// ...     print(atom)
+    /// ...     return atom[1] < 3
+    /// >>> parser.parse_streaming("tests/1zhy.pdb", on_atom)
+    ///
+    /// ('ATOM', 1, 'N', 'MET', -1, 'A', 42.854, 36.56, 10.394, 1.0, 'N')
+    /// ('ATOM', 2, 'CA', 'MET', -1, 'A', 42.25, 35.232, 10.096, 1.0, 'C')
+    /// ('ATOM', 3, 'C', 'MET', -1, 'A', 41.642, 34.623, 11.355, 1.0, 'C')
+    /// 3
+    #[pyo3(signature = (path, on_atom, /))]
+    pub fn parse_streaming(
+        &self,
+        python: Python,
+        path: String,
+        on_atom: PyObject,
+    ) -> PyResult<usize> {
+        self.stream_atoms(path, |label, atom| {
+            let result = on_atom.call1(python, (atom_fields(label, atom),))?;
+
+            Ok(!matches!(result.extract::<bool>(python), Ok(false)))
+        })
+    }
+
+    /// Parses PDB content held in memory (e.g. received from a database or generated) and
+    /// returns the Structure object. The content is parsed exactly like a file with parse.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// content : str
+    ///     The content of the PDB file.
+    /// records : set[str], optional
+    ///     Record types to parse, all supported records are parsed if not given.
+    /// strict : bool
+    ///     Whether a malformed record raises an exception (default True), otherwise it is
+    ///     skipped and recorded in Structure.warnings.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// Structure
+    ///     Parsed structure.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Loading structure from a string.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> with open("tests/1zhy.pdb") as file:
+    /// ...     structure = parser.parse_string(file.read())
+    /// >>> print(structure)
+    ///
+    /// Structure {
+    ///     pdbid: "1ZHY",
+    ///     classification: "LIPID BINDING PROTEIN",
+    ///     date: "26-APR-05",
+    /// }
+    #[pyo3(signature = (content, /, records = None, strict = true))]
+    pub fn parse_string(
+        &self,
+        python: Python,
+        content: &str,
+        records: Option<HashSet<String>>,
+        strict: bool,
+    ) -> PyResult<Structure> {
+        check_records(records.as_ref())?;
+
+        self.parse_content(python, content, records.as_ref(), strict, false)
+    }
+
+    /// Checks the PDB file against the fixed-column format and returns the found problems.
+    ///
+    /// The file is not parsed into a Structure. ATOM/HETATM records are checked for
+    /// non-numeric atom and residue numbers, misaligned or non-numeric coordinates,
+    /// occupancy and B-factor (blank occupancy and B-factor are allowed), and a missing
+    /// element symbol; HEADER and CRYST1 records are checked for their minimal length.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// path : str
+    ///     The path to the PDB file.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// list[str]
+    ///     The list of warnings (with line numbers), empty for a valid file.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Checking the PDB file.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> parser.validate_file("tests/1zhy.pdb")
+    ///
+    /// []
+    #[pyo3(signature = (path, /))]
+    pub fn validate_file(&self, path: String) -> PyResult<Vec<String>> {
+        Ok(validate_pdb(&fs::read_to_string(path)?))
+    }
+}
+
+impl Parser {
+    /// Parses the structure of the entry from the cache directory when it holds a file (with
+    /// the extension, "pdb" or "cif") that parses, otherwise downloads the structure (without
+    /// holding the GIL), parses it and stores it in the cache directory.
+    fn fetch_cached(
+        &self,
+        python: Python,
+        pdbid: &str,
+        extension: &str,
+        parse: impl Fn(&str) -> PyResult<Structure>,
+    ) -> PyResult<Structure> {
+        if let Some(structure) = self
+            .cached_content(pdbid, extension)
+            .and_then(|content| parse(&content).ok())
+        {
+            return Ok(structure);
+        }
+
+        let options = self.fetch_options;
+        let content = python.allow_threads(|| fetch_content(pdbid, extension, options))?;
+        let structure = parse(&content)?;
+        self.store_content(pdbid, extension, &content)?;
+
+        Ok(structure)
+    }
+
+    /// Path of the entry in the cache directory, None without a cache directory.
+    fn cache_path(&self, pdbid: &str, extension: &str) -> Option<PathBuf> {
+        self.cache_dir
+            .as_ref()
+            .map(|cache_dir| cache_dir.join(format!("{}.{}", pdbid.to_uppercase(), extension)))
+    }
+
+    /// Content of the entry from the cache directory, None when it is not cached.
+    fn cached_content(&self, pdbid: &str, extension: &str) -> Option<String> {
+        fs::read_to_string(self.cache_path(pdbid, extension)?).ok()
+    }
+
+    /// Writes the content of the entry to the cache directory (if there is one).
+    fn store_content(&self, pdbid: &str, extension: &str, content: &str) -> PyResult<()> {
+        if let (Some(cache_dir), Some(path)) = (&self.cache_dir, self.cache_path(pdbid, extension))
+        {
+            fs::create_dir_all(cache_dir)?;
+            fs::write(path, content)?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads the PDB file line by line and passes every ATOM/HETATM record to on_record,
+    /// until on_record returns false. Returns the number of records passed.
+    fn stream_atoms(
+        &self,
+        path: String,
+        mut on_record: impl FnMut(AtomType, AtomRecord) -> PyResult<bool>,
+    ) -> PyResult<usize> {
+        let mut reader = BufReader::new(File::open(path)?);
+        let mut line = String::with_capacity(128);
+        let mut line_number = 0;
+        let mut count = 0;
+
+        while reader.read_line(&mut line)? > 0 {
+            let record = line.trim_end_matches(['\n', '\r']);
+
+            let label = if record.starts_with("ATOM") {
+                Some(AtomType::ATOM)
+            } else if record.starts_with("HETATM") {
+                Some(AtomType::HETATM)
+            } else {
+                None
+            };
+
+            if let Some(label) = label {
+                let atom = parse_atom_record(record, line_number, self.guess_elements)?;
+
+                if !(self.skip_water && residue_names::is_water(atom.residue_name)) {
+                    count += 1;
+
+                    if !on_record(label, atom)? {
+                        break;
+                    }
+                }
+            }
+
+            line.clear();
+            line_number += 1;
+        }
+
+        Ok(count)
+    }
+
+    /// Parses the PDB content (with the atom records parsed on multiple threads when
+    /// parallel), then applies the alternate location handling.
+    fn parse_content(
+        &self,
+        python: Python,
+        content: &str,
+        records: Option<&HashSet<String>>,
+        strict: bool,
+        parallel: bool,
+    ) -> PyResult<Structure> {
+        let atom_records = match parallel {
+            true => Some(
+                python.allow_threads(|| parse_atom_records(content, records, self.guess_elements)),
+            ),
+            false => None,
+        };
+
+        let structure = parse_pdb(
+            python,
+            content,
+            records,
+            self.guess_elements,
+            strict,
+            atom_records,
+            self.skip_water,
+        )?;
+        self.apply_alt_loc(python, &structure);
+
+        Ok(structure)
+    }
+
+    /// Parses the mmCIF content, then applies the alternate location handling.
+    fn parse_cif_content(&self, python: Python, content: &str) -> PyResult<Structure> {
+        let structure = parse_cif(python, content, self.skip_water)?;
+        self.apply_alt_loc(python, &structure);
+
+        Ok(structure)
+    }
+
+    /// Keeps only the best conformers in all models when requested by the alt_loc option.
+    fn apply_alt_loc(&self, python: Python, structure: &Structure) {
+        if let AltLocMode::Best = self.alt_loc {
+            keep_best_alt_locs(python, &structure.chains);
+
+            for model in structure.models.iter().flatten() {
+                keep_best_alt_locs(python, &model.borrow(python).chains);
+            }
+        }
+    }
+}
+
+/// Removes all but the highest-occupancy conformer (the first one on ties) of every atom
+/// with alternate locations.
+fn keep_best_alt_locs(python: Python, chains: &IndexMap<char, Option<Py<Chain>>>) {
+    for residue in chains.values().flat_map(|chain| {
+        chain
+            .as_ref()
+            .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+            .borrow(python)
+            .residues
+            .values()
+            .map(|residue| {
+                residue
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .clone_ref(python)
+            })
+            .collect::<Vec<Py<Residue>>>()
+    }) {
+        let mut residue = residue.borrow_mut(python);
+
+        let conformers: Vec<(String, Option<char>, f64)> = residue
+            .atoms
+            .iter()
+            .map(|atom| {
+                let atom = atom
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python);
+
+                (atom.name.to_string(), atom.alt_loc, atom.occupancy)
+            })
+            .collect();
+
+        let keep: Vec<bool> = conformers
+            .iter()
+            .enumerate()
+            .map(|(index, (name, alt_loc, occupancy))| {
+                alt_loc.is_none()
+                    || !conformers.iter().enumerate().any(
+                        |(other_index, (other_name, other_alt_loc, other_occupancy))| {
+                            other_alt_loc.is_some()
+                                && other_name == name
+                                && (other_occupancy > occupancy
+                                    || (other_occupancy == occupancy && other_index < index))
+                        },
+                    )
+            })
+            .collect();
+
+        let mut keep = keep.into_iter();
+        residue.atoms.retain(|_| keep.next().unwrap_or(true));
+    }
+
+    ATOMS_GENERATION.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Content of the file data, decompressed when gzip-compressed. Plain data is taken over
+/// without copying, so the content of a file read with fs::read (allocated once, with the
+/// size of the file) is held in memory only once.
+#[inline(always)]
+fn decode_content(data: Vec<u8>) -> PyResult<String> {
+    if data.starts_with(&GZIP_MAGIC) {
+        decompress(&data)
+    } else {
+        match String::from_utf8(data) {
+            Ok(content) => Ok(content),
+            Err(error) => Err(PyException::new_err(format!("{}", error))),
+        }
+    }
+}
+
+/// Content of the gzip-compressed data.
+fn decompress(data: &[u8]) -> PyResult<String> {
+    let mut content = String::with_capacity(data.len() * 4);
+    GzDecoder::new(data).read_to_string(&mut content)?;
+
+    Ok(content)
+}
+
+/// Content of the mapped (plain) file, decoded line by line without copying, so an invalid
+/// line is reported with its number.
+fn decode_mapped(data: &[u8]) -> PyResult<&str> {
+    for (line_number, line) in data.split(|byte| *byte == b'\n').enumerate() {
+        if let Err(error) = std::str::from_utf8(line) {
+            return Err(PyException::new_err(format!(
+                "line: {}, {}",
+                line_number + 1,
+                error
+            )));
+        }
+    }
+
+    // Safety: every line is valid UTF-8 and the lines are separated by line feeds (ASCII),
+    // so the whole content is valid UTF-8 as well:
+    Ok(unsafe { std::str::from_utf8_unchecked(data) })
+}
+
+#[inline(always)]
+fn parse_numeric<T: FromStr>(
+    line: &str,
+    line_number: usize,
+    from: usize,
+    to: usize,
+) -> PyResult<T> {
+    line[from..to].trim().parse::<T>().map_err(|_| {
+        PyException::new_err(format!(
+            "error in line: {}, cannot parse numeric",
+            line_number + 1
+        ))
+    })
+}
+
+/// Downloads the content under the URL, retrying transient failures up to the number of
+/// retries, the n-th retry after a delay of n * RETRY_BACKOFF. The error of the last attempt is raised.
+fn download(url: &str, options: FetchOptions) -> PyResult<Vec<u8>> {
+    let mut retry = 0;
+
+    loop {
+        match download_once(url, options.timeout) {
+            Ok(content) => return Ok(content),
+            Err((_, true)) if retry < options.retries => {
+                retry += 1;
+                thread::sleep(RETRY_BACKOFF * retry);
+            }
+            Err((message, _)) => return Err(PyException::new_err(message)),
+        }
+    }
+}
+
+/// Single download attempt. A failure is reported with its message and whether it is
+/// transient (connection errors, timeouts and server errors are worth retrying, client
+/// errors such as 404 and invalid URLs are not).
+fn download_once(url: &str, timeout: Option<Duration>) -> Result<Vec<u8>, (String, bool)> {
+    let mut request = CLIENT.get_or_init(reqwest::blocking::Client::new).get(url);
+
+    if let Some(timeout) = timeout {
+        request = request.timeout(timeout);
+    }
+
+    let response = match request.send() {
+        Ok(response) => response,
+        Err(error) => {
+            let transient = error.is_connect() || error.is_timeout();
+            return Err((format!("{}", error), transient));
+        }
+    };
+
+    let response_status = response.status();
+
+    if response_status != 200 {
+        return Err((
+            format!("connection error, status: {}", response_status),
+            response_status.is_server_error(),
+        ));
+    }
+
+    match response.bytes() {
+        Ok(content) => Ok(content.to_vec()),
+        Err(error) => Err((format!("{}", error), true)),
+    }
+}
+
+/// Downloads the file of the entry (with the extension, "pdb" or "cif") from RCSB PDB.
+#[inline(always)]
+fn fetch_content(pdbid: &str, extension: &str, options: FetchOptions) -> PyResult<String> {
+    decode_content(download(
+        &format!(
+            "https://files.rcsb.org/download/{}.{}",
+            pdbid.to_lowercase(),
+            extension
+        ),
+        options,
+    )?)
+}
+
+/// Downloads the PDB files of the entries, split between up to MAX_CONCURRENT_DOWNLOADS
+/// threads. Results are in the order of the PDB IDs.
+fn fetch_contents(pdbids: &[String], options: FetchOptions) -> Vec<PyResult<String>> {
+    let chunk_size = pdbids.len().div_ceil(MAX_CONCURRENT_DOWNLOADS).max(1);
+
+    thread::scope(|scope| {
+        let workers: Vec<_> = pdbids
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|pdbid| fetch_content(pdbid, "pdb", options))
+                        .collect::<Vec<PyResult<String>>>()
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| {
+                worker
+                    .join()
+                    .expect(concat!("thread error in: ", file!(), ", line: ", line!()))
+            })
+            .collect()
+    })
+}
+
+/// Raises a ValueError for record types not in SUPPORTED_RECORDS.
+#[inline(always)]
+fn check_records(records: Option<&HashSet<String>>) -> PyResult<()> {
+    if let Some(record) = records
+        .into_iter()
+        .flatten()
+        .find(|record| !SUPPORTED_RECORDS.contains(&record.as_str()))
+    {
+        return Err(PyValueError::new_err(format!(
+            "unsupported record type: {}",
+            record
+        )));
+    }
+
+    Ok(())
+}
+
+/// True for strings shaped like a PDB ID (a digit 1-9 followed by 3 alphanumerics).
+#[inline(always)]
+fn is_pdbid(text: &str) -> bool {
+    let bytes = text.as_bytes();
+
+    bytes.len() == 4
+        && (b'1'..=b'9').contains(&bytes[0])
+        && bytes[1..].iter().all(|byte| byte.is_ascii_alphanumeric())
+}
+
+/// Serial number or residue number (hybrid-36 encoded in structures with more than 99999
+/// atoms or 9999 residues) in the columns.
+#[inline(always)]
+fn parse_hybrid36(line: &str, line_number: usize, (from, to): (usize, usize)) -> PyResult<i32> {
+    hybrid36::decode(&line[from..to], (to - from) as u32).ok_or_else(|| {
+        PyException::new_err(format!(
+            "error in line: {}, cannot parse numeric",
+            line_number + 1
+        ))
+    })
+}
+
+/// Like parse_numeric, but a blank field yields the default value.
+#[inline(always)]
+fn parse_optional_numeric<T: FromStr>(
+    line: &str,
+    line_number: usize,
+    from: usize,
+    to: usize,
+    default: T,
+) -> PyResult<T> {
+    match line[from..to].trim().is_empty() {
+        true => Ok(default),
+        false => parse_numeric::<T>(line, line_number, from, to),
+    }
+}
+
+/// Model number of the MODEL record (columns 11-14, read leniently from column 7 on).
+#[inline(always)]
+fn parse_model_number(line: &str, line_number: usize) -> PyResult<i32> {
+    line[6..].trim().parse::<i32>().map_err(|_| {
+        PyException::new_err(format!(
+            "error in line: {}, cannot parse model number",
+            line_number + 1
+        ))
+    })
+}
+
+/// Element from columns 77-78. Lines truncated before the element (or with a blank element)
+/// fall back to the element guessed from the atom name field, or to a blank element when
+/// guessing is disabled.
+#[inline(always)]
+fn parse_element(line: &str, guess_elements: bool) -> &str {
+    let field = line
+        .get(ELEMENT.0..ELEMENT.1)
+        .or_else(|| line.get(ELEMENT.0..))
+        .unwrap_or_default()
+        .trim();
+
+    match field.is_empty() && guess_elements {
+        true => guess_element(&line[NAME.0..NAME.1]),
+        false => field,
+    }
+}
+
+/// Element guessed from the atom name field (columns 13-16). Element symbols are
+/// right-justified in columns 13-14, so a name starting in column 14 (after a blank or a
+/// digit, e.g. " CA " or "1HA ") has a one-letter element (the first letter, any
+/// remoteness indicator and branch digits are ignored), while a name starting in column 13
+/// (e.g. "CA  " or "FE  ") has a two-letter element. Names starting in column 13 fall back
+/// to the first letter when the two letters are not a known element and for four-character
+/// hydrogen names (e.g. "HG21").
+fn guess_element(field: &str) -> &str {
+    match field.as_bytes().first() {
+        Some(first) if first.is_ascii_alphabetic() => {
+            let hydrogen = matches!(first, b'H' | b'D') && field.trim_end().len() == 4;
+
+            match field.get(0..2) {
+                Some(symbol) if !hydrogen && elements::atomic_mass(symbol).is_some() => symbol,
+                _ => &field[0..1],
+            }
+        }
+        _ => field
+            .find(|character: char| character.is_ascii_alphabetic())
+            .and_then(|start| field.get(start..start + 1))
+            .unwrap_or_default(),
+    }
+}
+
+/// Formal charge from columns 79-80 (e.g. "2+", "1-"), None when the field is blank or
+/// missing.
+#[inline(always)]
+fn parse_charge(line: &str, line_number: usize) -> PyResult<Option<i8>> {
+    let field = line
+        .get(CHARGE.0..CHARGE.1)
+        .or_else(|| line.get(CHARGE.0..))
+        .unwrap_or_default()
+        .trim();
+
+    if field.is_empty() {
+        return Ok(None);
+    }
+
+    let charge = match (field.strip_suffix('+'), field.strip_suffix('-')) {
+        (Some(magnitude), _) => magnitude.parse::<i8>().ok(),
+        (_, Some(magnitude)) => magnitude.parse::<i8>().ok().map(|magnitude| -magnitude),
+        _ => field.parse::<i8>().ok(),
+    };
+
+    match charge {
+        Some(charge) => Ok(Some(charge)),
+        None => Err(PyException::new_err(format!(
+            "error in line: {}, cannot parse charge",
+            line_number + 1
+        ))),
+    }
+}
+
+#[inline(always)]
+fn parse_header_into(line: &str, line_number: usize, structure: &mut Structure) -> PyResult<()> {
+    if line.len() < 66 {
+        return Err(PyException::new_err(format!(
+            "error in line: {}, HEADER line to short",
+            line_number + 1
+        )));
+    }
+
+    let pdbid = line[62..66].trim();
+    let classification = line[10..50].trim();
+    let date = line[50..59].trim();
+
+    structure.set_header(pdbid, classification, date);
+
+    Ok(())
+}
+
+/// Text of the TITLE record (columns 11-80), appended to the title. Continuation records
+/// (numbered in columns 9-10) follow the first one, so the text is joined in file order.
+#[inline(always)]
+fn parse_title_into(line: &str, structure: &mut Structure) {
+    if line.len() > 10 {
+        Structure::append_text(&mut structure.title, line[10..].trim());
+    }
+}
+
+/// Anisotropic displacement parameters of the ANISOU record (U11, U22, U33, U12, U13 and U23
+/// from columns 29-70, stored multiplied by 10^4), given to the atom of the preceding atom
+/// record, which must have the same serial number (columns 7-11).
+#[inline(always)]
+fn parse_anisou_into(
+    python: Python,
+    line: &str,
+    line_number: usize,
+    atom: &Py<Atom>,
+) -> PyResult<()> {
+    if line.len() < 70 {
+        return Err(PyException::new_err(format!(
+            "error in line: {}, ANISOU line to short",
+            line_number + 1
+        )));
+    }
+
+    let serial = parse_hybrid36(line, line_number, SERIAL)?;
+    let mut atom = atom.borrow_mut(python);
+
+    if serial != atom.number {
+        return Err(PyException::new_err(format!(
+            "error in line: {}, ANISOU serial number {} does not match the preceding atom {}",
+            line_number + 1,
+            serial,
+            atom.number
+        )));
+    }
+
+    let mut u_aniso = [0.0; 6];
+
+    for (index, value) in u_aniso.iter_mut().enumerate() {
+        let from = 28 + 7 * index;
+
+        *value = line[from..from + 7].trim().parse::<i32>().map_err(|_| {
+            PyException::new_err(format!(
+                "error in line: {}, cannot parse ANISOU (columns {}-{})",
+                line_number + 1,
+                from + 1,
+                from + 7
+            ))
+        })? as f64
+            / 10000.0;
+    }
+
+    atom.u_aniso = Some(u_aniso);
+
+    Ok(())
+}
+
+/// Residue names of the SEQRES record (from column 20, up to 13 names), stored with the
+/// serial number of the record (columns 8-10) under the chain name (column 12). Chains
+/// spread over several records are put together in the order of the serial numbers.
+#[inline(always)]
+fn parse_seqres_into(
+    line: &str,
+    line_number: usize,
+    seqres: &mut IndexMap<char, Vec<(usize, Vec<String>)>>,
+) -> PyResult<()> {
+    if line.len() < 19 {
+        return Err(PyException::new_err(format!(
+            "error in line: {}, SEQRES line to short",
+            line_number + 1
+        )));
+    }
+
+    let serial = line[7..10].trim().parse::<usize>().map_err(|_| {
+        PyException::new_err(format!(
+            "error in line: {}, cannot parse SEQRES serial number",
+            line_number + 1
+        ))
+    })?;
+
+    let chain_name = line.chars().nth(11).unwrap_or(' ');
+    let names = line[19..].split_whitespace().map(String::from).collect();
+
+    seqres.entry(chain_name).or_default().push((serial, names));
+
+    Ok(())
+}
+
+/// Stores the SEQRES sequences in the chains of the same names.
+fn seqres_into_chains(
+    python: Python,
+    seqres: &IndexMap<char, Vec<(usize, Vec<String>)>>,
+    chains: &IndexMap<char, Option<Py<Chain>>>,
+) {
+    for (name, chain) in chains.iter() {
+        if let Some(records) = seqres.get(name) {
+            let mut records = records.clone();
+            records.sort_by_key(|(serial, _)| *serial);
+
+            chain
+                .as_ref()
+                .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                .borrow_mut(python)
+                .seqres = records.into_iter().flat_map(|(_, names)| names).collect();
+        }
+    }
+}
+
+/// Bonds of the CONECT record: the atom from columns 7-11 bonded to up to four atoms from
+/// columns 12-31, stored as (smaller serial, larger serial) pairs.
+#[inline(always)]
+fn parse_conect_into(line: &str, line_number: usize, structure: &mut Structure) -> PyResult<()> {
+    if line.len() < 11 {
+        return Err(PyException::new_err(format!(
+            "error in line: {}, CONECT line to short",
+            line_number + 1
+        )));
+    }
+
+    let atom = parse_hybrid36(line, line_number, SERIAL)? as usize;
+
+    for (from, to) in [(11, 16), (16, 21), (21, 26), (26, 31)] {
+        let field = line
+            .get(from..to)
+            .or_else(|| line.get(from..))
+            .unwrap_or_default()
+            .trim();
+
+        if field.is_empty() {
+            continue;
+        }
+
+        let other = hybrid36::decode(field, 5)
+            .and_then(|other| usize::try_from(other).ok())
+            .ok_or_else(|| {
+                PyException::new_err(format!(
+                    "error in line: {}, cannot parse numeric",
+                    line_number + 1
+                ))
+            })?;
+
+        structure.bonds.push((atom.min(other), atom.max(other)));
+    }
+
+    Ok(())
+}
+
+#[inline(always)]
+fn parse_expdta_into(line: &str, structure: &mut Structure) {
+    if line.len() > 10 {
+        Structure::append_text(&mut structure.experimental_method, line[10..].trim());
+    }
+}
+
+#[inline(always)]
+fn parse_resolution_into(line: &str, structure: &mut Structure) {
+    structure.resolution = line[22..]
+        .split_whitespace()
+        .next()
+        .and_then(|resolution| resolution.parse::<f64>().ok());
+}
+
 #[inline(always)]
-fn parse_pdb(python: Python, content: &str) -> PyResult<Structure> {
+fn parse_cryst1_into(
+    python: Python,
+    line: &str,
+    line_number: usize,
+    structure: &mut Structure,
+) -> PyResult<()> {
+    if line.len() < 54 {
+        return Err(PyException::new_err(format!(
+            "error in line: {}, CRYST1 line to short",
+            line_number + 1
+        )));
+    }
+
+    let a = parse_numeric::<f64>(line, line_number, 6, 15)?;
+    let b = parse_numeric::<f64>(line, line_number, 15, 24)?;
+    let c = parse_numeric::<f64>(line, line_number, 24, 33)?;
+    let alpha = parse_numeric::<f64>(line, line_number, 33, 40)?;
+    let beta = parse_numeric::<f64>(line, line_number, 40, 47)?;
+    let gamma = parse_numeric::<f64>(line, line_number, 47, 54)?;
+
+    let unit_cell = UnitCell::new(a, b, c, alpha, beta, gamma);
+    structure.set_unit_cell(python, unit_cell)?;
+
+    Ok(())
+}
+
+/// First private use character, temporary chain keys are taken from here on.
+const TEMPORARY_CHAIN_KEY: u32 = 0xE000;
+
+/// Separation of chains on TER records. A polymer (ATOM records) that follows a TER record
+/// and reuses the name of an existing chain is put into a fresh chain, and so are HETATM
+/// records following a TER that closed a non-water HETATM segment (e.g. a ligand polymer),
+/// while waters keep the chain of the preceding segment. Such chains get temporary keys while
+/// parsing and unused chain names when the model is complete (see name_split_chains), so
+/// names used later in the file are never taken.
+#[derive(Default)]
+struct ChainSplitter {
+    /// Set by a TER record, cleared by the next ATOM/HETATM record.
+    ter_seen: bool,
+    /// Whether the last ATOM/HETATM record was a non-water HETATM record.
+    hetero_seen: bool,
+    /// Current key of the chain for every chain name of the file.
+    keys: HashMap<char, char>,
+    /// Number of temporary keys given out.
+    count: u32,
+}
+
+impl ChainSplitter {
+    /// Key of the chain the atom record with the chain name belongs to.
+    fn chain_key(
+        &mut self,
+        name: char,
+        label: &AtomType,
+        residue_name: &str,
+        chains: &IndexMap<char, Option<Py<Chain>>>,
+    ) -> char {
+        let key = *self.keys.get(&name).unwrap_or(&name);
+        let hetero = matches!(label, AtomType::HETATM) && !residue_names::is_water(residue_name);
+        let after_hetero = std::mem::replace(&mut self.hetero_seen, hetero);
+        let splits = matches!(label, AtomType::ATOM) || (hetero && after_hetero);
+
+        if std::mem::take(&mut self.ter_seen) && splits && chains.contains_key(&key) {
+            if let Some(temporary) = char::from_u32(TEMPORARY_CHAIN_KEY + self.count) {
+                self.count += 1;
+                self.keys.insert(name, temporary);
+
+                return temporary;
+            }
+        }
+
+        key
+    }
+}
+
+/// Gives the chains split on TER records (with temporary keys) unused chain names.
+fn name_split_chains(
+    python: Python,
+    chains: &mut IndexMap<char, Option<Py<Chain>>>,
+) -> PyResult<()> {
+    let is_temporary = |key: &char| *key as u32 >= TEMPORARY_CHAIN_KEY;
+
+    if !chains.keys().any(is_temporary) {
+        return Ok(());
+    }
+
+    let mut names: Vec<char> = CHAIN_NAMES
+        .chars()
+        .filter(|name| !chains.contains_key(name))
+        .collect();
+    names.reverse();
+
+    let mut named = IndexMap::with_capacity(chains.len());
+
+    for (key, chain) in chains.drain(..) {
+        let key = match is_temporary(&key) {
+            true => {
+                let name = names.pop().ok_or_else(|| {
+                    PyException::new_err("no unused chain names left for chains split on TER")
+                })?;
+
+                chain
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow_mut(python)
+                    .name = name;
+
+                name
+            }
+            false => key,
+        };
+
+        named.insert(key, chain);
+    }
+
+    *chains = named;
+
+    Ok(())
+}
+
+/// Fields of a single ATOM/HETATM record.
+struct AtomRecord<'a> {
+    atom_number: i32,
+    atom_name: &'a str,
+    atom_alt_loc: Option<char>,
+    residue_name: &'a str,
+    chain_name: char,
+    residue_number: i32,
+    insertion_code: Option<char>,
+    atom_pos_x: f64,
+    atom_pos_y: f64,
+    atom_pos_z: f64,
+    atom_occupancy: f64,
+    atom_b_factor: f64,
+    atom_element: &'a str,
+    atom_charge: Option<i8>,
+}
+
+/// Fields of the atom record passed to the callbacks of parse_streaming and for_each_atom:
+/// (label, atom number, atom name, residue name, residue number, chain name, x, y, z,
+/// occupancy, element).
+#[allow(clippy::type_complexity)]
+#[inline(always)]
+fn atom_fields(
+    label: AtomType,
+    atom: AtomRecord<'_>,
+) -> (String, i32, &str, &str, i32, char, f64, f64, f64, f64, &str) {
+    (
+        format!("{}", label),
+        atom.atom_number,
+        atom.atom_name,
+        atom.residue_name,
+        atom.residue_number,
+        atom.chain_name,
+        atom.atom_pos_x,
+        atom.atom_pos_y,
+        atom.atom_pos_z,
+        atom.atom_occupancy,
+        atom.atom_element,
+    )
+}
+
+#[inline(always)]
+fn parse_atom_record(
+    line: &str,
+    line_number: usize,
+    guess_elements: bool,
+) -> PyResult<AtomRecord<'_>> {
+    if line.len() < OCCUPANCY.1 {
+        return Err(PyException::new_err(format!(
+            "error in line: {}, ATOM/HETATM line to short",
+            line_number + 1
+        )));
+    }
+
+    let atom_name = line[NAME.0..NAME.1].trim();
+
+    Ok(AtomRecord {
+        atom_number: parse_hybrid36(line, line_number, SERIAL)?,
+        atom_name,
+        atom_alt_loc: line[ALT_LOC.0..ALT_LOC.1]
+            .chars()
+            .next()
+            .filter(|character| *character != ' '),
+        residue_name: line[RESIDUE_NAME.0..RESIDUE_NAME.1].trim(),
+        chain_name: line.chars().nth(CHAIN.0).unwrap(),
+        residue_number: parse_hybrid36(line, line_number, RESIDUE_NUMBER)?,
+        insertion_code: line[INSERTION_CODE.0..INSERTION_CODE.1]
+            .chars()
+            .next()
+            .filter(|character| *character != ' '),
+        atom_pos_x: parse_numeric::<f64>(line, line_number, X.0, X.1)?,
+        atom_pos_y: parse_numeric::<f64>(line, line_number, Y.0, Y.1)?,
+        atom_pos_z: parse_numeric::<f64>(line, line_number, Z.0, Z.1)?,
+        atom_occupancy: parse_optional_numeric::<f64>(
+            line,
+            line_number,
+            OCCUPANCY.0,
+            OCCUPANCY.1,
+            1.0,
+        )?,
+        // Lines ending after the occupancy (or within the B-factor) are accepted:
+        atom_b_factor: match line.get(B_FACTOR.0..B_FACTOR.1.min(line.len())) {
+            Some(field) if !field.trim().is_empty() => {
+                parse_numeric::<f64>(line, line_number, B_FACTOR.0, B_FACTOR.1.min(line.len()))?
+            }
+            _ => 0.0,
+        },
+        atom_element: parse_element(line, guess_elements),
+        atom_charge: parse_charge(line, line_number)?,
+    })
+}
+
+#[inline(always)]
+fn parse_atom_into(
+    python: Python,
+    record: PyResult<AtomRecord>,
+    label: AtomType,
+    chains: &mut IndexMap<char, Option<Py<Chain>>>,
+    splitter: &mut ChainSplitter,
+    skip_water: bool,
+) -> PyResult<Option<Py<Atom>>> {
+    let mut record = record?;
+    record.chain_name = splitter.chain_key(record.chain_name, &label, record.residue_name, chains);
+
+    if skip_water && residue_names::is_water(record.residue_name) {
+        return Ok(None);
+    }
+
+    insert_atom(python, record, label, chains).map(Some)
+}
+
+/// Adds the atom of the record to the chains, under the chain, residue and atom of the
+/// record (missing chains and residues are created). Returns the added atom.
+#[inline(always)]
+fn insert_atom(
+    python: Python,
+    record: AtomRecord,
+    label: AtomType,
+    chains: &mut IndexMap<char, Option<Py<Chain>>>,
+) -> PyResult<Py<Atom>> {
+    let AtomRecord {
+        atom_number,
+        atom_name,
+        atom_alt_loc,
+        residue_name,
+        chain_name,
+        residue_number,
+        insertion_code,
+        atom_pos_x,
+        atom_pos_y,
+        atom_pos_z,
+        atom_occupancy,
+        atom_b_factor,
+        atom_element,
+        atom_charge,
+    } = record;
+
+    let atom = Py::new(
+        python,
+        Atom::new(
+            label,
+            atom_number,
+            atom_name,
+            atom_alt_loc,
+            atom_element,
+            (atom_pos_x, atom_pos_y, atom_pos_z),
+            atom_occupancy,
+            atom_b_factor,
+            atom_charge,
+        ),
+    )?;
+
+    // Chain (adds the chain if it does not exist yet):
+    let chain = match chains.get(&chain_name) {
+        Some(chain) => chain,
+        None => {
+            chains.insert(chain_name, Some(Py::new(python, Chain::new(chain_name))?));
+
+            &chains[&chain_name]
+        }
+    };
+
+    let mut chain = chain
+        .as_ref()
+        .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+        .borrow_mut(python);
+
+    // Residue (adds the residue if it does not exist yet), looked up by name, number and
+    // insertion code, so any residue number (including negative ones) is handled the same
+    // way and inserted residues (e.g. 100A after 100) stay distinct:
+    let key = (residue_name.into(), residue_number, insertion_code);
+
+    match chain.residues.get(&key) {
+        Some(residue) => {
+            residue
+                .as_ref()
+                .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                .borrow_mut(python)
+                .atoms
+                .push(Some(atom.clone_ref(python)));
+        }
+        None => {
+            let mut residue = Residue::new(residue_number, insertion_code, residue_name);
+
+            residue.atoms.push(Some(atom.clone_ref(python)));
+            chain.residues.insert(key, Some(Py::new(python, residue)?));
+        }
+    }
+
+    Ok(atom)
+}
+
+fn validate_numeric<T: FromStr>(
+    line: &str,
+    line_number: usize,
+    from: usize,
+    to: usize,
+    field: &str,
+    warnings: &mut Vec<String>,
+) {
+    if line[from..to].trim().parse::<T>().is_err() {
+        warnings.push(format!(
+            "line: {}, {} (columns {}-{}) is not numeric",
+            line_number + 1,
+            field,
+            from + 1,
+            to
+        ));
+    }
+}
+
+fn validate_coordinate(line: &str, line_number: usize, from: usize, warnings: &mut Vec<String>) {
+    let field = &line[from..from + 8];
+
+    if field.trim().parse::<f64>().is_err() {
+        warnings.push(format!(
+            "line: {}, coordinate (columns {}-{}) is not numeric",
+            line_number + 1,
+            from + 1,
+            from + 8
+        ));
+    } else if field.as_bytes()[4] != b'.' {
+        warnings.push(format!(
+            "line: {}, coordinate (columns {}-{}) is misaligned",
+            line_number + 1,
+            from + 1,
+            from + 8
+        ));
+    }
+}
+
+fn validate_pdb(content: &str) -> Vec<String> {
+    let mut warnings = Vec::new();
+
+    for (line_number, line) in content.lines().enumerate() {
+        if line.len() < 6 || !line.is_ascii() {
+            continue;
+        }
+
+        match &line[0..6] {
+            "ATOM  " | "HETATM" => {
+                if line.len() < Z.1 {
+                    warnings.push(format!(
+                        "line: {}, ATOM/HETATM line to short",
+                        line_number + 1
+                    ));
+                    continue;
+                }
+
+                for (field, (from, to)) in
+                    [("atom number", SERIAL), ("residue number", RESIDUE_NUMBER)]
+                {
+                    if hybrid36::decode(&line[from..to], (to - from) as u32).is_none() {
+                        warnings.push(format!(
+                            "line: {}, {} (columns {}-{}) is not numeric",
+                            line_number + 1,
+                            field,
+                            from + 1,
+                            to
+                        ));
+                    }
+                }
+
+                for from in [X.0, Y.0, Z.0] {
+                    validate_coordinate(line, line_number, from, &mut warnings);
+                }
+
+                for (field, (from, to)) in [("occupancy", OCCUPANCY), ("B-factor", B_FACTOR)] {
+                    if line.len() >= to && !line[from..to].trim().is_empty() {
+                        validate_numeric::<f64>(line, line_number, from, to, field, &mut warnings);
+                    }
+                }
+
+                if line.len() < ELEMENT.1 || line[ELEMENT.0..ELEMENT.1].trim().is_empty() {
+                    warnings.push(format!(
+                        "line: {}, missing element (columns 77-78)",
+                        line_number + 1
+                    ));
+                }
+            }
+            "HEADER" if line.len() < 66 => {
+                warnings.push(format!("line: {}, HEADER line to short", line_number + 1));
+            }
+            "CRYST1" if line.len() < 54 => {
+                warnings.push(format!("line: {}, CRYST1 line to short", line_number + 1));
+            }
+            _ => {}
+        }
+    }
+
+    warnings
+}
+
+/// Raises the error of a record in strict mode, otherwise records it as a warning (the
+/// record is skipped).
+#[inline(always)]
+fn recover<T>(
+    python: Python,
+    result: PyResult<T>,
+    strict: bool,
+    warnings: &mut Vec<String>,
+) -> PyResult<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(error) if !strict => {
+            warnings.push(error.value(python).to_string());
+            Ok(None)
+        }
+        Err(error) => Err(error),
+    }
+}
+
+/// Type of the atom record of the line (only of the wanted record types), None for other
+/// lines.
+#[inline(always)]
+fn atom_label(line: &str, wanted: impl Fn(&str) -> bool) -> Option<AtomType> {
+    if &line[0..4] == "ATOM" && wanted("ATOM") {
+        Some(AtomType::ATOM)
+    } else if &line[0..6] == "HETATM" && wanted("HETATM") {
+        Some(AtomType::HETATM)
+    } else {
+        None
+    }
+}
+
+/// Parses the atom records (of the wanted record types) of the content in the order of their
+/// lines. The content is split into models on MODEL records and the models are parsed
+/// concurrently on the rayon thread pool; the records are collected in model order, so the
+/// result does not depend on the thread scheduling.
+fn parse_atom_records<'a>(
+    content: &'a str,
+    records: Option<&HashSet<String>>,
+    guess_elements: bool,
+) -> Vec<PyResult<AtomRecord<'a>>> {
+    let wanted = |record: &str| records.is_none_or(|records| records.contains(record));
+
+    let mut models: Vec<Vec<(usize, &str)>> = vec![Vec::new()];
+
+    for (line_number, line) in content.lines().enumerate() {
+        if line.starts_with("TER") || line.len() < 6 {
+            continue;
+        }
+
+        if &line[0..6] == "MODEL " {
+            models.push(Vec::new());
+        } else if atom_label(line, wanted).is_some() {
+            models
+                .last_mut()
+                .expect(concat!("parse error in: ", file!(), ", line: ", line!()))
+                .push((line_number, line));
+        }
+    }
+
+    models
+        .par_iter()
+        .map(|model| {
+            model
+                .iter()
+                .map(|(line_number, line)| parse_atom_record(line, *line_number, guess_elements))
+                .collect::<Vec<PyResult<AtomRecord>>>()
+        })
+        .collect::<Vec<Vec<PyResult<AtomRecord>>>>()
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+#[inline(always)]
+fn parse_pdb<'a>(
+    python: Python,
+    content: &'a str,
+    records: Option<&HashSet<String>>,
+    guess_elements: bool,
+    strict: bool,
+    atom_records: Option<Vec<PyResult<AtomRecord<'a>>>>,
+    skip_water: bool,
+) -> PyResult<Structure> {
     let mut structure = Structure::new(python)?;
+    let wanted = |record: &str| records.is_none_or(|records| records.contains(record));
+    let mut warnings = Vec::new();
+
+    // Atom records parsed in advance (in the order of their lines), otherwise every atom
+    // record is parsed when its line is reached:
+    let mut atom_records = atom_records.map(Vec::into_iter);
+    let mut next_atom_record = |line: &'a str, line_number: usize| match atom_records.as_mut() {
+        Some(atom_records) => {
+            atom_records
+                .next()
+                .expect(concat!("parse error in: ", file!(), ", line: ", line!()))
+        }
+        None => parse_atom_record(line, line_number, guess_elements),
+    };
+
+    // The first model is stored in the chains of the structure, the following ones are
+    // built here and moved to the models of the structure on ENDMDL:
+    let mut first_model_seen = false;
+    let mut current_model: Option<Model> = None;
+    let mut splitter = ChainSplitter::default();
+    let mut seqres = IndexMap::new();
+
+    // Atom of the last atom record (None when it was skipped), for its ANISOU record:
+    let mut previous_atom: Option<Py<Atom>> = None;
 
     for (line_number, line) in content.lines().enumerate() {
+        if line.starts_with("TER") {
+            splitter.ter_seen = true;
+            continue;
+        }
+
         if line.len() < 6 {
             // return Err(PyException::new_err(format!(
             //     "error in line: {}, label field error",
@@ -310,15 +2019,328 @@ fn parse_pdb(python: Python, content: &str) -> PyResult<Structure> {
             continue;
         }
 
-        if &line[0..4] == "ATOM" {
-            parse_atom_into(python, line, line_number, AtomType::ATOM, &mut structure)?;
-        } else if &line[0..6] == "HETATM" {
-            parse_atom_into(python, line, line_number, AtomType::HETATM, &mut structure)?;
-        } else if &line[0..6] == "HEADER" {
-            parse_header_into(line, line_number, &mut structure)?;
-        } else if &line[0..6] == "CRYST1" {
-            parse_cryst1_into(python, line, line_number, &mut structure)?;
+        let chains = match current_model.as_mut() {
+            Some(model) => &mut model.chains,
+            None => &mut structure.chains,
+        };
+
+        if let Some(label) = atom_label(line, wanted) {
+            let record = next_atom_record(line, line_number);
+            let result = parse_atom_into(python, record, label, chains, &mut splitter, skip_water);
+            previous_atom = recover(python, result, strict, &mut warnings)?.flatten();
+        } else if &line[0..4] == "ATOM" || &line[0..6] == "HETATM" {
+            previous_atom = None;
+        } else if &line[0..6] == "ANISOU" && wanted("ANISOU") {
+            if let Some(atom) = previous_atom.as_ref() {
+                let result = parse_anisou_into(python, line, line_number, atom);
+                recover(python, result, strict, &mut warnings)?;
+            }
+        } else if &line[0..6] == "MODEL " {
+            let result = parse_model_number(line, line_number);
+
+            let Some(number) = recover(python, result, strict, &mut warnings)? else {
+                continue;
+            };
+
+            splitter = ChainSplitter::default();
+
+            match first_model_seen {
+                true => {
+                    if let Some(mut model) = current_model.replace(Model::new(number)) {
+                        name_split_chains(python, &mut model.chains)?;
+                        structure.models.push(Some(Py::new(python, model)?));
+                    }
+                }
+                false => {
+                    first_model_seen = true;
+                    structure.first_model = number;
+                }
+            }
+        } else if &line[0..6] == "ENDMDL" {
+            if let Some(mut model) = current_model.take() {
+                name_split_chains(python, &mut model.chains)?;
+                structure.models.push(Some(Py::new(python, model)?));
+            }
+        } else if &line[0..6] == "HEADER" && wanted("HEADER") {
+            let result = parse_header_into(line, line_number, &mut structure);
+            recover(python, result, strict, &mut warnings)?;
+        } else if &line[0..6] == "CRYST1" && wanted("CRYST1") {
+            let result = parse_cryst1_into(python, line, line_number, &mut structure);
+            recover(python, result, strict, &mut warnings)?;
+        } else if &line[0..6] == "TITLE " && wanted("TITLE") {
+            parse_title_into(line, &mut structure);
+        } else if &line[0..6] == "EXPDTA" && wanted("EXPDTA") {
+            parse_expdta_into(line, &mut structure);
+        } else if line.starts_with("REMARK   2 RESOLUTION.") && wanted("REMARK") {
+            parse_resolution_into(line, &mut structure);
+        } else if &line[0..6] == "CONECT" && wanted("CONECT") {
+            let result = parse_conect_into(line, line_number, &mut structure);
+            recover(python, result, strict, &mut warnings)?;
+        } else if &line[0..6] == "SEQRES" && wanted("SEQRES") {
+            let result = parse_seqres_into(line, line_number, &mut seqres);
+            recover(python, result, strict, &mut warnings)?;
+        }
+    }
+
+    // Bonds are listed from both of their atoms:
+    structure.bonds.sort_unstable();
+    structure.bonds.dedup();
+
+    structure.warnings = warnings;
+
+    // The last model may lack its ENDMDL record:
+    if let Some(mut model) = current_model.take() {
+        name_split_chains(python, &mut model.chains)?;
+        structure.models.push(Some(Py::new(python, model)?));
+    }
+
+    name_split_chains(python, &mut structure.chains)?;
+
+    seqres_into_chains(python, &seqres, &structure.chains);
+
+    for model in structure.models.iter().flatten() {
+        seqres_into_chains(python, &seqres, &model.borrow(python).chains);
+    }
+
+    Ok(structure)
+}
+
+/// Month abbreviations of the dates in HEADER records.
+const MONTHS: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+/// First private use character of the supplementary plane, names of the chains of mmCIF files
+/// with more chains than CHAIN_NAMES are taken from here on.
+const EXTRA_CHAIN_NAME: u32 = 0xF0000;
+
+/// Date of an mmCIF file (YYYY-MM-DD) in the format of the HEADER record (DD-MON-YY), other
+/// dates are kept as they are.
+fn pdb_date(date: &str) -> String {
+    let mut parts = date.split('-');
+
+    match (
+        parts.next(),
+        parts.next().and_then(|month| month.parse::<usize>().ok()),
+        parts.next(),
+        parts.next(),
+    ) {
+        (Some(year), Some(month @ 1..=12), Some(day), None) if year.len() == 4 => {
+            format!("{}-{}-{}", day, MONTHS[month - 1], &year[2..])
+        }
+        _ => date.to_string(),
+    }
+}
+
+/// Value of the _atom_site column parsed as a number, the default is used for a missing
+/// column or a null value.
+#[inline(always)]
+fn parse_cif_numeric<T: FromStr>(
+    value: Option<&str>,
+    row: usize,
+    tag: &str,
+    default: Option<T>,
+) -> PyResult<T> {
+    match value {
+        Some(value) => value.parse::<T>().ok(),
+        None => default,
+    }
+    .ok_or_else(|| {
+        PyException::new_err(format!(
+            "error in _atom_site row: {}, cannot parse {}",
+            row + 1,
+            tag
+        ))
+    })
+}
+
+/// Builds the structure from the mmCIF content: the header from the _entry, _struct,
+/// _struct_keywords, _pdbx_database_status, _exptl and _refine items, the unit cell from
+/// the _cell items and the atoms from the _atom_site loop. Author (auth_) atom, residue and
+/// chain names and numbers are used where given, as in PDB files. Chain names longer than
+/// one character are replaced by unused chain names.
+fn parse_cif(python: Python, content: &str, skip_water: bool) -> PyResult<Structure> {
+    let data = CifData::parse(content)?;
+    let mut structure = Structure::new(python)?;
+
+    structure.set_header(
+        data.item("_entry.id").unwrap_or_default(),
+        data.item("_struct_keywords.pdbx_keywords")
+            .unwrap_or_default(),
+        &pdb_date(
+            data.item("_pdbx_database_status.recvd_initial_deposition_date")
+                .unwrap_or_default(),
+        ),
+    );
+    structure.title = data.item("_struct.title").unwrap_or_default().to_string();
+    structure.experimental_method = data.item("_exptl.method").unwrap_or_default().to_string();
+    structure.resolution = data
+        .item("_refine.ls_d_res_high")
+        .and_then(|resolution| resolution.parse::<f64>().ok());
+
+    let cell: Option<Vec<f64>> = [
+        "_cell.length_a",
+        "_cell.length_b",
+        "_cell.length_c",
+        "_cell.angle_alpha",
+        "_cell.angle_beta",
+        "_cell.angle_gamma",
+    ]
+    .iter()
+    .map(|tag| data.item(tag)?.parse::<f64>().ok())
+    .collect();
+
+    if let Some(cell) = cell {
+        let unit_cell = UnitCell::new(cell[0], cell[1], cell[2], cell[3], cell[4], cell[5]);
+        structure.set_unit_cell(python, unit_cell)?;
+    }
+
+    let atom_site = data
+        .category_loop("_atom_site")
+        .ok_or_else(|| PyException::new_err("missing _atom_site loop"))?;
+
+    // Columns of the field, in order of preference:
+    let columns = |names: &[&str]| -> Vec<usize> {
+        names
+            .iter()
+            .filter_map(|name| atom_site.column(&format!("_atom_site.{}", name)))
+            .collect()
+    };
+    let required = |names: &[&str]| -> PyResult<Vec<usize>> {
+        let found = columns(names);
+
+        match found.is_empty() {
+            true => Err(PyException::new_err(format!(
+                "missing _atom_site column: {}",
+                names.join(" or ")
+            ))),
+            false => Ok(found),
+        }
+    };
+    let value = |row: usize, columns: &[usize]| {
+        columns
+            .iter()
+            .find_map(|column| atom_site.value(row, *column))
+    };
+
+    let group = columns(&["group_PDB"]);
+    let serial = required(&["id"])?;
+    let atom_name = required(&["auth_atom_id", "label_atom_id"])?;
+    let alt_loc = columns(&["label_alt_id"]);
+    let residue_name = required(&["auth_comp_id", "label_comp_id"])?;
+    let chain_name = required(&["auth_asym_id", "label_asym_id"])?;
+    let residue_number = required(&["auth_seq_id", "label_seq_id"])?;
+    let insertion_code = columns(&["pdbx_PDB_ins_code"]);
+    let x = required(&["Cartn_x"])?;
+    let y = required(&["Cartn_y"])?;
+    let z = required(&["Cartn_z"])?;
+    let occupancy = columns(&["occupancy"]);
+    let b_factor = columns(&["B_iso_or_equiv"]);
+    let element = columns(&["type_symbol"]);
+    let charge = columns(&["pdbx_formal_charge"]);
+    let model = columns(&["pdbx_PDB_model_num"]);
+
+    // One-character chain names are kept, longer ones get unused names:
+    let mut chain_keys: HashMap<&str, char> = HashMap::new();
+
+    for row in 0..atom_site.num_rows() {
+        let name = value(row, &chain_name).unwrap_or(" ");
+        let mut characters = name.chars();
+
+        if let (Some(character), None) = (characters.next(), characters.next()) {
+            chain_keys.insert(name, character);
+        }
+    }
+
+    let mut unused_names = CHAIN_NAMES
+        .chars()
+        .filter(|name| !chain_keys.values().any(|key| key == name))
+        .collect::<Vec<char>>()
+        .into_iter()
+        .chain((EXTRA_CHAIN_NAME..).filter_map(char::from_u32));
+
+    let mut current_model: Option<Model> = None;
+    let mut model_number = None;
+
+    for row in 0..atom_site.num_rows() {
+        let number =
+            parse_cif_numeric::<i32>(value(row, &model), row, "pdbx_PDB_model_num", Some(1))?;
+
+        if model_number != Some(number) {
+            match model_number {
+                Some(_) => {
+                    if let Some(model) = current_model.replace(Model::new(number)) {
+                        structure.models.push(Some(Py::new(python, model)?));
+                    }
+                }
+                None => structure.first_model = number,
+            }
+
+            model_number = Some(number);
+        }
+
+        let chains = match current_model.as_mut() {
+            Some(model) => &mut model.chains,
+            None => &mut structure.chains,
+        };
+
+        let name = value(row, &chain_name).unwrap_or(" ");
+        let chain_key = match chain_keys.get(name) {
+            Some(key) => *key,
+            None => {
+                let key = unused_names.next().ok_or_else(|| {
+                    PyException::new_err("no unused chain names left for mmCIF chains")
+                })?;
+                chain_keys.insert(name, key);
+
+                key
+            }
+        };
+
+        let label = match value(row, &group) {
+            Some("HETATM") => AtomType::HETATM,
+            _ => AtomType::ATOM,
+        };
+
+        if skip_water && residue_names::is_water(value(row, &residue_name).unwrap_or_default()) {
+            continue;
         }
+
+        let record = AtomRecord {
+            atom_number: parse_cif_numeric(value(row, &serial), row, "id", None)?,
+            atom_name: name_field(value(row, &atom_name).unwrap_or_default()),
+            atom_alt_loc: value(row, &alt_loc).and_then(|alt_loc| alt_loc.chars().next()),
+            residue_name: name_field(value(row, &residue_name).unwrap_or_default()),
+            chain_name: chain_key,
+            residue_number: parse_cif_numeric(value(row, &residue_number), row, "seq_id", None)?,
+            insertion_code: value(row, &insertion_code).and_then(|code| code.chars().next()),
+            atom_pos_x: parse_cif_numeric(value(row, &x), row, "Cartn_x", None)?,
+            atom_pos_y: parse_cif_numeric(value(row, &y), row, "Cartn_y", None)?,
+            atom_pos_z: parse_cif_numeric(value(row, &z), row, "Cartn_z", None)?,
+            atom_occupancy: parse_cif_numeric(value(row, &occupancy), row, "occupancy", Some(1.0))?,
+            atom_b_factor: parse_cif_numeric(
+                value(row, &b_factor),
+                row,
+                "B_iso_or_equiv",
+                Some(0.0),
+            )?,
+            atom_element: name_field(value(row, &element).unwrap_or_default()),
+            atom_charge: match value(row, &charge) {
+                Some(charge) => Some(parse_cif_numeric(
+                    Some(charge),
+                    row,
+                    "pdbx_formal_charge",
+                    None,
+                )?),
+                None => None,
+            },
+        };
+
+        insert_atom(python, record, label, chains)?;
+    }
+
+    if let Some(model) = current_model.take() {
+        structure.models.push(Some(Py::new(python, model)?));
     }
 
     Ok(structure)
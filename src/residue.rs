@@ -1,11 +1,29 @@
-use crate::atom::Atom;
+use crate::{
+    atom::{self, name_field, Atom, AtomState, AtomType},
+    chain::ResidueKey,
+    geometry::Vector,
+    iterators::AtomIterator,
+    residue_names,
+};
 
 use pyo3::{
-    exceptions::PyIndexError, pyclass, pymethods, types::PyList, Py, PyRefMut, PyResult,
-    PyTraverseError, PyVisit, Python,
+    exceptions::{PyIndexError, PyKeyError},
+    pyclass, pymethods,
+    types::{PyList, PyString, PyType},
+    Py, PyCell, PyObject, PyRef, PyResult, PyTraverseError, PyVisit, Python,
 };
 
-use heapless;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Names of the protein backbone atoms, in the order returned by Residue.backbone.
+const BACKBONE_ATOMS: [&str; 4] = ["N", "CA", "C", "O"];
+
+/// Advanced whenever atoms are removed from a residue in place, so the atom counts cached by
+/// structures (see Structure::cached_num_atoms) are counted again.
+pub static ATOMS_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Pickled state of a residue: number, insertion code, name and the states of the atoms.
+pub type ResidueState = (i32, Option<char>, String, Vec<AtomState>);
 
 /// Residue - a class that represents a residue of a PDB structure.
 #[pyclass(module = "nanoPDB")]
@@ -14,9 +32,9 @@ pub struct Residue {
     #[pyo3(get)]
     pub number: i32,
 
+    pub insertion_code: Option<char>,
     pub name: heapless::String<4>,
     pub atoms: Vec<Option<Py<Atom>>>,
-    pub current_index: usize,
 }
 
 #[pymethods]
@@ -25,10 +43,17 @@ impl Residue {
     // Getters
     // ----------------------------------------------------------------------------------------
 
+    /// [str | None] Residue insertion code (None when blank).
+    #[getter]
+    pub fn insertion_code(&self) -> Option<char> {
+        self.insertion_code
+    }
+
     /// [str] Residue name.
     #[getter]
-    pub fn name(&self) -> String {
-        self.name.to_string()
+    pub fn name(&self, python: Python) -> Py<PyString> {
+        // Shared (interned) str object per distinct name, like the names of atoms:
+        PyString::intern(python, &self.name).into()
     }
 
     // ----------------------------------------------------------------------------------------
@@ -41,6 +66,10 @@ impl Residue {
         }
     }
 
+    pub fn __contains__(&self, python: Python, name: &str) -> bool {
+        self.find_atom(python, name.trim()).is_some()
+    }
+
     pub fn __getitem__(&self, python: Python, index: usize) -> PyResult<Py<Atom>> {
         if index < self.atoms.len() {
             Ok(self.atoms[index]
@@ -53,41 +82,38 @@ impl Residue {
         }
     }
 
-    pub fn __iter__(mut slf: PyRefMut<Self>) -> PyRefMut<Self> {
-        slf.current_index = 0;
-
-        slf
+    pub fn __iter__(slf: PyRef<Self>) -> AtomIterator {
+        AtomIterator::new(slf.into())
     }
 
     pub fn __len__(&self) -> usize {
         self.atoms.len()
     }
 
-    pub fn __next__(&mut self, python: Python) -> Option<Py<Atom>> {
-        if self.current_index < self.atoms.len() {
-            self.current_index += 1;
-
-            Some(
-                self.atoms[self.current_index - 1]
-                    .as_ref()
-                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
-                    .as_ref(python)
-                    .into(),
-            )
-        } else {
-            None
-        }
+    pub fn __reduce__(slf: &PyCell<Self>, python: Python) -> PyResult<(PyObject, (ResidueState,))> {
+        Ok((
+            slf.get_type().getattr("_from_state")?.into(),
+            (slf.borrow().state(python),),
+        ))
     }
 
     pub fn __repr__(&self) -> String {
+        format!(
+            "<Residue {} {}{}: {} atoms>",
+            self.name,
+            self.number,
+            self.insertion_code.map(String::from).unwrap_or_default(),
+            self.atoms.len()
+        )
+    }
+
+    pub fn __str__(&self) -> String {
         format!("{:#}", self)
     }
 
     pub fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
-        for atom in self.atoms.iter() {
-            if let Some(atom) = atom {
-                visit.call(atom)?;
-            }
+        for atom in self.atoms.iter().flatten() {
+            visit.call(atom)?;
         }
 
         Ok(())
@@ -97,6 +123,228 @@ impl Residue {
     // Methods
     // ----------------------------------------------------------------------------------------
 
+    /// Rebuilds the residue from its pickled state (see __reduce__).
+    #[classmethod]
+    #[pyo3(name = "_from_state", signature = (state, /))]
+    pub fn py_from_state(_class: &PyType, python: Python, state: ResidueState) -> PyResult<Self> {
+        Residue::from_state(python, state)
+    }
+
+    /// Returns the atom with the given name (e.g. "CA").
+    ///
+    /// Surrounding whitespace is ignored, so " CA " and "CA" are the same name. The first
+    /// matching atom is returned (the first alternate location of disordered atoms). The
+    /// atom name is also checked by `name in residue`.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// name : str
+    ///     The atom name.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// Atom
+    ///     The atom, a KeyError is raised if there is no such atom.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Retrieving the alpha carbon of the residue.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> residue = structure[0][0]
+    /// ...
+    /// >>> residue.atom("CA").number
+    ///
+    /// 2
+    #[pyo3(signature = (name, /))]
+    pub fn atom(&self, python: Python, name: &str) -> PyResult<Py<Atom>> {
+        match self.find_atom(python, name.trim()) {
+            Some(atom) => Ok(atom.clone_ref(python)),
+            None => Err(PyKeyError::new_err(format!("no atom: {}", name.trim()))),
+        }
+    }
+
+    /// Returns the protein backbone atoms of the residue: N, CA, C and O (in that order).
+    ///
+    /// Missing atoms are left out, so the list is shorter for incomplete residues (see
+    /// is_complete_backbone). Only amino acids (see is_amino_acid) have a backbone, the list
+    /// is empty for other residues (the backbone of nucleotides is not covered). The first
+    /// alternate location of disordered atoms is returned.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// list[Atom]
+    ///     The list of the backbone atoms present in the residue.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Retrieving the backbone atom names of the residue.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> residue = structure[0][0]
+    /// ...
+    /// >>> [atom.name for atom in residue.backbone()]
+    ///
+    /// ['N', 'CA', 'C', 'O']
+    #[pyo3(signature = (/))]
+    pub fn backbone(&self, python: Python) -> Vec<Py<Atom>> {
+        if !self.is_amino_acid() {
+            return Vec::new();
+        }
+
+        BACKBONE_ATOMS
+            .iter()
+            .filter_map(|name| self.find_atom(python, name))
+            .map(|atom| atom.clone_ref(python))
+            .collect()
+    }
+
+    /// Returns the axis-aligned bounding box of the residue (computed in a single pass).
+    ///
+    /// Useful for setting up grids, docking boxes and visualization viewports.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// ((float, float, float), (float, float, float))
+    ///     The minimum and maximum corners, a ValueError is raised for a residue without
+    ///     atoms.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Computing the bounding box of the residue.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> residue = structure[0][0]
+    /// ...
+    /// >>> residue.bounding_box()
+    ///
+    /// ((41.642, 32.997, 9.357), (46.947, 36.56, 12.461))
+    #[pyo3(signature = (/))]
+    pub fn bounding_box(&self, python: Python) -> PyResult<(Vector, Vector)> {
+        atom::bounding_box(python, &self.collect_atoms(python))
+    }
+
+    /// Returns the center of geometry (mean of the atom positions) of the residue.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// (float, float, float)
+    ///     The center of geometry, a ValueError is raised for a residue without atoms.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Computing the center of geometry of the residue.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> residue = structure[0][0]
+    /// ...
+    /// >>> residue.center_of_geometry()
+    ///
+    /// (43.632..., 34.636, 10.422...)
+    #[pyo3(signature = (/))]
+    pub fn center_of_geometry(&self, python: Python) -> PyResult<Vector> {
+        atom::center_of_geometry(python, &self.collect_atoms(python))
+    }
+
+    /// Returns the center of mass of the residue.
+    ///
+    /// Atoms are weighted by the atomic masses of their elements. Atoms of unknown elements
+    /// have no weight and are reported with a UserWarning.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// (float, float, float)
+    ///     The center of mass, a ValueError is raised when no atom has a known mass.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Computing the center of mass of the residue.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> residue = structure[0][0]
+    /// ...
+    /// >>> residue.center_of_mass()
+    ///
+    /// (43.912..., 34.414..., 10.370...)
+    #[pyo3(signature = (/))]
+    pub fn center_of_mass(&self, python: Python) -> PyResult<Vector> {
+        atom::center_of_mass(python, &self.collect_atoms(python))
+    }
+
+    /// Removes duplicated atoms (same name and exactly the same position) from the residue.
+    ///
+    /// The first occurrence of every duplicated atom is kept.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// int
+    ///     The number of removed atoms.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Cleaning up the residue.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> residue = structure[0][0]
+    /// ...
+    /// >>> residue.deduplicate_atoms()
+    ///
+    /// 0
+    #[pyo3(signature = (/))]
+    pub fn deduplicate_atoms(&mut self, python: Python) -> usize {
+        let total = self.atoms.len();
+        let mut kept: Vec<Option<Py<Atom>>> = Vec::with_capacity(total);
+
+        for atom in self.atoms.drain(..) {
+            let is_duplicate = {
+                let current = atom
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python);
+
+                kept.iter().any(|other| {
+                    other
+                        .as_ref()
+                        .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                        .borrow(python)
+                        .is_duplicate_of(&current)
+                })
+            };
+
+            if !is_duplicate {
+                kept.push(atom);
+            }
+        }
+
+        let removed = total - kept.len();
+        self.atoms = kept;
+
+        if removed > 0 {
+            ATOMS_GENERATION.fetch_add(1, Ordering::Relaxed);
+        }
+
+        removed
+    }
+
     /// Returns a list of atoms that builds the residue.
     ///
     ///
@@ -116,40 +364,9 @@ impl Residue {
     /// ...
     /// >>> residue.get_atoms()
     ///
-    /// [Atom {
-    ///     label: "ATOM",
-    ///     number: 1,
-    ///     name: "N",
-    ///     element: "N",
-    ///     position: (
-    ///         42.854,
-    ///         36.56,
-    ///         10.394,
-    ///     ),
-    ///     occupancy: 1.0,
-    /// }, Atom {
-    ///     label: "ATOM",
-    ///     number: 2,
-    ///     name: "CA",
-    ///     element: "C",
-    ///     position: (
-    ///         42.25,
-    ///         35.232,
-    ///         10.096,
-    ///     ),
-    ///     occupancy: 1.0,
-    /// }, Atom {
-    ///     label: "ATOM",
-    ///     number: 3,
-    ///     name: "C",
-    ///     element: "C",
-    ///     position: (
-    ///         41.642,
-    ///         34.623,
-    ///         11.355,
-    ///     ),
-    ///     occupancy: 1.0,
-    /// }
+    /// [<Atom N 1: (42.854, 36.56, 10.394)>,
+    ///  <Atom CA 2: (42.25, 35.232, 10.096)>,
+    ///  <Atom C 3: (41.642, 34.623, 11.355)>,
     /// ...
     #[pyo3(signature = (/))]
     pub fn get_atoms(&self, python: Python) -> Py<PyList> {
@@ -162,26 +379,315 @@ impl Residue {
         )
         .into()
     }
+
+    /// Returns the Kyte-Doolittle hydropathy of the residue.
+    ///
+    /// Modified amino acids (e.g. MSE) take the value of their parent amino acid.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// float | None
+    ///     The hydropathy score, None for residues that are not amino acids.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Retrieving the hydropathy of the residue.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> residue = structure[0][0]
+    /// ...
+    /// >>> residue.hydrophobicity()
+    ///
+    /// 1.9
+    #[pyo3(signature = (/))]
+    pub fn hydrophobicity(&self) -> Option<f64> {
+        residue_names::hydrophobicity(&self.name)
+    }
+
+    /// Checks whether the residue is an amino acid (one of the 20 standard ones, SEC, PYL or a
+    /// modified amino acid like MSE or SEP).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// bool
+    ///     True if the residue is an amino acid.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Checking the first residue.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure[0][0].is_amino_acid()
+    ///
+    /// True
+    #[pyo3(signature = (/))]
+    pub fn is_amino_acid(&self) -> bool {
+        residue_names::kind(&self.name) == "amino_acid"
+    }
+
+    /// Checks whether the residue is an amino acid with all backbone atoms (N, CA, C and O).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// bool
+    ///     True if none of the backbone atoms is missing, False for residues other than
+    ///     amino acids.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Counting the residues with complete backbones.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> sum(residue.is_complete_backbone() for residue in structure.residues())
+    ///
+    /// 436
+    #[pyo3(signature = (/))]
+    pub fn is_complete_backbone(&self, python: Python) -> bool {
+        self.is_amino_acid()
+            && BACKBONE_ATOMS
+                .iter()
+                .all(|name| self.find_atom(python, name).is_some())
+    }
+
+    /// Checks whether the residue is a nucleotide (A, C, G, U of RNA or DA, DC, DG, DT, DU of
+    /// DNA).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// bool
+    ///     True if the residue is a nucleotide.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Checking the first residue.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure[0][0].is_nucleic()
+    ///
+    /// False
+    #[pyo3(signature = (/))]
+    pub fn is_nucleic(&self) -> bool {
+        residue_names::kind(&self.name) == "nucleotide"
+    }
+
+    /// Checks whether the residue is a water (HOH, WAT or DOD, in any case).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// bool
+    ///     True if the residue is a water.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Checking the last residue of the structure.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.get_residues()[-1].is_water()
+    ///
+    /// True
+    #[pyo3(signature = (/))]
+    pub fn is_water(&self) -> bool {
+        residue_names::kind(&self.name) == "water"
+    }
+
+    /// Returns the kind of the residue, based on its name.
+    ///
+    /// Amino acids (standard and modified, e.g. MSE or SEP), nucleotides (DNA and RNA) and
+    /// waters are recognized by their names, any other residue (ions, cofactors, small
+    /// molecules) is a ligand.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// str
+    ///     "amino_acid", "nucleotide", "water" or "ligand".
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Counting the kinds of residues.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> collections.Counter(residue.kind() for residue in structure.residues())
+    ///
+    /// Counter({'amino_acid': 436, 'water': 262, 'ligand': 3})
+    #[pyo3(signature = (/))]
+    pub fn kind(&self) -> &'static str {
+        residue_names::kind(&self.name)
+    }
+
+    /// Returns the molecular weight of the residue, the sum of the atomic masses of its atoms.
+    ///
+    /// Only the atoms present in the file are counted, missing hydrogens are not added
+    /// implicitly. Atoms of unknown elements have no weight and are reported with a
+    /// UserWarning. Waters weigh 0.0 unless included.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// include_water : bool
+    ///     Whether water molecules are counted (default False).
+    /// include_hydrogens : bool
+    ///     Whether hydrogen (and deuterium) atoms are counted (default True).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// float
+    ///     The molecular weight (in daltons).
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Weighing the first residue.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure[0][0].molecular_weight()
+    ///
+    /// 122.121
+    #[pyo3(signature = (include_water = false, include_hydrogens = true))]
+    pub fn molecular_weight(
+        &self,
+        python: Python,
+        include_water: bool,
+        include_hydrogens: bool,
+    ) -> PyResult<f64> {
+        match !include_water && self.is_water() {
+            true => Ok(0.0),
+            false => atom::molecular_weight(python, &self.collect_atoms(python), include_hydrogens),
+        }
+    }
 }
 
 impl Residue {
     #[inline(always)]
-    pub fn new(number: i32, name: &str) -> Self {
+    pub fn new(number: i32, insertion_code: Option<char>, name: &str) -> Self {
         Residue {
             number,
+            insertion_code,
             name: name.into(),
             atoms: Vec::default(),
-            current_index: 0,
         }
     }
+
+    pub fn state(&self, python: Python) -> ResidueState {
+        (
+            self.number,
+            self.insertion_code,
+            self.name.to_string(),
+            self.atoms
+                .iter()
+                .flatten()
+                .map(|atom| atom.borrow(python).state())
+                .collect(),
+        )
+    }
+
+    pub fn from_state(python: Python, state: ResidueState) -> PyResult<Self> {
+        let (number, insertion_code, name, atoms) = state;
+        let mut residue = Residue::new(number, insertion_code, name_field(&name));
+
+        for atom in atoms {
+            residue
+                .atoms
+                .push(Some(Py::new(python, Atom::from_state(atom))?));
+        }
+
+        Ok(residue)
+    }
+
+    /// Key of the residue within its chain.
+    #[inline(always)]
+    pub fn key(&self) -> ResidueKey {
+        (self.name.clone(), self.number, self.insertion_code)
+    }
+
+    /// New residue with the same number, insertion code and name, built from the given atoms.
+    pub fn copy_with_atoms(&self, python: Python, atoms: Vec<Atom>) -> PyResult<Residue> {
+        let mut residue = Residue::new(self.number, self.insertion_code, &self.name);
+
+        for atom in atoms {
+            residue.atoms.push(Some(Py::new(python, atom)?));
+        }
+
+        Ok(residue)
+    }
+
+    /// All atoms of the residue.
+    pub fn collect_atoms(&self, python: Python) -> Vec<Py<Atom>> {
+        self.atoms
+            .iter()
+            .map(|atom| {
+                atom.as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .clone_ref(python)
+            })
+            .collect()
+    }
+
+    /// Copy of the residue with copies of all its atoms.
+    pub fn deep_copy(&self, python: Python) -> PyResult<Residue> {
+        self.copy_with_atoms(
+            python,
+            self.atoms
+                .iter()
+                .flatten()
+                .map(|atom| atom.borrow(python).clone())
+                .collect(),
+        )
+    }
+
+    pub fn find_atom(&self, python: Python, name: &str) -> Option<&Py<Atom>> {
+        self.atoms
+            .iter()
+            .map(|atom| {
+                atom.as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+            })
+            .find(|atom| atom.borrow(python).name.as_str() == name)
+    }
+
+    /// True if the residue contains any ATOM (polymer) record.
+    pub fn is_polymer(&self, python: Python) -> bool {
+        self.atoms
+            .iter()
+            .flatten()
+            .any(|atom| matches!(atom.borrow(python).label, AtomType::ATOM))
+    }
 }
 
 impl std::fmt::Display for Residue {
     fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        formatter
-            .debug_struct("Residue")
-            .field("number", &self.number)
-            .field("name", &self.name)
-            .finish()
+        let mut residue = formatter.debug_struct("Residue");
+        residue.field("number", &self.number);
+
+        if let Some(insertion_code) = self.insertion_code {
+            residue.field("insertion_code", &insertion_code);
+        }
+
+        residue.field("name", &self.name).finish()
     }
 }
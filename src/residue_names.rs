@@ -0,0 +1,145 @@
+static AMINO_ACIDS: &[(&str, char)] = &[
+    ("ALA", 'A'),
+    ("ARG", 'R'),
+    ("ASN", 'N'),
+    ("ASP", 'D'),
+    ("CYS", 'C'),
+    ("GLN", 'Q'),
+    ("GLU", 'E'),
+    ("GLY", 'G'),
+    ("HIS", 'H'),
+    ("ILE", 'I'),
+    ("LEU", 'L'),
+    ("LYS", 'K'),
+    ("MET", 'M'),
+    ("PHE", 'F'),
+    ("PRO", 'P'),
+    ("SER", 'S'),
+    ("THR", 'T'),
+    ("TRP", 'W'),
+    ("TYR", 'Y'),
+    ("VAL", 'V'),
+    ("SEC", 'U'),
+    ("PYL", 'O'),
+];
+
+static MODIFIED_AMINO_ACIDS: &[(&str, char)] = &[
+    ("MSE", 'M'),
+    ("SEP", 'S'),
+    ("TPO", 'T'),
+    ("PTR", 'Y'),
+    ("HYP", 'P'),
+    ("MLY", 'K'),
+    ("M3L", 'K'),
+    ("KCX", 'K'),
+    ("LLP", 'K'),
+    ("CSO", 'C'),
+    ("CSD", 'C'),
+    ("CME", 'C'),
+    ("HIC", 'H'),
+    ("CGU", 'E'),
+    ("PCA", 'Q'),
+];
+
+static RIBONUCLEOTIDES: &[(&str, char)] = &[("A", 'A'), ("C", 'C'), ("G", 'G'), ("U", 'U')];
+
+static DEOXYRIBONUCLEOTIDES: &[(&str, char)] = &[
+    ("DA", 'A'),
+    ("DC", 'C'),
+    ("DG", 'G'),
+    ("DT", 'T'),
+    ("DU", 'U'),
+];
+
+static WATERS: &[&str] = &["HOH", "WAT", "DOD"];
+
+/// Kyte-Doolittle hydropathy scale (keyed by one-letter code).
+static KYTE_DOOLITTLE: &[(char, f64)] = &[
+    ('A', 1.8),
+    ('R', -4.5),
+    ('N', -3.5),
+    ('D', -3.5),
+    ('C', 2.5),
+    ('Q', -3.5),
+    ('E', -3.5),
+    ('G', -0.4),
+    ('H', -3.2),
+    ('I', 4.5),
+    ('L', 3.8),
+    ('K', -3.9),
+    ('M', 1.9),
+    ('F', 2.8),
+    ('P', -1.6),
+    ('S', -0.8),
+    ('T', -0.7),
+    ('W', -0.9),
+    ('Y', -1.3),
+    ('V', 4.2),
+];
+
+#[inline(always)]
+fn lookup(table: &[(&str, char)], name: &str) -> Option<char> {
+    table
+        .iter()
+        .find(|(residue_name, _)| *residue_name == name)
+        .map(|(_, code)| *code)
+}
+
+/// One-letter code of a standard or modified (code of the parent) amino acid.
+pub fn amino_acid_code(name: &str) -> Option<char> {
+    lookup(AMINO_ACIDS, name).or_else(|| lookup(MODIFIED_AMINO_ACIDS, name))
+}
+
+/// One-letter code (base) of a ribonucleotide or a deoxyribonucleotide.
+pub fn nucleotide_code(name: &str) -> Option<char> {
+    lookup(RIBONUCLEOTIDES, name).or_else(|| lookup(DEOXYRIBONUCLEOTIDES, name))
+}
+
+pub fn is_ribonucleotide(name: &str) -> bool {
+    lookup(RIBONUCLEOTIDES, name).is_some()
+}
+
+pub fn is_deoxyribonucleotide(name: &str) -> bool {
+    lookup(DEOXYRIBONUCLEOTIDES, name).is_some()
+}
+
+/// One-letter code of a standard or modified amino acid, or of a nucleotide.
+pub fn one_letter_code(name: &str) -> Option<char> {
+    amino_acid_code(name).or_else(|| nucleotide_code(name))
+}
+
+/// True if the table (residue name with atom names) lists the atom for the residue.
+pub fn lists_atom(table: &[(&str, &[&str])], residue_name: &str, atom_name: &str) -> bool {
+    table
+        .iter()
+        .any(|(name, atoms)| *name == residue_name && atoms.contains(&atom_name))
+}
+
+/// True for the residue names of water (including heavy water), in any case.
+pub fn is_water(name: &str) -> bool {
+    WATERS.iter().any(|water| water.eq_ignore_ascii_case(name))
+}
+
+/// Kind of the residue: "amino_acid" (standard or modified), "nucleotide" (DNA or RNA),
+/// "water" or "ligand" (any other residue, e.g. ions and small molecules).
+pub fn kind(name: &str) -> &'static str {
+    if amino_acid_code(name).is_some() {
+        "amino_acid"
+    } else if nucleotide_code(name).is_some() {
+        "nucleotide"
+    } else if is_water(name) {
+        "water"
+    } else {
+        "ligand"
+    }
+}
+
+/// Kyte-Doolittle hydropathy of an amino acid (modified residues take the parent's value).
+pub fn hydrophobicity(name: &str) -> Option<f64> {
+    let code = amino_acid_code(name)?;
+
+    KYTE_DOOLITTLE
+        .iter()
+        .find(|(amino_acid, _)| *amino_acid == code)
+        .map(|(_, value)| *value)
+}
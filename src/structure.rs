@@ -1,12 +1,73 @@
-use crate::{chain::Chain, unit_cell::UnitCell};
+use crate::{
+    atom::{self, Atom, AtomType},
+    chain::{Chain, ChainState, ResidueKey},
+    elements::{self, DEFAULT_VDW_RADIUS},
+    geometry::{self, Vector},
+    grid::NeighborGrid,
+    hbonds::{self, PolarAtom},
+    iterators::{ChainIterator, ElementIterator, StructureAtomIterator, StructureResidueIterator},
+    model::Model,
+    neighbor_search::NeighborSearch,
+    pattern,
+    residue::{Residue, ATOMS_GENERATION},
+    residue_names,
+    unit_cell::{UnitCell, UnitCellState},
+    writer,
+};
 
 use pyo3::{
-    exceptions::PyIndexError, pyclass, pymethods, types::PyList, Py, PyRefMut, PyResult,
-    PyTraverseError, PyVisit, Python,
+    exceptions::{PyIndexError, PyKeyError, PyValueError},
+    ffi, pyclass, pymethods,
+    types::{PyDict, PyList, PyString, PyType},
+    AsPyPointer, FromPyObject, Py, PyCell, PyObject, PyRef, PyResult, PyTraverseError, PyVisit,
+    Python,
 };
 
 use indexmap::IndexMap;
 
+use std::{
+    cell::Cell,
+    collections::{HashMap, HashSet, VecDeque},
+    sync::atomic::Ordering,
+};
+
+use numpy::{PyArray1, PyArray2};
+
+/// Names given to new chains, in order of preference.
+pub const CHAIN_NAMES: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+/// Maximum deviation of the product of a rotation matrix and its transpose from the
+/// identity (matrices are often given with 5 or 6 decimals).
+const ROTATION_TOLERANCE: f64 = 1e-4;
+
+/// Residue number, residue name and sorted atom names of a residue.
+type ResidueTopology = (i32, String, Vec<String>);
+
+/// Pickled state of a structure: the header (PDB ID, classification, date, title,
+/// experimental method and resolution), the unit cell, the chains, the number of the first
+/// model, the following models (number and chains), the bonds and the warnings.
+pub type StructureState = (
+    String,
+    String,
+    String,
+    String,
+    String,
+    Option<f64>,
+    UnitCellState,
+    Vec<ChainState>,
+    i32,
+    Vec<(i32, Vec<ChainState>)>,
+    Vec<(usize, usize)>,
+    Vec<String>,
+);
+
+/// Key of Structure.__getitem__: a position or a chain name.
+#[derive(FromPyObject)]
+pub enum ChainKey {
+    Index(usize),
+    Name(char),
+}
+
 /// Structure - a class that represents a PDB structure.
 #[pyclass(module = "nanoPDB")]
 pub struct Structure {
@@ -22,9 +83,43 @@ pub struct Structure {
     #[pyo3(get)]
     pub date: String,
 
+    /// [str] Title of the experiment or analysis (the text of all TITLE records, continuation
+    /// records joined with single spaces).
+    #[pyo3(get)]
+    pub title: String,
+
+    /// [str] Experimental technique used for the structure determination.
+    #[pyo3(get)]
+    pub experimental_method: String,
+
+    /// [float | None] Resolution (in Angstroms), None if not applicable.
+    #[pyo3(get)]
+    pub resolution: Option<f64>,
+
     pub unit_cell: Option<Py<UnitCell>>,
+
+    /// Chains of the first model, all structure methods work on them.
     pub chains: IndexMap<char, Option<Py<Chain>>>,
-    pub current_index: usize,
+
+    /// Number of the first model (1 for files without MODEL records).
+    pub first_model: i32,
+
+    /// Models following the first one (NMR ensembles and other multi-model files).
+    pub models: Vec<Option<Py<Model>>>,
+
+    /// [list[(int, int)]] Bonds from the CONECT records, as pairs of atom serial numbers
+    /// (smaller first, sorted).
+    #[pyo3(get)]
+    pub bonds: Vec<(usize, usize)>,
+
+    /// [list[str]] Records skipped by lenient parsing (with strict disabled), with the line
+    /// numbers and the reasons.
+    #[pyo3(get)]
+    pub warnings: Vec<String>,
+
+    /// Number of atoms of the chains with the ATOMS_GENERATION it was counted in (read by
+    /// __repr__, see cached_num_atoms).
+    pub atom_count: Cell<Option<(u64, usize)>>,
 }
 
 #[pymethods]
@@ -33,6 +128,22 @@ impl Structure {
     // Getters
     // ----------------------------------------------------------------------------------------
 
+    /// [list[Model]] Models of the structure (a single model for files without MODEL
+    /// records). The first model shares its chains with the structure.
+    #[getter]
+    pub fn models(&self, python: Python) -> PyResult<Vec<Py<Model>>> {
+        let mut models = vec![self.first_model(python)?];
+
+        models.extend(self.models.iter().map(|model| {
+            model
+                .as_ref()
+                .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                .clone_ref(python)
+        }));
+
+        Ok(models)
+    }
+
     /// [UnitCell] The unit cell of the structure.
     #[getter]
     pub fn unit_cell(&self, python: Python) -> Py<UnitCell> {
@@ -53,47 +164,67 @@ impl Structure {
         for chain in self.chains.values_mut() {
             *chain = None;
         }
+
+        for model in self.models.iter_mut() {
+            *model = None;
+        }
+    }
+
+    pub fn __copy__(&self, python: Python) -> PyResult<Structure> {
+        self.deep_copy(python)
+    }
+
+    pub fn __deepcopy__(&self, python: Python, _memo: &PyDict) -> PyResult<Structure> {
+        self.deep_copy(python)
     }
 
-    pub fn __getitem__(&self, python: Python, index: usize) -> PyResult<Py<Chain>> {
-        if index < self.chains.len() {
-            Ok(self.chains[index]
+    pub fn __getitem__(&self, python: Python, key: ChainKey) -> PyResult<Py<Chain>> {
+        match key {
+            ChainKey::Index(index) if index < self.chains.len() => Ok(self.chains[index]
                 .as_ref()
                 .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
                 .as_ref(python)
-                .into())
-        } else {
-            Err(PyIndexError::new_err("index out of range"))
+                .into()),
+            ChainKey::Index(_) => Err(PyIndexError::new_err("index out of range")),
+            ChainKey::Name(name) => self.chain(python, name),
         }
     }
 
-    pub fn __iter__(mut slf: PyRefMut<Self>) -> PyRefMut<Self> {
-        slf.current_index = 0;
-
-        slf
+    pub fn __iter__(slf: PyRef<Self>) -> ChainIterator {
+        ChainIterator::new(slf.into())
     }
 
+    /// Number of models (1 for files without MODEL records), indexing and iteration go over
+    /// the chains of the first model.
     pub fn __len__(&self) -> usize {
-        self.chains.len()
+        1 + self.models.len()
     }
 
-    pub fn __next__(&mut self, python: Python) -> Option<Py<Chain>> {
-        if self.current_index < self.chains.len() {
-            self.current_index += 1;
+    pub fn __reduce__(
+        slf: &PyCell<Self>,
+        python: Python,
+    ) -> PyResult<(PyObject, (StructureState,))> {
+        Ok((
+            slf.get_type().getattr("_from_state")?.into(),
+            (slf.borrow().state(python),),
+        ))
+    }
 
-            Some(
-                self.chains[self.current_index - 1]
-                    .as_ref()
-                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
-                    .as_ref(python)
-                    .into(),
-            )
-        } else {
-            None
-        }
+    pub fn __repr__(&self, python: Python) -> String {
+        let pdbid = match self.pdbid.is_empty() {
+            true => String::new(),
+            false => format!(" {}", self.pdbid),
+        };
+
+        format!(
+            "<Structure{}: {} chains, {} atoms>",
+            pdbid,
+            self.num_chains(),
+            self.cached_num_atoms(python)
+        )
     }
 
-    pub fn __repr__(&self) -> String {
+    pub fn __str__(&self) -> String {
         format!("{:#}", self)
     }
 
@@ -102,10 +233,12 @@ impl Structure {
             visit.call(unit_cell)?;
         }
 
-        for chain in self.chains.values() {
-            if let Some(chain) = chain {
-                visit.call(chain)?;
-            }
+        for chain in self.chains.values().flatten() {
+            visit.call(chain)?;
+        }
+
+        for model in self.models.iter().flatten() {
+            visit.call(model)?;
         }
 
         Ok(())
@@ -115,62 +248,150 @@ impl Structure {
     // Methods
     // ----------------------------------------------------------------------------------------
 
-    /// Returns a list of atoms that builds the structure.
+    /// Rebuilds the structure from its pickled state (see __reduce__).
+    #[classmethod]
+    #[pyo3(name = "_from_state", signature = (state, /))]
+    pub fn py_from_state(_class: &PyType, python: Python, state: StructureState) -> PyResult<Self> {
+        Structure::from_state(python, state)
+    }
+
+    /// Returns the relative asphericity of the structure.
+    ///
+    /// Computed from the eigenvalues l1 <= l2 <= l3 of the gyration tensor of the atom
+    /// positions as (l3 - (l1 + l2) / 2) / (l1 + l2 + l3), the asphericity normalized by the
+    /// squared radius of gyration. It is 0.0 for spherically symmetric structures and 1.0 for
+    /// rods. A ValueError is raised when the structure has no extent (no atoms or all atoms
+    /// in one place).
     ///
     ///
     /// Returns
     /// -------
-    /// list[Atom]
-    ///     The list of atoms that build the structure.
+    /// float
+    ///     The relative asphericity (dimensionless, range 0.0-1.0).
     ///
     ///
     /// Examples
     /// --------
-    /// Retrieving the list of atoms that builds the structure.
+    /// Describing the shape of the structure.
     ///
     /// >>> parser = nanoPDB.Parser()
     /// >>> structure = parser.fetch("1zhy")
     /// ...
-    /// >>> structure.get_atoms()
+    /// >>> structure.asphericity()
+    ///
+    /// 0.2306...
+    #[pyo3(signature = (/))]
+    pub fn asphericity(&self, python: Python) -> PyResult<f64> {
+        let [l1, l2, l3] = self.gyration_eigenvalues(python)?;
+
+        Ok((l3 - (l1 + l2) / 2.0) / (l1 + l2 + l3))
+    }
+
+    /// Returns a new structure with alternate conformations merged into single atoms.
+    ///
+    /// Alternate locations of an atom (atoms of a residue sharing the same name, all with an
+    /// alternate location indicator) are replaced with a single atom without the indicator,
+    /// placed at the occupancy-weighted average position (plain average when all occupancies
+    /// are zero) and with the B-factor averaged the same way. The occupancy of the merged atom
+    /// is the sum of the conformer occupancies capped at 1.0, other fields are taken from the
+    /// first conformer. The original structure is not modified.
     ///
-    /// [Atom {
-    ///     label: "ATOM",
-    ///     number: 1,
-    ///     name: "N",
-    ///     element: "N",
-    ///     position: (
-    ///         42.854,
-    ///         36.56,
-    ///         10.394,
-    ///     ),
-    ///     occupancy: 1.0,
-    /// }, Atom {
-    ///     label: "ATOM",
-    ///     number: 2,
-    ///     name: "CA",
-    ///     element: "C",
-    ///     position: (
-    ///         42.25,
-    ///         35.232,
-    ///         10.096,
-    ///     ),
-    ///     occupancy: 1.0,
-    /// }, Atom {
-    ///     label: "ATOM",
-    ///     number: 3,
-    ///     name: "C",
-    ///     element: "C",
-    ///     position: (
-    ///         41.642,
-    ///         34.623,
-    ///         11.355,
-    ///     ),
-    ///     occupancy: 1.0,
-    /// }
+    ///
+    /// Returns
+    /// -------
+    /// Structure
+    ///     The structure with averaged alternate conformations.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Averaging alternate conformations.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
     /// ...
+    /// >>> structure.num_atoms(), structure.average_altlocs().num_atoms()
+    ///
+    /// (3806, 3806)
     #[pyo3(signature = (/))]
-    pub fn get_atoms(&self, python: Python) -> PyResult<Py<PyList>> {
-        let atoms = PyList::empty(python);
+    pub fn average_altlocs(&self, python: Python) -> PyResult<Structure> {
+        self.map_residues(python, |_, residue| {
+            let mut groups: Vec<Vec<Atom>> = Vec::new();
+
+            for atom in residue.atoms.iter().map(|atom| {
+                atom.as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python)
+            }) {
+                match groups.iter_mut().find(|group| {
+                    group[0].alt_loc.is_some()
+                        && atom.alt_loc.is_some()
+                        && group[0].name == atom.name
+                }) {
+                    Some(group) => group.push(atom.clone()),
+                    None => groups.push(vec![atom.clone()]),
+                }
+            }
+
+            let atoms = groups
+                .into_iter()
+                .map(|group| {
+                    let total: f64 = group.iter().map(|atom| atom.occupancy).sum();
+                    let weight = |atom: &Atom| match total > 0.0 {
+                        true => atom.occupancy / total,
+                        false => 1.0 / group.len() as f64,
+                    };
+
+                    let mut merged = group[0].clone();
+                    merged.position = group.iter().fold((0.0, 0.0, 0.0), |sum, atom| {
+                        geometry::add(sum, geometry::scale(atom.position, weight(atom)))
+                    });
+                    merged.b_factor = group.iter().map(|atom| atom.b_factor * weight(atom)).sum();
+                    merged.occupancy = total.min(1.0);
+                    merged.alt_loc = None;
+
+                    merged
+                })
+                .collect();
+
+            Ok(Some(residue.copy_with_atoms(python, atoms)?))
+        })
+    }
+
+    /// Returns the atoms of the structure as plain tuples.
+    ///
+    /// The tuples are built in a single walk over the structure, without creating Atom
+    /// objects on the Python side, which makes this a lightweight way of getting the whole
+    /// structure as plain Python data.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// list[(int, str, str, int, str, float, float, float)]
+    ///     The list of (atom number, atom name, residue name, residue number, chain name,
+    ///     x, y, z) tuples.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Retrieving the atoms as tuples.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.atom_tuples()
+    ///
+    /// [(1, 'N', 'MET', -1, 'A', 42.854, 36.56, 10.394),
+    ///  (2, 'CA', 'MET', -1, 'A', 42.25, 35.232, 10.096),
+    ///  (3, 'C', 'MET', -1, 'A', 41.642, 34.623, 11.355),
+    /// ...
+    #[allow(clippy::type_complexity)]
+    #[pyo3(signature = (/))]
+    pub fn atom_tuples(
+        &self,
+        python: Python,
+    ) -> Vec<(i32, Py<PyString>, Py<PyString>, i32, char, f64, f64, f64)> {
+        let mut tuples = Vec::new();
 
         for chain in self.chains.values().map(|chain| {
             chain
@@ -187,81 +408,275 @@ impl Structure {
                 for atom in residue.atoms.iter().map(|atom| {
                     atom.as_ref()
                         .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                        .borrow(python)
                 }) {
-                    atoms.append(atom)?;
+                    let (x, y, z) = atom.position;
+
+                    tuples.push((
+                        atom.number,
+                        atom.name(python),
+                        residue.name(python),
+                        residue.number,
+                        chain.name,
+                        x,
+                        y,
+                        z,
+                    ));
                 }
             }
         }
 
-        Ok(atoms.into())
+        tuples
     }
 
-    /// Returns the list of chains that builds the chain.
+    /// Returns a lazy iterator over all atoms of the structure (in file order).
+    ///
+    /// The atoms of all chains and residues are yielded one at a time, replacing the nested
+    /// loops over chains, residues and atoms. No list is built (unlike get_atoms).
     ///
     ///
     /// Returns
     /// -------
-    /// list[Chain]
-    ///     The list of chains that builds the structure.
+    /// StructureAtomIterator
+    ///     The iterator over the atoms of the structure.
     ///
     ///
     /// Examples
     /// --------
-    /// Retrieving the list of chains that builds the structure.
+    /// Extracting the coordinates of all atoms.
     ///
     /// >>> parser = nanoPDB.Parser()
     /// >>> structure = parser.fetch("1zhy")
     /// ...
-    /// >>> structure.get_chains()
+    /// >>> positions = [atom.position for atom in structure.atoms()]
+    /// >>> positions[:2]
     ///
-    /// [Chain {
-    ///     name: 'A',
-    /// }]
+    /// [(42.854, 36.56, 10.394), (42.25, 35.232, 10.096)]
     #[pyo3(signature = (/))]
-    pub fn get_chains(&self, python: Python) -> Py<PyList> {
-        PyList::new(
-            python,
-            self.chains.values().map(|chain| {
+    pub fn atoms(&self, python: Python) -> StructureAtomIterator {
+        StructureAtomIterator::new(self.chain_handles(python))
+    }
+
+    /// Returns the atoms within the radius of a point.
+    ///
+    /// A neighbor grid is built for the single query, use neighbor_search to build it once
+    /// and query it repeatedly. The atoms are returned in file order, including the atoms
+    /// exactly at the radius.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// center : (float, float, float)
+    ///     The point to search around.
+    /// radius : float
+    ///     The search radius (in Angstroms).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// list[Atom]
+    ///     The list of atoms within the radius.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Finding the atoms around the center of the structure.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> atoms = structure.atoms_within(structure.center_of_geometry(), 6.0)
+    /// >>> len(atoms)
+    ///
+    /// 44
+    #[pyo3(signature = (center, radius, /))]
+    pub fn atoms_within(
+        &self,
+        python: Python,
+        center: Vector,
+        radius: f64,
+    ) -> PyResult<Vec<Py<Atom>>> {
+        NeighborSearch::new(python, self.collect_atoms(python), radius.max(1.0))
+            .atoms_within(python, center, radius)
+    }
+
+    /// Returns the likely Watson-Crick base pairs of the nucleic acid chains.
+    ///
+    /// This is a distance heuristic: a purine (A, G) and a pyrimidine (C, T, U) are paired when
+    /// the bases are complementary (A with T or U, G with C) and the purine N1 atom lies within
+    /// the cutoff of the pyrimidine N3 atom. Every base is in at most one pair: candidates are
+    /// accepted in order of increasing distance, skipping bases that are already paired. Each
+    /// pair is reported once, with the residue appearing first in the file first.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// cutoff : float
+    ///     The maximum N1-N3 distance (in Angstroms, default 3.5).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// list[(int, int)]
+    ///     The list of paired residue numbers.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Finding the base pairs of a DNA double helix.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1bna")
+    /// ...
+    /// >>> structure.base_pairs()
+    ///
+    /// [(1, 24), (2, 23), (3, 22), (4, 21), (5, 20), (6, 19), ...]
+    #[pyo3(signature = (cutoff = 3.5))]
+    pub fn base_pairs(&self, python: Python, cutoff: f64) -> PyResult<Vec<(i32, i32)>> {
+        if cutoff <= 0.0 {
+            return Err(PyValueError::new_err("cutoff must be positive"));
+        }
+
+        // (order in file, residue number, base, position of N1 or N3)
+        let mut purines: Vec<(usize, i32, char, Vector)> = Vec::new();
+        let mut pyrimidines: Vec<(usize, i32, char, Vector)> = Vec::new();
+
+        for (order, residue) in self
+            .chains
+            .values()
+            .flat_map(|chain| {
                 chain
                     .as_ref()
                     .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
-            }),
-        )
-        .into()
+                    .borrow(python)
+                    .residues
+                    .values()
+                    .map(|residue| {
+                        residue
+                            .as_ref()
+                            .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                            .clone_ref(python)
+                    })
+                    .collect::<Vec<Py<Residue>>>()
+            })
+            .enumerate()
+        {
+            let residue = residue.borrow(python);
+
+            let (bases, base, atom_name) = match residue_names::nucleotide_code(&residue.name) {
+                Some(base @ ('A' | 'G')) => (&mut purines, base, "N1"),
+                Some(base @ ('C' | 'T' | 'U')) => (&mut pyrimidines, base, "N3"),
+                _ => continue,
+            };
+
+            if let Some(atom) = residue.find_atom(python, atom_name) {
+                bases.push((order, residue.number, base, atom.borrow(python).position));
+            }
+        }
+
+        let grid = NeighborGrid::new(
+            pyrimidines
+                .iter()
+                .map(|(_, _, _, position)| *position)
+                .collect(),
+            cutoff,
+        );
+
+        // (N1-N3 distance, purine index, pyrimidine index) of every complementary candidate:
+        let mut candidates: Vec<(f64, usize, usize)> = Vec::new();
+
+        for (purine, (_, _, base, position)) in purines.iter().enumerate() {
+            for pyrimidine in grid.within(*position, cutoff) {
+                let (_, _, other, other_position) = &pyrimidines[pyrimidine];
+
+                if matches!((base, other), ('A', 'T') | ('A', 'U') | ('G', 'C')) {
+                    candidates.push((
+                        geometry::distance(*position, *other_position),
+                        purine,
+                        pyrimidine,
+                    ));
+                }
+            }
+        }
+
+        // Closest candidates first, every base ends up in at most one pair:
+        candidates.sort_unstable_by(|first, second| first.0.total_cmp(&second.0));
+
+        let mut purine_used = vec![false; purines.len()];
+        let mut pyrimidine_used = vec![false; pyrimidines.len()];
+        let mut pairs: Vec<(usize, i32, i32)> = Vec::new();
+
+        for (_, purine, pyrimidine) in candidates {
+            if purine_used[purine] || pyrimidine_used[pyrimidine] {
+                continue;
+            }
+
+            purine_used[purine] = true;
+            pyrimidine_used[pyrimidine] = true;
+
+            let (order, number, _, _) = purines[purine];
+            let (other_order, other_number, _, _) = pyrimidines[pyrimidine];
+
+            pairs.push(match order < other_order {
+                true => (order, number, other_number),
+                false => (other_order, other_number, number),
+            });
+        }
+
+        pairs.sort_unstable();
+
+        Ok(pairs
+            .into_iter()
+            .map(|(_, first, second)| (first, second))
+            .collect())
     }
 
-    /// Returns the list of residues that builds the structure.
+    /// Returns the binding site of a ligand as a new structure.
+    ///
+    /// The ligand is every HETATM residue with the given name. The binding site consists of
+    /// the ligand and all residues (including waters and other ligands) with at least one
+    /// atom within the radius of any ligand atom. A ValueError is raised when no such ligand
+    /// is found. The original structure is not modified.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// ligand_resname : str
+    ///     The residue name of the ligand.
+    /// radius : float
+    ///     The distance from the ligand atoms in Angstroms.
     ///
     ///
     /// Returns
     /// -------
-    /// list[Residue]
-    ///     The list of residues that builds the structure.
+    /// Structure
+    ///     The structure with the ligand and the surrounding residues.
     ///
     ///
     /// Examples
     /// --------
-    /// Retrieving the list of residues that builds the structure.
+    /// Extracting the cholesterol binding pocket.
     ///
     /// >>> parser = nanoPDB.Parser()
     /// >>> structure = parser.fetch("1zhy")
     /// ...
-    /// >>> structure.get_residues()
+    /// >>> pocket = structure.binding_site("CLR", 5.0)
+    /// >>> pocket.num_atoms()
     ///
-    /// [Residue {
-    ///     number: -1,
-    ///     name: "MET",
-    /// }, Residue {
-    ///     number: 0,
-    ///     name: "ASP",
-    /// }, Residue {
-    ///     number: 1,
-    ///     name: "PRO",
-    /// }
-    /// ...
-    #[pyo3(signature = (/))]
-    pub fn get_residues(&self, python: Python) -> PyResult<Py<PyList>> {
-        let residues = PyList::empty(python);
+    /// 255
+    #[pyo3(signature = (ligand_resname, radius, /))]
+    pub fn binding_site(
+        &self,
+        python: Python,
+        ligand_resname: &str,
+        radius: f64,
+    ) -> PyResult<Structure> {
+        if radius < 0.0 {
+            return Err(PyValueError::new_err("radius must not be negative"));
+        }
+
+        let mut ligand: Vec<Vector> = Vec::new();
+        let mut surroundings: Vec<((char, ResidueKey), Vector)> = Vec::new();
 
         for chain in self.chains.values().map(|chain| {
             chain
@@ -273,32 +688,3051 @@ impl Structure {
                 residue
                     .as_ref()
                     .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python)
             }) {
-                residues.append(residue)?;
+                for atom in residue.atoms.iter().map(|atom| {
+                    atom.as_ref()
+                        .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                        .borrow(python)
+                }) {
+                    if residue.name == ligand_resname && matches!(atom.label, AtomType::HETATM) {
+                        ligand.push(atom.position);
+                    } else {
+                        surroundings.push(((chain.name, residue.key()), atom.position));
+                    }
+                }
             }
         }
 
-        Ok(residues.into())
-    }
-}
+        if ligand.is_empty() {
+            return Err(PyValueError::new_err(format!(
+                "ligand not found: {}",
+                ligand_resname
+            )));
+        }
 
-impl Structure {
-    pub fn new(python: Python) -> PyResult<Self> {
-        Ok(Structure {
-            pdbid: String::default(),
-            classification: String::default(),
-            date: String::default(),
-            unit_cell: Some(Py::new(python, UnitCell::default())?),
-            chains: IndexMap::default(),
-            current_index: 0,
+        let grid = NeighborGrid::new(ligand, radius.max(1.0));
+        let site: HashSet<(char, ResidueKey)> = surroundings
+            .into_iter()
+            .filter(|(_, position)| !grid.within(*position, radius).is_empty())
+            .map(|(residue, _)| residue)
+            .collect();
+
+        self.map_residues(python, |chain, residue| {
+            let in_site = site.contains(&(chain.name, residue.key()));
+            let is_ligand = residue.name == ligand_resname
+                && residue.atoms.iter().any(|atom| {
+                    matches!(
+                        atom.as_ref()
+                            .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                            .borrow(python)
+                            .label,
+                        AtomType::HETATM
+                    )
+                });
+
+            match in_site || is_ligand {
+                true => Ok(Some(residue.deep_copy(python)?)),
+                false => Ok(None),
+            }
         })
     }
 
-    #[inline(always)]
-    pub fn set_header(&mut self, pdbid: &str, classification: &str, date: &str) {
-        self.pdbid = pdbid.to_string();
-        self.classification = classification.to_string();
-        self.date = date.to_string();
+    /// Returns the axis-aligned bounding box of the structure (computed in a single pass).
+    ///
+    /// Useful for setting up grids, docking boxes and visualization viewports.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// ((float, float, float), (float, float, float))
+    ///     The minimum and maximum corners, a ValueError is raised for a structure without
+    ///     atoms.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Computing the bounding box of the structure.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.bounding_box()
+    ///
+    /// ((30.841, 6.707, 3.466), (91.927, 64.985, 75.795))
+    #[pyo3(signature = (/))]
+    pub fn bounding_box(&self, python: Python) -> PyResult<(Vector, Vector)> {
+        atom::bounding_box(python, &self.collect_atoms(python))
+    }
+
+    /// Returns the serial numbers of the atoms bonded to the atom (from the CONECT records).
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// serial : int
+    ///     The serial number of the atom.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// list[int]
+    ///     The sorted serial numbers of the bonded atoms (empty when the atom has no bonds).
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Finding the neighbors of a cholesterol atom.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.bonded_to(3542)
+    ///
+    /// [3541, 3543, 3544]
+    #[pyo3(signature = (serial, /))]
+    pub fn bonded_to(&self, serial: usize) -> Vec<usize> {
+        let mut bonded: Vec<usize> = self
+            .bonds
+            .iter()
+            .filter_map(|&(first, second)| {
+                if first == serial {
+                    Some(second)
+                } else if second == serial {
+                    Some(first)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        bonded.sort_unstable();
+        bonded
+    }
+
+    /// Returns the center of geometry (mean of the atom positions) of the structure.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// (float, float, float)
+    ///     The center of geometry, a ValueError is raised for a structure without atoms.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Computing the center of geometry of the structure.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.center_of_geometry()
+    ///
+    /// (57.025..., 36.565..., 39.136...)
+    #[pyo3(signature = (/))]
+    pub fn center_of_geometry(&self, python: Python) -> PyResult<Vector> {
+        atom::center_of_geometry(python, &self.collect_atoms(python))
+    }
+
+    /// Returns the center of mass of the structure.
+    ///
+    /// Atoms are weighted by the atomic masses of their elements. Atoms of unknown elements
+    /// have no weight and are reported with a UserWarning.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// (float, float, float)
+    ///     The center of mass, a ValueError is raised when no atom has a known mass.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Computing the center of mass of the structure.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.center_of_mass()
+    ///
+    /// (57.096..., 36.546..., 39.111...)
+    #[pyo3(signature = (/))]
+    pub fn center_of_mass(&self, python: Python) -> PyResult<Vector> {
+        atom::center_of_mass(python, &self.collect_atoms(python))
+    }
+
+    /// Translates the whole structure so the center of the selected atoms is at the origin.
+    ///
+    /// The center is the center of geometry (plain mean of the positions) of the given atoms,
+    /// all atoms of the structure are moved by the same vector.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// atoms : list[Atom]
+    ///     The selected atoms (at least one).
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Centering the structure on the gamma atoms.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.center_on(structure.select_atoms_glob("*G*"))
+    #[pyo3(signature = (atoms, /))]
+    pub fn center_on(&mut self, python: Python, atoms: Vec<Py<Atom>>) -> PyResult<()> {
+        let points: Vec<(Vector, f64)> = atoms
+            .iter()
+            .map(|atom| (atom.borrow(python).position, 1.0))
+            .collect();
+
+        let Some(center) = geometry::weighted_center(&points) else {
+            return Err(PyValueError::new_err("selection is empty"));
+        };
+
+        self.map_positions(python, |position| geometry::sub(position, center));
+
+        Ok(())
+    }
+
+    /// Returns the chain with the given name (chain ID), same as structure[name].
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// name : str
+    ///     The chain name (a single character).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// Chain
+    ///     The chain with the name, a KeyError is raised if there is no such chain.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Retrieving the chain A of the structure.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.chain("A")
+    ///
+    /// <Chain A: 701 residues>
+    #[pyo3(signature = (name, /))]
+    pub fn chain(&self, python: Python, name: char) -> PyResult<Py<Chain>> {
+        match self.chains.get(&name) {
+            Some(chain) => Ok(chain
+                .as_ref()
+                .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                .clone_ref(python)),
+            None => Err(PyKeyError::new_err(format!("no chain: {}", name))),
+        }
+    }
+
+    /// Returns the distances between the centers of mass of every pair of chains.
+    ///
+    /// Atomic masses are looked up by element, atoms of unknown elements have no weight.
+    /// Chains without any atom of known mass are skipped.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// list[((str, str), float)]
+    ///     The list of ((chain name, chain name), distance) tuples.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Measuring the distances between chains.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("4hhb")
+    /// ...
+    /// >>> structure.chain_com_distances()
+    ///
+    /// [(('A', 'B'), ...), (('A', 'C'), ...), (('A', 'D'), ...), (('B', 'C'), ...), ...]
+    #[pyo3(signature = (/))]
+    pub fn chain_com_distances(&self, python: Python) -> Vec<((char, char), f64)> {
+        let centers: Vec<(char, Vector)> = self
+            .chains
+            .values()
+            .filter_map(|chain| {
+                let chain = chain
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python);
+
+                Some((
+                    chain.name,
+                    geometry::weighted_center(&chain.mass_points(python))?,
+                ))
+            })
+            .collect();
+
+        let mut distances = Vec::new();
+
+        for (index, (first_name, first_center)) in centers.iter().enumerate() {
+            for (second_name, second_center) in centers.iter().skip(index + 1) {
+                distances.push((
+                    (*first_name, *second_name),
+                    geometry::distance(*first_center, *second_center),
+                ));
+            }
+        }
+
+        distances
+    }
+
+    /// Returns the numbers of residues preceded by a cis peptide bond.
+    ///
+    /// A peptide bond is cis when its omega torsion (CA-C-N-CA) lies within the tolerance
+    /// around 0 degrees (trans bonds have omega close to 180 degrees). Bonds across chain
+    /// breaks and residues missing backbone atoms are skipped.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// tolerance : float
+    ///     Maximum deviation of omega from 0 degrees (default 30.0).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// list[int]
+    ///     The list of residue numbers following a cis peptide bond.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Finding cis peptide bonds (usually preceding a proline).
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.cis_peptides()
+    ///
+    /// [145]
+    #[pyo3(signature = (tolerance = 30.0))]
+    pub fn cis_peptides(&self, python: Python, tolerance: f64) -> Vec<i32> {
+        self.chains
+            .values()
+            .flat_map(|chain| {
+                chain
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python)
+                    .omega_torsions(python)
+            })
+            .filter(|(_, omega)| omega.abs() <= tolerance)
+            .map(|(number, _)| number)
+            .collect()
+    }
+
+    /// Returns the pairs of atoms that are in contact.
+    ///
+    /// Two atoms are in contact when they are at most the cutoff apart. The atoms are put
+    /// into a neighbor grid with cells as wide as the cutoff, so every atom is only compared
+    /// with the atoms of its own and the 26 adjacent cells, and the search scales linearly
+    /// with the number of atoms (instead of comparing every pair). Atoms are identified by
+    /// their index in the get_atoms() list, every pair is reported once (lower index first)
+    /// and the pairs are sorted. The GIL is released during the search.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// cutoff : float
+    ///     The maximum distance (in Angstroms, default 4.0).
+    /// exclude_same_residue : bool
+    ///     Whether atoms of the same residue are skipped (default True).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// list[(int, int)]
+    ///     The list of (atom index, atom index) tuples.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Counting the contacts between residues.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> len(structure.contacts(4.0))
+    ///
+    /// 13059
+    #[pyo3(signature = (cutoff = 4.0, exclude_same_residue = true))]
+    pub fn contacts(
+        &self,
+        python: Python,
+        cutoff: f64,
+        exclude_same_residue: bool,
+    ) -> PyResult<Vec<(usize, usize)>> {
+        if cutoff <= 0.0 {
+            return Err(PyValueError::new_err("cutoff must be positive"));
+        }
+
+        // (position, index of the residue in the structure) of every atom
+        let mut atoms: Vec<(Vector, usize)> = Vec::new();
+
+        for (residue_index, residue) in self
+            .chains
+            .values()
+            .flat_map(|chain| {
+                chain
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python)
+                    .residues
+                    .values()
+                    .map(|residue| {
+                        residue
+                            .as_ref()
+                            .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                            .clone_ref(python)
+                    })
+                    .collect::<Vec<Py<Residue>>>()
+            })
+            .enumerate()
+        {
+            for atom in residue.borrow(python).collect_atoms(python) {
+                atoms.push((atom.borrow(python).position, residue_index));
+            }
+        }
+
+        Ok(python.allow_threads(|| {
+            let grid = NeighborGrid::new(
+                atoms.iter().map(|(position, _)| *position).collect(),
+                cutoff,
+            );
+
+            grid.pairs(cutoff)
+                .into_iter()
+                .filter(|(first, second)| {
+                    !exclude_same_residue || atoms[*first].1 != atoms[*second].1
+                })
+                .collect()
+        }))
+    }
+
+    /// Returns the convex hull of the atom positions.
+    ///
+    /// The hull is computed with the incremental algorithm. For degenerate inputs (fewer
+    /// than four atoms, or all atoms collinear or coplanar) all distinct atom positions are
+    /// returned and the volume is 0.0.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// (list[(float, float, float)], float)
+    ///     The positions of the hull vertices and the volume enclosed by the hull
+    ///     (in cubic Angstroms).
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Computing the convex hull of the structure.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> vertices, volume = structure.convex_hull()
+    /// >>> volume
+    ///
+    /// 105984.91...
+    #[pyo3(signature = (/))]
+    pub fn convex_hull(&self, python: Python) -> (Vec<Vector>, f64) {
+        geometry::convex_hull(&self.positions(python))
+    }
+
+    /// Returns the coordinates of all atoms as a NumPy array.
+    ///
+    /// The array is built in one go, which is much faster than reading the atom positions
+    /// one by one. Rows follow the file order, so they align with the atoms yielded by
+    /// atoms().
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// numpy.ndarray
+    ///     The (N, 3) array of atom coordinates (float64).
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Computing the centroid of the structure.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> coordinates = structure.coordinates()
+    /// >>> coordinates.shape
+    ///
+    /// (3806, 3)
+    #[pyo3(signature = (/))]
+    pub fn coordinates(&self, python: Python) -> PyResult<Py<PyArray2<f64>>> {
+        Structure::coordinates_array(python, &self.positions(python))
+    }
+
+    /// Returns the coordinates of the selected atoms as a NumPy array.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// atoms : list[Atom]
+    ///     The selected atoms (e.g. from select_atoms_glob).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// numpy.ndarray
+    ///     The (N, 3) array of atom coordinates (float64), in the order of the selection.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Retrieving the coordinates of the gamma atoms.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.coordinates_of(structure.select_atoms_glob("*G*"))[0]
+    ///
+    /// array([44.444, 33.949, 10.488])
+    #[pyo3(signature = (atoms, /))]
+    pub fn coordinates_of(
+        &self,
+        python: Python,
+        atoms: Vec<Py<Atom>>,
+    ) -> PyResult<Py<PyArray2<f64>>> {
+        let positions: Vec<Vector> = atoms
+            .iter()
+            .map(|atom| atom.borrow(python).position)
+            .collect();
+
+        Structure::coordinates_array(python, &positions)
+    }
+
+    /// Returns an independent copy of the structure.
+    ///
+    /// All models, chains, residues and atoms are copied, so transforming the copy (e.g. with
+    /// translate or rotate) leaves the original untouched. copy.copy and copy.deepcopy make
+    /// the same full copy.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// Structure
+    ///     The copy of the structure.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Transforming a copy of the structure.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> moved = structure.copy()
+    /// >>> moved.translate((10.0, 0.0, 0.0))
+    /// >>> structure[0][0][0].position, moved[0][0][0].position
+    ///
+    /// ((42.854, 36.56, 10.394), (52.854, 36.56, 10.394))
+    #[pyo3(signature = (/))]
+    pub fn copy(&self, python: Python) -> PyResult<Structure> {
+        self.deep_copy(python)
+    }
+
+    /// Returns a new structure with only the selected residue ranges.
+    ///
+    /// Every selection is a (chain name, first residue number, last residue number) triple, both
+    /// ends inclusive. A residue is kept when any selection covers it, so overlapping selections
+    /// merge without duplicating residues. Residues keep their order, the header and unit cell
+    /// are copied, and chains without selected residues are dropped. The atoms are copies, so
+    /// the new structure is independent of this one.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// selections : list[(str, int, int)]
+    ///     The list of (chain name, first residue number, last residue number) triples.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// Structure
+    ///     The structure with the selected residues.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Carving out two overlapping regions.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> region = structure.extract_region([("A", 10, 30), ("A", 25, 40)])
+    /// >>> len(region.get_residues())
+    ///
+    /// 31
+    #[pyo3(signature = (selections, /))]
+    pub fn extract_region(
+        &self,
+        python: Python,
+        selections: Vec<(char, i32, i32)>,
+    ) -> PyResult<Structure> {
+        if let Some((name, first, last)) = selections.iter().find(|(_, first, last)| first > last) {
+            return Err(PyValueError::new_err(format!(
+                "invalid selection: ({}, {}, {}), the first residue number is greater than the last",
+                name, first, last
+            )));
+        }
+
+        self.map_residues(python, |chain, residue| {
+            match selections.iter().any(|(name, first, last)| {
+                *name == chain.name && (*first..=*last).contains(&residue.number)
+            }) {
+                true => Ok(Some(residue.deep_copy(python)?)),
+                false => Ok(None),
+            }
+        })
+    }
+
+    /// Returns the molecular formula of the structure in the Hill system.
+    ///
+    /// Atoms are counted by element. Carbon comes first, then hydrogen, then all other elements
+    /// in alphabetical order (without carbon, all elements are in alphabetical order). Counts
+    /// of 1 are omitted. Atoms without an element symbol are not counted.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// include_hetatm : bool
+    ///     Whether HETATM records (ligands, ions and waters) are counted (default True).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// str
+    ///     The formula, e.g. "C1200 H1800 N310 O350 S12".
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Retrieving the composition of the protein alone.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.formula(False)
+    ///
+    /// C2251 N578 O678 S7
+    #[pyo3(signature = (include_hetatm = true))]
+    pub fn formula(&self, python: Python, include_hetatm: bool) -> String {
+        let mut counts: IndexMap<String, usize> = IndexMap::new();
+
+        for atom in self.collect_atoms(python) {
+            let atom = atom.borrow(python);
+
+            if atom.element.trim().is_empty()
+                || (!include_hetatm && matches!(atom.label, AtomType::HETATM))
+            {
+                continue;
+            }
+
+            *counts.entry(elements::symbol(&atom.element)).or_default() += 1;
+        }
+
+        let carbon = counts.contains_key("C");
+
+        counts.sort_by(|first, _, second, _| {
+            let rank = |symbol: &str| match (carbon, symbol) {
+                (true, "C") => 0,
+                (true, "H") => 1,
+                _ => 2,
+            };
+
+            (rank(first), first).cmp(&(rank(second), second))
+        });
+
+        counts
+            .iter()
+            .map(|(symbol, count)| match count {
+                1 => symbol.clone(),
+                _ => format!("{}{}", symbol, count),
+            })
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    /// Returns a list of atoms that builds the structure.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// list[Atom]
+    ///     The list of atoms that build the structure.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Retrieving the list of atoms that builds the structure.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.get_atoms()
+    ///
+    /// [<Atom N 1: (42.854, 36.56, 10.394)>,
+    ///  <Atom CA 2: (42.25, 35.232, 10.096)>,
+    ///  <Atom C 3: (41.642, 34.623, 11.355)>,
+    /// ...
+    #[pyo3(signature = (/))]
+    pub fn get_atoms(&self, python: Python) -> PyResult<Py<PyList>> {
+        let atoms = PyList::empty(python);
+
+        for chain in self.chains.values().map(|chain| {
+            chain
+                .as_ref()
+                .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                .borrow(python)
+        }) {
+            for residue in chain.residues.values().map(|residue| {
+                residue
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python)
+            }) {
+                for atom in residue.atoms.iter().map(|atom| {
+                    atom.as_ref()
+                        .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                }) {
+                    atoms.append(atom)?;
+                }
+            }
+        }
+
+        Ok(atoms.into())
+    }
+
+    /// Returns the list of chains that builds the chain.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// list[Chain]
+    ///     The list of chains that builds the structure.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Retrieving the list of chains that builds the structure.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.get_chains()
+    ///
+    /// [<Chain A: 701 residues>]
+    #[pyo3(signature = (/))]
+    pub fn get_chains(&self, python: Python) -> Py<PyList> {
+        PyList::new(
+            python,
+            self.chains.values().map(|chain| {
+                chain
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+            }),
+        )
+        .into()
+    }
+
+    /// Returns the list of residues that builds the structure.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// list[Residue]
+    ///     The list of residues that builds the structure.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Retrieving the list of residues that builds the structure.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.get_residues()
+    ///
+    /// [<Residue MET -1: 8 atoms>, <Residue ASP 0: 8 atoms>, <Residue PRO 1: 7 atoms>,
+    /// ...
+    #[pyo3(signature = (/))]
+    pub fn get_residues(&self, python: Python) -> PyResult<Py<PyList>> {
+        let residues = PyList::empty(python);
+
+        for chain in self.chains.values().map(|chain| {
+            chain
+                .as_ref()
+                .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                .borrow(python)
+        }) {
+            for residue in chain.residues.values().map(|residue| {
+                residue
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+            }) {
+                residues.append(residue)?;
+            }
+        }
+
+        Ok(residues.into())
+    }
+
+    /// Returns the hydrogen bond network as a list of donor-acceptor edges.
+    ///
+    /// Hydrogen bonds are assigned geometrically (hydrogens are not required) between a donor
+    /// and an acceptor closer than the cutoff, found with a neighbor grid. Amino acids donate
+    /// through the backbone N (except PRO) and the side chains of ARG, ASN, GLN, HIS, LYS, SER,
+    /// THR, TRP and TYR, and accept through the backbone O and OXT and the side chains of ASN,
+    /// ASP, GLN, GLU, HIS, SER, THR and TYR. Water oxygens and the nitrogens and oxygens of
+    /// other residues act as both. Atoms of the same residue are not paired and every pair is
+    /// reported once. Atoms are identified by their index in the get_atoms() list, so the edge
+    /// list can be passed directly to e.g. networkx.Graph.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// cutoff : float
+    ///     The maximum donor-acceptor distance (in Angstroms, default 3.5).
+    /// include_waters : bool
+    ///     Whether water molecules take part, which adds water-mediated paths to the
+    ///     network (default True).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// list[(int, int)]
+    ///     The list of (donor index, acceptor index) tuples.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Building the hydrogen bond network of the protein alone.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> edges = structure.hbond_graph(3.5, False)
+    /// >>> len(edges)
+    ///
+    /// 1144
+    #[pyo3(signature = (cutoff = 3.5, include_waters = true))]
+    pub fn hbond_graph(
+        &self,
+        python: Python,
+        cutoff: f64,
+        include_waters: bool,
+    ) -> PyResult<Vec<(usize, usize)>> {
+        if cutoff <= 0.0 {
+            return Err(PyValueError::new_err("cutoff must be positive"));
+        }
+
+        let mut polar_atoms = Vec::new();
+        let mut index = 0;
+
+        for chain in self.chains.values().map(|chain| {
+            chain
+                .as_ref()
+                .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                .borrow(python)
+        }) {
+            for (residue_index, residue) in chain
+                .residues
+                .values()
+                .map(|residue| {
+                    residue
+                        .as_ref()
+                        .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                        .borrow(python)
+                })
+                .enumerate()
+            {
+                let water = residue_names::is_water(&residue.name);
+
+                for atom in residue.atoms.iter().map(|atom| {
+                    atom.as_ref()
+                        .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                        .borrow(python)
+                }) {
+                    let (donor, acceptor) = hbonds::roles(
+                        &residue.name,
+                        &atom.name,
+                        &elements::normalize(&atom.element),
+                    );
+
+                    if (donor || acceptor) && (include_waters || !water) {
+                        polar_atoms.push(PolarAtom {
+                            index,
+                            residue: (chain.name, residue_index),
+                            position: atom.position,
+                            donor,
+                            acceptor,
+                        });
+                    }
+
+                    index += 1;
+                }
+            }
+        }
+
+        Ok(python.allow_threads(|| hbonds::hydrogen_bonds(&polar_atoms, cutoff)))
+    }
+
+    /// Returns a lazy iterator over the atoms of the given chemical element.
+    ///
+    /// The atoms are found one at a time while iterating, no list is built, which makes it
+    /// memory-friendly for sparse elements (e.g. metals) in large structures.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// element : str
+    ///     Chemical element symbol (case-insensitive).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// ElementIterator
+    ///     The iterator over atoms of the element.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Iterating over the sulfur atoms of the structure.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> for atom in structure.iter_element("S"):
+    /// ...     print(atom.number)
+    ///
+    /// 7
+    /// 559
+    /// 668
+    /// ...
+    #[pyo3(signature = (element, /))]
+    pub fn iter_element(&self, python: Python, element: &str) -> ElementIterator {
+        ElementIterator::new(element, self.chain_handles(python))
+    }
+
+    /// Returns the approximate radius of the largest internal cavity.
+    ///
+    /// The bounding box of the structure is covered with a regular grid and, for every grid
+    /// point inside the convex hull, the distance to the nearest atom surface (van der Waals
+    /// radius, 1.70 for unknown elements) and to the hull boundary is measured. The result
+    /// is the radius of the largest empty sphere fully inside the hull. The accuracy is
+    /// about the grid spacing, while the computation time grows with the cube of the inverse
+    /// spacing (halving the spacing makes it roughly 8 times slower). The GIL is released
+    /// during the computation.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// grid_spacing : float
+    ///     The distance between grid points (in Angstroms, default 1.0).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// float
+    ///     The cavity radius (in Angstroms), 0.0 if there is no cavity.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Estimating the largest cavity of the structure.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.largest_cavity_radius(1.0)
+    ///
+    /// 5.73...
+    #[pyo3(signature = (grid_spacing = 1.0))]
+    pub fn largest_cavity_radius(&self, python: Python, grid_spacing: f64) -> PyResult<f64> {
+        if grid_spacing <= 0.0 {
+            return Err(PyValueError::new_err("grid spacing must be positive"));
+        }
+
+        let atoms: Vec<(Vector, f64)> = self
+            .collect_atoms(python)
+            .iter()
+            .map(|atom| {
+                let atom = atom.borrow(python);
+
+                (
+                    atom.position,
+                    elements::vdw_radius(&atom.element).unwrap_or(DEFAULT_VDW_RADIUS),
+                )
+            })
+            .collect();
+
+        Ok(python.allow_threads(|| geometry::largest_empty_sphere(&atoms, grid_spacing)))
+    }
+
+    /// Checks whether both structures describe the same molecule, ignoring the coordinates.
+    ///
+    /// The structures are compared with a canonical traversal: chains in order (by chain
+    /// name), residues in order (by residue number and name), and the atom names of each
+    /// residue regardless of the order of the atoms within the residue. Two conformations
+    /// of the same molecule are isomorphic, structures differing by any chain, residue or
+    /// atom are not.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// other : Structure
+    ///     The structure to compare with.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// bool
+    ///     True if the structures have the same topology.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Comparing a structure with its copy with averaged alternate conformations.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.is_isomorphic(structure.average_altlocs())
+    ///
+    /// True
+    #[pyo3(signature = (other, /))]
+    pub fn is_isomorphic(&self, python: Python, other: PyRef<Structure>) -> bool {
+        self.topology(python) == other.topology(python)
+    }
+
+    /// Marks the solvent exposed atoms (see Atom.is_exposed).
+    ///
+    /// A cheap alternative to the solvent accessible surface: an atom is exposed when fewer
+    /// than threshold other atoms lie within the radius of it.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// radius : float
+    ///     The radius of the neighbor search in Angstroms (default 10.0).
+    /// threshold : int
+    ///     The number of neighbors from which an atom is buried (default 120).
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Selecting the surface atoms.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.mark_exposure()
+    /// >>> surface = [atom for atom in structure.get_atoms() if atom.is_exposed]
+    /// >>> len(surface)
+    ///
+    /// 968
+    #[pyo3(signature = (radius = 10.0, threshold = 120))]
+    pub fn mark_exposure(&self, python: Python, radius: f64, threshold: usize) -> PyResult<()> {
+        if radius <= 0.0 {
+            return Err(PyValueError::new_err(format!(
+                "radius must be positive, got: {}",
+                radius
+            )));
+        }
+
+        let atoms = self.collect_atoms(python);
+        let grid = NeighborGrid::new(self.positions(python), radius);
+
+        for (atom, position) in atoms.iter().zip(grid.points.iter()) {
+            // The atom itself is always within the radius.
+            let neighbors = grid.within(*position, radius).len() - 1;
+            atom.borrow_mut(python).exposed = Some(neighbors < threshold);
+        }
+
+        Ok(())
+    }
+
+    /// Renumbers the atoms (and optionally the residues) to match the reference structure.
+    ///
+    /// Both structures have to describe the same molecule (see is_isomorphic; when residues are
+    /// renumbered too, residue numbers are not compared), otherwise a ValueError is raised.
+    /// Atoms are matched by chain, residue and atom name, so the order of the atoms within the
+    /// residues does not matter. Coordinates are not changed.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// reference : Structure
+    ///     The structure with the numbering to copy.
+    /// residues : bool
+    ///     Whether the residue numbers are copied as well (default False).
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Copying the numbering of a deposited model to a refined one.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> deposited = parser.parse("deposited.pdb")
+    /// >>> refined = parser.parse("refined.pdb")
+    /// ...
+    /// >>> refined.match_numbering(deposited, True)
+    #[pyo3(signature = (reference, /, residues = false))]
+    pub fn match_numbering(
+        &mut self,
+        python: Python,
+        reference: PyRef<Structure>,
+        residues: bool,
+    ) -> PyResult<()> {
+        let comparable = |mut topology: Vec<(char, Vec<ResidueTopology>)>| {
+            if residues {
+                for (_, chain) in topology.iter_mut() {
+                    for residue in chain.iter_mut() {
+                        residue.0 = 0;
+                    }
+                }
+            }
+
+            topology
+        };
+
+        if comparable(self.topology(python)) != comparable(reference.topology(python)) {
+            return Err(PyValueError::new_err("topologies of the structures differ"));
+        }
+
+        for (chain, reference_chain) in self.chains.values().zip(reference.chains.values()) {
+            let mut chain = chain
+                .as_ref()
+                .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                .borrow_mut(python);
+            let reference_chain = reference_chain
+                .as_ref()
+                .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                .borrow(python);
+
+            let mut renumbered = IndexMap::new();
+
+            for ((_, residue), reference_residue) in chain
+                .residues
+                .drain(..)
+                .zip(reference_chain.residues.values())
+            {
+                let residue =
+                    residue.expect(concat!("memory error in: ", file!(), ", line: ", line!()));
+                let reference_residue = reference_residue
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python);
+
+                // Serials of the reference atoms by name (in order of appearance).
+                let mut serials: HashMap<String, VecDeque<i32>> = HashMap::new();
+
+                for atom in reference_residue.atoms.iter().flatten() {
+                    let atom = atom.borrow(python);
+                    serials
+                        .entry(atom.name.to_string())
+                        .or_default()
+                        .push_back(atom.number);
+                }
+
+                {
+                    let mut residue = residue.borrow_mut(python);
+
+                    for atom in residue.atoms.iter().flatten() {
+                        let mut atom = atom.borrow_mut(python);
+
+                        if let Some(serial) = serials
+                            .get_mut(atom.name.as_str())
+                            .and_then(|serials| serials.pop_front())
+                        {
+                            atom.number = serial;
+                        }
+                    }
+
+                    if residues {
+                        residue.number = reference_residue.number;
+                        residue.insertion_code = reference_residue.insertion_code;
+                    }
+                }
+
+                let key = residue.borrow(python).key();
+                renumbered.insert(key, Some(residue));
+            }
+
+            chain.residues = renumbered;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the largest distance between any two atoms (the molecular diameter).
+    ///
+    /// The two most distant atoms always lie on the convex hull, so the hull is computed
+    /// first (incremental algorithm) and only its vertices are compared pairwise, which is
+    /// much faster than comparing all pairs of atoms.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// float
+    ///     The diameter (in Angstroms).
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Measuring the size of the structure.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.max_diameter()
+    ///
+    /// 73.73...
+    #[pyo3(signature = (/))]
+    pub fn max_diameter(&self, python: Python) -> PyResult<f64> {
+        let positions = self.positions(python);
+
+        if positions.len() < 2 {
+            return Err(PyValueError::new_err(
+                "at least two atoms are required to compute the diameter",
+            ));
+        }
+
+        Ok(geometry::diameter(&positions))
+    }
+
+    /// Returns the metadata of the structure as a small dictionary, without coordinates.
+    ///
+    /// Intended for lightweight API responses and catalog indexing: only the header fields,
+    /// the chain names and the counts are included, no atom data is read.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// dict
+    ///     The dictionary with keys: "pdbid", "classification", "date", "title",
+    ///     "experimental_method", "resolution", "chain_ids", "num_chains", "num_residues" and
+    ///     "num_atoms".
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Indexing the structure.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.metadata_dict()
+    ///
+    /// {'pdbid': '1ZHY', 'classification': 'LIPID BINDING PROTEIN', 'date': '26-APR-05',
+    ///  'title': 'STRUCTURE OF YEAST OXYSTEROL BINDING PROTEIN OSH4 IN COMPLEX WITH
+    ///  CHOLESTEROL', 'experimental_method': 'X-RAY DIFFRACTION', 'resolution': 1.6,
+    ///  'chain_ids': ['A'], 'num_chains': 1, 'num_residues': 701, 'num_atoms': 3806}
+    #[pyo3(signature = (/))]
+    pub fn metadata_dict(&self, python: Python) -> PyResult<Py<PyDict>> {
+        let metadata = PyDict::new(python);
+        let (num_residues, num_atoms) = self
+            .chains
+            .values()
+            .map(|chain| {
+                let chain = chain
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python);
+
+                (chain.num_residues(), chain.num_atoms(python))
+            })
+            .fold((0, 0), |total, counts| {
+                (total.0 + counts.0, total.1 + counts.1)
+            });
+
+        metadata.set_item("pdbid", &self.pdbid)?;
+        metadata.set_item("classification", &self.classification)?;
+        metadata.set_item("date", &self.date)?;
+        metadata.set_item("title", &self.title)?;
+        metadata.set_item("experimental_method", &self.experimental_method)?;
+        metadata.set_item("resolution", self.resolution)?;
+        metadata.set_item(
+            "chain_ids",
+            self.chains
+                .keys()
+                .map(|name| name.to_string())
+                .collect::<Vec<String>>(),
+        )?;
+        metadata.set_item("num_chains", self.num_chains())?;
+        metadata.set_item("num_residues", num_residues)?;
+        metadata.set_item("num_atoms", num_atoms)?;
+
+        Ok(metadata.into())
+    }
+
+    /// Returns the model with the given index.
+    ///
+    /// Files without MODEL records have a single model. All other structure methods work on
+    /// the first model (index 0), whose chains are shared with the structure.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// index : int
+    ///     The index of the model (0-based), an IndexError is raised when out of range.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// Model
+    ///     The model.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Accessing a frame of an NMR ensemble.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1d3z")
+    /// ...
+    /// >>> len(structure.models), structure.model(1)
+    ///
+    /// (10, <Model 2: 1 chains>)
+    #[pyo3(signature = (index, /))]
+    pub fn model(&self, python: Python, index: usize) -> PyResult<Py<Model>> {
+        match index {
+            0 => self.first_model(python),
+            _ => match self.models.get(index - 1) {
+                Some(model) => Ok(model
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .clone_ref(python)),
+                None => Err(PyIndexError::new_err("index out of range")),
+            },
+        }
+    }
+
+    /// Returns the molecular weight of the structure, the sum of the atomic masses of its
+    /// atoms.
+    ///
+    /// Only the atoms present in the file are counted, missing hydrogens are not added
+    /// implicitly (X-ray structures rarely contain them, so their weight is typically below
+    /// the weight computed from the sequence). Atoms of unknown elements have no weight and
+    /// are reported with a UserWarning.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// include_water : bool
+    ///     Whether water molecules are counted (default False).
+    /// include_hydrogens : bool
+    ///     Whether hydrogen (and deuterium) atoms are counted (default True).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// float
+    ///     The molecular weight (in daltons).
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Weighing the structure without its waters.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> round(structure.molecular_weight(), 1)
+    ///
+    /// 46959.2
+    #[pyo3(signature = (include_water = false, include_hydrogens = true))]
+    pub fn molecular_weight(
+        &self,
+        python: Python,
+        include_water: bool,
+        include_hydrogens: bool,
+    ) -> PyResult<f64> {
+        let atoms: Vec<Py<Atom>> = self
+            .chains
+            .values()
+            .flat_map(|chain| {
+                chain
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python)
+                    .weighed_atoms(python, include_water)
+            })
+            .collect();
+
+        atom::molecular_weight(python, &atoms, include_hydrogens)
+    }
+
+    /// Returns a spatial index over the atoms of the structure.
+    ///
+    /// The atoms are put into a neighbor grid once, so the index answers any number of
+    /// atoms_within and neighbors queries without scanning all atoms. A cell size close to the
+    /// typical query radius is the fastest. The index keeps the positions at the time it was
+    /// built, so it has to be built again after the structure is moved.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// cell_size : float
+    ///     The width of the grid cells (in Angstroms, default 5.0).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// NeighborSearch
+    ///     The index over the atoms of the structure.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Counting the atoms around every atom of the ligands.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> search = structure.neighbor_search()
+    /// ...
+    /// >>> ligands = [residue for residue in structure.residues() if residue.kind() == "ligand"]
+    /// >>> sum(len(search.neighbors(atom, 4.0)) for residue in ligands for atom in residue)
+    ///
+    /// 330
+    #[pyo3(signature = (cell_size = 5.0))]
+    pub fn neighbor_search(&self, python: Python, cell_size: f64) -> PyResult<NeighborSearch> {
+        if cell_size <= 0.0 {
+            return Err(PyValueError::new_err("cell size must be positive"));
+        }
+
+        Ok(NeighborSearch::new(
+            python,
+            self.collect_atoms(python),
+            cell_size,
+        ))
+    }
+
+    /// Returns the atoms of the structure within the radius of an atom, without the atom
+    /// itself.
+    ///
+    /// Atoms do not know the structure they belong to, so the search starts from the
+    /// structure. A neighbor grid is built for the single query, use neighbor_search for
+    /// repeated queries.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// atom : Atom
+    ///     The atom to search around.
+    /// radius : float
+    ///     The search radius (in Angstroms).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// list[Atom]
+    ///     The list of neighboring atoms (in file order).
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Finding the atoms bonded to the first alpha carbon.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> [atom.name for atom in structure.neighbors(structure[0][0][1], 1.7)]
+    ///
+    /// ['N', 'C', 'CB']
+    #[pyo3(signature = (atom, radius, /))]
+    pub fn neighbors(
+        &self,
+        python: Python,
+        atom: PyRef<Atom>,
+        radius: f64,
+    ) -> PyResult<Vec<Py<Atom>>> {
+        NeighborSearch::new(python, self.collect_atoms(python), radius.max(1.0))
+            .neighbors(python, atom, radius)
+    }
+
+    /// Returns the summary of the structure metadata and contents.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// dict
+    ///     The dictionary with keys: "pdbid", "classification", "date", "title",
+    ///     "experimental_method", "resolution", "num_chains", "num_residues", "num_atoms"
+    ///     and "has_unit_cell".
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Retrieving the summary of the structure.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.summary()
+    ///
+    /// {'pdbid': '1ZHY', 'classification': 'LIPID BINDING PROTEIN', 'date': '26-APR-05',
+    ///  'title': 'STRUCTURE OF YEAST OXYSTEROL BINDING PROTEIN OSH4 IN COMPLEX WITH
+    ///  CHOLESTEROL', 'experimental_method': 'X-RAY DIFFRACTION', 'resolution': 1.6,
+    ///  'num_chains': 1, 'num_residues': 701, 'num_atoms': 3806, 'has_unit_cell': True}
+    #[pyo3(signature = (/))]
+    pub fn summary(&self, python: Python) -> PyResult<Py<PyDict>> {
+        let summary = PyDict::new(python);
+        let num_residues: usize = self
+            .chains
+            .values()
+            .map(|chain| {
+                chain
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python)
+                    .num_residues()
+            })
+            .sum();
+        let has_unit_cell = self.unit_cell.as_ref().is_some_and(|unit_cell| {
+            let unit_cell = unit_cell.borrow(python);
+            unit_cell.a > 0.0 && unit_cell.b > 0.0 && unit_cell.c > 0.0
+        });
+
+        summary.set_item("pdbid", &self.pdbid)?;
+        summary.set_item("classification", &self.classification)?;
+        summary.set_item("date", &self.date)?;
+        summary.set_item("title", &self.title)?;
+        summary.set_item("experimental_method", &self.experimental_method)?;
+        summary.set_item("resolution", self.resolution)?;
+        summary.set_item("num_chains", self.num_chains())?;
+        summary.set_item("num_residues", num_residues)?;
+        summary.set_item("num_atoms", self.num_atoms(python))?;
+        summary.set_item("has_unit_cell", has_unit_cell)?;
+
+        Ok(summary.into())
+    }
+
+    /// Returns the number of atoms that builds the structure.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// int
+    ///     The number of atoms in the structure.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Counting the atoms of the structure.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.num_atoms()
+    ///
+    /// 3806
+    #[pyo3(signature = (/))]
+    pub fn num_atoms(&self, python: Python) -> usize {
+        self.chains
+            .values()
+            .map(|chain| {
+                chain
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python)
+                    .num_atoms(python)
+            })
+            .sum()
+    }
+
+    /// Returns the number of chains that builds the structure (of the first model).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// int
+    ///     The number of chains in the structure.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Counting the chains of the structure.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.num_chains()
+    ///
+    /// 1
+    #[pyo3(signature = (/))]
+    pub fn num_chains(&self) -> usize {
+        self.chains.len()
+    }
+
+    /// Returns the number of residues (including waters and ligands) in the structure.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// int
+    ///     The number of residues in the structure.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Counting the residues of the structure.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.num_residues()
+    ///
+    /// 701
+    #[pyo3(signature = (/))]
+    pub fn num_residues(&self, python: Python) -> usize {
+        self.chains
+            .values()
+            .map(|chain| {
+                chain
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python)
+                    .num_residues()
+            })
+            .sum()
+    }
+
+    /// Returns the data for a Ramachandran plot (backbone torsions of the structure).
+    ///
+    /// Only residues with both phi and psi defined are reported, so the chain termini,
+    /// residues next to chain breaks and residues missing any of the N, CA, C atoms are
+    /// skipped.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// list[(int, float, float)]
+    ///     The list of (residue number, phi, psi) tuples, angles in degrees.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Retrieving the Ramachandran plot data.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.ramachandran()
+    ///
+    /// [(0, -79.71..., 148.10...), (1, -53.91..., -28.21...), (2, -62.95..., -18.62...), ...]
+    #[pyo3(signature = (/))]
+    pub fn ramachandran(&self, python: Python) -> Vec<(i32, f64, f64)> {
+        self.chains
+            .values()
+            .flat_map(|chain| {
+                chain
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python)
+                    .backbone_torsions(python)
+            })
+            .filter_map(|(number, phi, psi)| Some((number, phi?, psi?)))
+            .collect()
+    }
+
+    /// Returns a new structure without the water residues.
+    ///
+    /// Waters are the residues named HOH, WAT or DOD (in any case), all other HETATM groups
+    /// (ligands, ions) are kept. Chains left without residues are dropped. The atoms are
+    /// copies, so the new structure is independent of this one.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// Structure
+    ///     The structure without waters.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Removing the ordered waters.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.num_atoms(), structure.remove_water().num_atoms()
+    ///
+    /// (3806, 3544)
+    #[pyo3(signature = (/))]
+    pub fn remove_water(&self, python: Python) -> PyResult<Structure> {
+        self.map_residues(python, |_, residue| {
+            match residue_names::is_water(&residue.name) {
+                true => Ok(None),
+                false => Ok(Some(residue.deep_copy(python)?)),
+            }
+        })
+    }
+
+    /// Returns the mean B-factor of every residue along with the residue numbers.
+    ///
+    /// Both outputs follow the order of the residues in the structure, so the B-factor at a
+    /// given index belongs to the residue number at the same index.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// (numpy.ndarray, list[int])
+    ///     The array of mean B-factors and the list of residue numbers.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Retrieving the B-factor profile of a structure.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> b_factors, numbers = structure.residue_b_factors()
+    /// >>> numbers[:5]
+    ///
+    /// [-1, 0, 1, 2, 3]
+    #[pyo3(signature = (/))]
+    pub fn residue_b_factors(&self, python: Python) -> (Py<PyArray1<f64>>, Vec<i32>) {
+        let mut b_factors = Vec::new();
+        let mut numbers = Vec::new();
+
+        for chain in self.chains.values().map(|chain| {
+            chain
+                .as_ref()
+                .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                .borrow(python)
+        }) {
+            for residue in chain.residues.values().map(|residue| {
+                residue
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python)
+            }) {
+                let total: f64 = residue
+                    .atoms
+                    .iter()
+                    .map(|atom| {
+                        atom.as_ref()
+                            .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                            .borrow(python)
+                            .b_factor
+                    })
+                    .sum();
+
+                b_factors.push(match residue.atoms.is_empty() {
+                    true => 0.0,
+                    false => total / residue.atoms.len() as f64,
+                });
+                numbers.push(residue.number);
+            }
+        }
+
+        (PyArray1::from_vec(python, b_factors).to_owned(), numbers)
+    }
+
+    /// Returns a lazy iterator over all residues of the structure (in file order).
+    ///
+    /// The residues of all chains are yielded one at a time, without caring about the chain
+    /// boundaries. No list is built (unlike get_residues).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// StructureResidueIterator
+    ///     The iterator over the residues of the structure.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Iterating over the residues of the structure.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> for residue in structure.residues():
+    /// ...     print(residue.name, residue.number)
+    ///
+    /// MET -1
+    /// ASP 0
+    /// ...
+    #[pyo3(signature = (/))]
+    pub fn residues(&self, python: Python) -> StructureResidueIterator {
+        StructureResidueIterator::new(self.chain_handles(python))
+    }
+
+    /// Returns the root-mean-square deviation between the atoms of two structures.
+    ///
+    /// Atoms of the selection are paired in file order and compared as they are, without
+    /// superposition (see superpose). Both structures must have the same number of selected
+    /// atoms, otherwise a ValueError is raised. Only the first models are compared.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// other : Structure
+    ///     The structure to compare with.
+    /// selection : str
+    ///     "CA" for the alpha carbons (default), "backbone" for the N, CA, C and O atoms of
+    ///     amino acids or "all" for all atoms.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// float
+    ///     The RMSD (in Angstroms).
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Comparing the structure with a shifted copy.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> shifted = structure.copy()
+    /// ...
+    /// >>> shifted.translate((1.0, 0.0, 0.0))
+    /// >>> structure.rmsd(shifted, "backbone")
+    ///
+    /// 1.0
+    #[pyo3(signature = (other, /, selection = "CA"))]
+    pub fn rmsd(&self, python: Python, other: PyRef<Structure>, selection: &str) -> PyResult<f64> {
+        let (positions, other_positions) = self.selection_pairs(python, &other, selection)?;
+
+        Ok(geometry::rmsd(&positions, &other_positions).unwrap_or_default())
+    }
+
+    /// Rotates the structure in place (all models) by the rotation matrix.
+    ///
+    /// Every atom position p becomes matrix @ p (rotation about the origin, combine with translate
+    /// to rotate about another point). The structure is modified, use copy to keep the original.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// matrix : list[list[float]]
+    ///     The 3x3 rotation matrix (orthonormal rows, determinant 1), a ValueError is raised for
+    ///     other matrices.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Rotating the structure by 90 degrees about the z axis.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.rotate([[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]])
+    /// >>> structure[0][0][0].position
+    ///
+    /// (-36.56, 42.854, 10.394)
+    #[pyo3(signature = (matrix, /))]
+    pub fn rotate(&self, python: Python, matrix: [[f64; 3]; 3]) -> PyResult<()> {
+        if !geometry::is_rotation(matrix, ROTATION_TOLERANCE) {
+            return Err(PyValueError::new_err("matrix is not a rotation matrix"));
+        }
+
+        self.map_all_positions(python, |position| geometry::transform(matrix, position));
+
+        Ok(())
+    }
+
+    /// Writes the structure to a PDB file (see to_pdb).
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// path : str
+    ///     The path to the output file.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Saving a downloaded structure.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.save("1zhy.pdb")
+    #[pyo3(signature = (path, /))]
+    pub fn save(&self, python: Python, path: String) -> PyResult<()> {
+        std::fs::write(path, self.to_pdb(python))?;
+
+        Ok(())
+    }
+
+    /// Returns a new structure with only the atoms of the record type.
+    ///
+    /// Selecting "ATOM" keeps the polymer (e.g. the protein without ligands, ions and
+    /// waters), selecting "HETATM" keeps the heteroatom groups. Chains and residues keep
+    /// their grouping for the kept atoms, residues and chains left without atoms are dropped.
+    /// The atoms are copies, so the new structure is independent of this one.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// label : str
+    ///     The record type of the kept atoms, "ATOM" or "HETATM" (other values raise a
+    ///     ValueError).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// Structure
+    ///     The structure with the selected atoms.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Separating the protein from the heteroatom groups.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.select_atoms("ATOM").num_atoms(), structure.select_atoms("HETATM").num_atoms()
+    ///
+    /// (3514, 292)
+    #[pyo3(signature = (label, /))]
+    pub fn select_atoms(&self, python: Python, label: &str) -> PyResult<Structure> {
+        if label != "ATOM" && label != "HETATM" {
+            return Err(PyValueError::new_err(format!(
+                "label must be \"ATOM\" or \"HETATM\", got: {:?}",
+                label
+            )));
+        }
+
+        self.map_residues(python, |_, residue| {
+            let atoms: Vec<Atom> = residue
+                .atoms
+                .iter()
+                .map(|atom| {
+                    atom.as_ref()
+                        .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                        .borrow(python)
+                })
+                .filter(|atom| atom.label() == label)
+                .map(|atom| atom.clone())
+                .collect();
+
+            match atoms.is_empty() {
+                true => Ok(None),
+                false => Ok(Some(residue.copy_with_atoms(python, atoms)?)),
+            }
+        })
+    }
+
+    /// Returns the atoms whose names match the glob pattern.
+    ///
+    /// The pattern is matched against the whole (trimmed) atom name, `*` matches any sequence
+    /// of characters (including an empty one) and `?` matches exactly one character, other
+    /// characters match literally (case-sensitive).
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// pattern : str
+    ///     The glob pattern, e.g. "H*" for hydrogens or "*G*" for gamma atoms.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// list[Atom]
+    ///     The list of matching atoms (in file order), empty if no atom matches.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Selecting the gamma atoms.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> len(structure.select_atoms_glob("*G*"))
+    ///
+    /// 451
+    #[pyo3(signature = (pattern, /))]
+    pub fn select_atoms_glob(&self, python: Python, pattern: &str) -> Vec<Py<Atom>> {
+        self.collect_atoms(python)
+            .into_iter()
+            .filter(|atom| pattern::glob_match(pattern, &atom.borrow(python).name))
+            .collect()
+    }
+
+    /// Returns the relative shape anisotropy of the structure.
+    ///
+    /// Computed from the eigenvalues l1, l2, l3 of the gyration tensor of the atom positions
+    /// as 1 - 3 * (l1 * l2 + l2 * l3 + l3 * l1) / (l1 + l2 + l3) ** 2. It is 0.0 for
+    /// spherically symmetric structures (and other highly symmetric ones, e.g. cubes), 0.25
+    /// for planar symmetric ones and 1.0 for rods. A ValueError is raised when the structure
+    /// has no extent (no atoms or all atoms in one place).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// float
+    ///     The relative shape anisotropy (dimensionless, range 0.0-1.0).
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Describing the shape of the structure.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.shape_anisotropy()
+    ///
+    /// 0.0572...
+    #[pyo3(signature = (/))]
+    pub fn shape_anisotropy(&self, python: Python) -> PyResult<f64> {
+        let [l1, l2, l3] = self.gyration_eigenvalues(python)?;
+
+        Ok(1.0 - 3.0 * (l1 * l2 + l2 * l3 + l3 * l1) / (l1 + l2 + l3).powi(2))
+    }
+
+    /// Splits chains wherever consecutive residue numbers jump by more than the threshold.
+    ///
+    /// The first segment of a split chain keeps the chain (and its name), every further segment
+    /// becomes a new chain placed right after it. New chains are named with the first unused
+    /// name from A-Z, then a-z, then 0-9. Jumps are measured in both directions, so restarted
+    /// numbering also splits the chain. Note that waters and ligands numbered far from the
+    /// polymer end up in chains of their own.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// gap_threshold : int
+    ///     The largest allowed difference of consecutive residue numbers (at least 1).
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Separating segments of a fused chain.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.parse("fused.pdb")
+    /// ...
+    /// >>> structure.split_on_gaps(100)
+    /// >>> [chain.name for chain in structure]
+    ///
+    /// ['A', 'C', 'B']
+    #[pyo3(signature = (gap_threshold, /))]
+    pub fn split_on_gaps(&mut self, python: Python, gap_threshold: i32) -> PyResult<()> {
+        if gap_threshold < 1 {
+            return Err(PyValueError::new_err("gap threshold must be at least 1"));
+        }
+
+        // Indices of the residues starting a new segment, for every chain.
+        let splits: Vec<Vec<usize>> = self
+            .chains
+            .values()
+            .map(|chain| {
+                let chain = chain
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python);
+
+                let numbers: Vec<i64> = chain.residues.keys().map(|key| key.1 as i64).collect();
+
+                (1..numbers.len())
+                    .filter(|index| {
+                        (numbers[*index] - numbers[index - 1]).abs() > gap_threshold as i64
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let mut names = CHAIN_NAMES
+            .chars()
+            .filter(|name| !self.chains.contains_key(name));
+        let mut new_names: Vec<char> = Vec::new();
+
+        for _ in splits.iter().flatten() {
+            match names.next() {
+                Some(name) => new_names.push(name),
+                None => return Err(PyValueError::new_err("no unused chain names left")),
+            }
+        }
+
+        let mut new_names = new_names.into_iter();
+        let mut chains = IndexMap::new();
+
+        for ((name, chain), splits) in self.chains.drain(..).zip(splits) {
+            let chain = chain.expect(concat!("memory error in: ", file!(), ", line: ", line!()));
+            let mut segments = Vec::new();
+
+            {
+                let mut chain = chain.borrow_mut(python);
+
+                for split in splits.into_iter().rev() {
+                    segments.push(chain.residues.split_off(split));
+                }
+            }
+
+            chains.insert(name, Some(chain));
+
+            for residues in segments.into_iter().rev() {
+                let name = new_names.next().expect(concat!(
+                    "memory error in: ",
+                    file!(),
+                    ", line: ",
+                    line!()
+                ));
+
+                chains.insert(
+                    name,
+                    Some(Py::new(
+                        python,
+                        Chain {
+                            name,
+                            residues,
+                            seqres: Vec::new(),
+                        },
+                    )?),
+                );
+            }
+        }
+
+        self.chains = chains;
+
+        Ok(())
+    }
+
+    /// Superposes the structure onto the reference in place (all models) and returns the
+    /// RMSD after the superposition.
+    ///
+    /// The rotation and translation minimizing the RMSD between the selected atoms (paired
+    /// in file order, see rmsd) are computed with the Kabsch algorithm and applied to every
+    /// atom of the structure. The fit is always a proper rotation, mirror images are never
+    /// produced. Both structures must have the same number of selected atoms, otherwise a
+    /// ValueError is raised. The reference is not modified.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// reference : Structure
+    ///     The structure to superpose onto.
+    /// selection : str
+    ///     "CA" for the alpha carbons (default), "backbone" for the N, CA, C and O atoms of
+    ///     amino acids or "all" for all atoms.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// float
+    ///     The RMSD (in Angstroms) of the selected atoms after the superposition.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Undoing a rotation and a translation of a copy.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// >>> moved = structure.copy()
+    /// ...
+    /// >>> moved.rotate([[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]])
+    /// >>> moved.translate((10.0, 0.0, 0.0))
+    /// >>> round(moved.superpose(structure), 6)
+    ///
+    /// 0.0
+    #[pyo3(signature = (reference, /, selection = "CA"))]
+    pub fn superpose(
+        &self,
+        python: Python,
+        reference: PyRef<Structure>,
+        selection: &str,
+    ) -> PyResult<f64> {
+        let (positions, reference_positions) =
+            self.selection_pairs(python, &reference, selection)?;
+
+        let (rotation, translation) = geometry::superposition(&positions, &reference_positions)
+            .ok_or_else(|| PyValueError::new_err(format!("no atoms selected ({})", selection)))?;
+
+        let fit = |position| geometry::add(geometry::transform(rotation, position), translation);
+
+        self.map_all_positions(python, fit);
+
+        let positions: Vec<Vector> = positions.into_iter().map(fit).collect();
+
+        Ok(geometry::rmsd(&positions, &reference_positions).unwrap_or_default())
+    }
+
+    /// Returns the structure in the PDB format.
+    ///
+    /// The text consists of the HEADER and CRYST1 records (when present in the structure)
+    /// and the ATOM/HETATM records of all atoms, with their original serial numbers. Each
+    /// chain ends with a TER record, structures with more than one model have every model
+    /// enclosed in MODEL/ENDMDL records. Parsing the text gives back the same atoms.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// str
+    ///     The PDB text.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Converting the structure back to the PDB format.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> print(structure.to_pdb())
+    ///
+    /// HEADER    LIPID BINDING PROTEIN                   26-APR-05   1ZHY
+    /// CRYST1   82.760   94.210   65.412  90.00  96.27  90.00
+    /// ATOM      1  N   MET A  -1      42.854  36.560  10.394  1.00 37.27           N
+    /// ATOM      2  CA  MET A  -1      42.250  35.232  10.096  1.00 37.04           C
+    /// ...
+    #[pyo3(signature = (/))]
+    pub fn to_pdb(&self, python: Python) -> String {
+        let mut pdb = String::new();
+
+        if !(self.pdbid.is_empty() && self.classification.is_empty() && self.date.is_empty()) {
+            pdb.push_str(&writer::header_record(
+                &self.classification,
+                &self.date,
+                &self.pdbid,
+            ));
+            pdb.push('\n');
+        }
+
+        if let Some(unit_cell) = self.unit_cell.as_ref() {
+            let unit_cell = unit_cell.borrow(python);
+
+            if unit_cell.a > 0.0 {
+                pdb.push_str(&writer::cryst1_record(&unit_cell));
+                pdb.push('\n');
+            }
+        }
+
+        if self.models.is_empty() {
+            Structure::push_chains(python, &self.chains, &mut pdb);
+        } else {
+            pdb.push_str(&format!("MODEL     {:>4}\n", self.first_model));
+            Structure::push_chains(python, &self.chains, &mut pdb);
+            pdb.push_str("ENDMDL\n");
+
+            for model in self.models.iter().map(|model| {
+                model
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python)
+            }) {
+                pdb.push_str(&format!("MODEL     {:>4}\n", model.number));
+                Structure::push_chains(python, &model.chains, &mut pdb);
+                pdb.push_str("ENDMDL\n");
+            }
+        }
+
+        pdb.push_str("END\n");
+        pdb
+    }
+
+    /// Returns the structure in the AutoDock PDBQT format.
+    ///
+    /// This is a best-effort conversion. Charges are the formal charges read from columns
+    /// 79-80 of the PDB file, so every atom needs one (neutral atoms as "0+"), a ValueError is
+    /// raised when any atom has a blank charge field. Atom types follow AutoDock 4: carbons are aromatic
+    /// (A) in the rings of PHE, TYR, TRP and HIS and aliphatic (C) otherwise, nitrogens are
+    /// acceptors (NA) in the HIS ring and N otherwise, oxygens are OA, sulfurs SA, hydrogens
+    /// are HD when the nearest heavy atom of the residue is a nitrogen or an oxygen and H
+    /// otherwise. Other elements keep their symbol (e.g. Zn, Fe, Mg). Each chain ends with
+    /// a TER record.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// str
+    ///     The PDBQT text.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Converting a structure with charges to PDBQT.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.parse("receptor.pdb")
+    /// ...
+    /// >>> print(structure.to_pdbqt_string())
+    ///
+    /// ATOM      1  N   MET A   1      42.854  36.560  10.394  1.00 37.27    +1.000 N
+    /// ATOM      2  CA  MET A   1      42.250  35.232  10.096  1.00 37.04    +0.000 C
+    /// ...
+    #[pyo3(signature = (/))]
+    pub fn to_pdbqt_string(&self, python: Python) -> PyResult<String> {
+        let atoms = self.collect_atoms(python);
+        let uncharged: Vec<&Py<Atom>> = atoms
+            .iter()
+            .filter(|atom| atom.borrow(python).charge.is_none())
+            .collect();
+
+        if let Some(atom) = uncharged.first() {
+            return Err(PyValueError::new_err(format!(
+                "{} of {} atoms have no charge (columns 79-80 are blank), the first is atom {}",
+                uncharged.len(),
+                atoms.len(),
+                atom.borrow(python).number
+            )));
+        }
+
+        let mut pdbqt = String::new();
+
+        for chain in self.chains.values().map(|chain| {
+            chain
+                .as_ref()
+                .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                .borrow(python)
+        }) {
+            for residue in chain.residues.values().map(|residue| {
+                residue
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python)
+            }) {
+                let atoms: Vec<Atom> = residue
+                    .atoms
+                    .iter()
+                    .map(|atom| {
+                        atom.as_ref()
+                            .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                            .borrow(python)
+                            .clone()
+                    })
+                    .collect();
+
+                for atom in atoms.iter() {
+                    pdbqt.push_str(&format!(
+                        "{}    {:+6.3} {:<2}\n",
+                        writer::atom_record(atom, atom.number, &residue, chain.name),
+                        atom.charge.unwrap_or(0) as f64,
+                        writer::autodock_type(&residue.name, atom, &atoms),
+                    ));
+                }
+            }
+
+            pdbqt.push_str("TER\n");
+        }
+
+        Ok(pdbqt)
+    }
+
+    /// Translates the structure in place (all models) by the vector.
+    ///
+    /// The structure is modified, use copy to keep the original.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// vector : (float, float, float)
+    ///     The translation vector.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Moving the structure along the x axis.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.translate((10.0, 0.0, 0.0))
+    /// >>> structure[0][0][0].position
+    ///
+    /// (52.854, 36.56, 10.394)
+    #[pyo3(signature = (vector, /))]
+    pub fn translate(&self, python: Python, vector: Vector) {
+        self.map_all_positions(python, |position| geometry::add(position, vector));
+    }
+
+    /// Writes the selected atoms to a PDB file.
+    ///
+    /// Records keep the residue and chain of every atom, atoms are written in the order of
+    /// the structure (not of the selection) and each chain ends with a TER record. The atoms
+    /// have to belong to the structure, otherwise a ValueError is raised.
+    ///
+    ///
+    /// Parameters
+    /// ----------
+    /// path : str
+    ///     The path to the output file.
+    /// atoms : list[Atom]
+    ///     The atoms to write.
+    /// renumber : bool
+    ///     Whether the atoms are renumbered contiguously from 1 (default True), otherwise the
+    ///     original atom numbers are kept.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Saving the carbon atoms of the structure.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> carbons = [atom for atom in structure.get_atoms() if atom.element == "C"]
+    /// >>> structure.write_selection("carbons.pdb", carbons)
+    #[pyo3(signature = (path, atoms, /, renumber = true))]
+    pub fn write_selection(
+        &self,
+        python: Python,
+        path: String,
+        atoms: Vec<Py<Atom>>,
+        renumber: bool,
+    ) -> PyResult<()> {
+        let mut selected: HashSet<*mut ffi::PyObject> =
+            atoms.iter().map(|atom| atom.as_ptr()).collect();
+
+        let mut pdb = String::new();
+        let mut serial = 0;
+
+        for chain in self.chains.values().map(|chain| {
+            chain
+                .as_ref()
+                .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                .borrow(python)
+        }) {
+            let mut written = false;
+
+            for residue in chain.residues.values().map(|residue| {
+                residue
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python)
+            }) {
+                for atom in residue.atoms.iter().map(|atom| {
+                    atom.as_ref()
+                        .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                }) {
+                    if !selected.remove(&atom.as_ptr()) {
+                        continue;
+                    }
+
+                    let atom = atom.borrow(python);
+                    serial += 1;
+                    written = true;
+
+                    pdb.push_str(&writer::pdb_record(
+                        &atom,
+                        if renumber { serial } else { atom.number },
+                        &residue,
+                        chain.name,
+                    ));
+                    pdb.push('\n');
+                }
+            }
+
+            if written {
+                pdb.push_str("TER\n");
+            }
+        }
+
+        if !selected.is_empty() {
+            return Err(PyValueError::new_err(format!(
+                "{} of the selected atoms do not belong to the structure",
+                selected.len()
+            )));
+        }
+
+        pdb.push_str("END\n");
+        std::fs::write(path, pdb)?;
+
+        Ok(())
+    }
+
+    /// Returns the water molecules of the structure.
+    ///
+    /// Waters are recognized by the residue name (HOH, WAT or DOD).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// list[Residue]
+    ///     The list of water residues (empty for a desolvated structure).
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Counting the ordered waters.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> len(structure.waters())
+    ///
+    /// 262
+    #[pyo3(signature = (/))]
+    pub fn waters(&self, python: Python) -> Vec<Py<Residue>> {
+        self.chains
+            .values()
+            .flat_map(|chain| {
+                chain
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python)
+                    .residues
+                    .iter()
+                    .filter(|((name, _, _), _)| residue_names::is_water(name))
+                    .map(|(_, residue)| {
+                        residue
+                            .as_ref()
+                            .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                            .clone_ref(python)
+                    })
+                    .collect::<Vec<Py<Residue>>>()
+            })
+            .collect()
+    }
+}
+
+impl Structure {
+    pub fn new(python: Python) -> PyResult<Self> {
+        Ok(Structure {
+            pdbid: String::default(),
+            classification: String::default(),
+            date: String::default(),
+            title: String::default(),
+            experimental_method: String::default(),
+            resolution: None,
+            unit_cell: Some(Py::new(python, UnitCell::default())?),
+            chains: IndexMap::default(),
+            first_model: 1,
+            models: Vec::default(),
+            bonds: Vec::default(),
+            warnings: Vec::default(),
+            atom_count: Cell::default(),
+        })
+    }
+
+    /// Number of atoms, counted only on the first call and again after atoms were removed
+    /// from residues in place (see ATOMS_GENERATION).
+    pub fn cached_num_atoms(&self, python: Python) -> usize {
+        let generation = ATOMS_GENERATION.load(Ordering::Relaxed);
+
+        match self.atom_count.get() {
+            Some((counted, count)) if counted == generation => count,
+            _ => {
+                let count = self.num_atoms(python);
+                self.atom_count.set(Some((generation, count)));
+
+                count
+            }
+        }
+    }
+
+    /// The first model, sharing its chains with the structure.
+    pub fn first_model(&self, python: Python) -> PyResult<Py<Model>> {
+        let mut model = Model::new(self.first_model);
+
+        for (name, chain) in self.chains.iter() {
+            model.chains.insert(
+                *name,
+                Some(
+                    chain
+                        .as_ref()
+                        .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                        .clone_ref(python),
+                ),
+            );
+        }
+
+        Py::new(python, model)
+    }
+
+    pub fn state(&self, python: Python) -> StructureState {
+        let chain_states = |chains: &IndexMap<char, Option<Py<Chain>>>| -> Vec<ChainState> {
+            chains
+                .values()
+                .flatten()
+                .map(|chain| chain.borrow(python).state(python))
+                .collect()
+        };
+
+        (
+            self.pdbid.clone(),
+            self.classification.clone(),
+            self.date.clone(),
+            self.title.clone(),
+            self.experimental_method.clone(),
+            self.resolution,
+            self.unit_cell(python).borrow(python).state(),
+            chain_states(&self.chains),
+            self.first_model,
+            self.models
+                .iter()
+                .flatten()
+                .map(|model| {
+                    let model = model.borrow(python);
+                    (model.number, chain_states(&model.chains))
+                })
+                .collect(),
+            self.bonds.clone(),
+            self.warnings.clone(),
+        )
+    }
+
+    pub fn from_state(python: Python, state: StructureState) -> PyResult<Self> {
+        let (
+            pdbid,
+            classification,
+            date,
+            title,
+            experimental_method,
+            resolution,
+            unit_cell,
+            chains,
+            first_model,
+            models,
+            bonds,
+            warnings,
+        ) = state;
+
+        let from_chain_states =
+            |states: Vec<ChainState>| -> PyResult<IndexMap<char, Option<Py<Chain>>>> {
+                let mut chains = IndexMap::with_capacity(states.len());
+
+                for state in states {
+                    let chain = Chain::from_state(python, state)?;
+                    chains.insert(chain.name, Some(Py::new(python, chain)?));
+                }
+
+                Ok(chains)
+            };
+
+        let mut structure = Structure::new(python)?;
+        structure.set_header(&pdbid, &classification, &date);
+        structure.title = title;
+        structure.experimental_method = experimental_method;
+        structure.resolution = resolution;
+        structure.set_unit_cell(python, UnitCell::from_state(unit_cell))?;
+        structure.chains = from_chain_states(chains)?;
+        structure.first_model = first_model;
+        structure.bonds = bonds;
+        structure.warnings = warnings;
+
+        for (number, chains) in models {
+            let mut model = Model::new(number);
+            model.chains = from_chain_states(chains)?;
+
+            structure.models.push(Some(Py::new(python, model)?));
+        }
+
+        Ok(structure)
+    }
+
+    /// New structure with the same header and unit cell, and no chains.
+    pub fn empty_copy(&self, python: Python) -> PyResult<Structure> {
+        let mut structure = Structure::new(python)?;
+        structure.set_header(&self.pdbid, &self.classification, &self.date);
+        structure.title = self.title.clone();
+        structure.experimental_method = self.experimental_method.clone();
+        structure.resolution = self.resolution;
+
+        if let Some(unit_cell) = &self.unit_cell {
+            structure.set_unit_cell(python, unit_cell.borrow(python).clone())?;
+        }
+
+        Ok(structure)
+    }
+
+    /// Copy of the structure with copies of all its models, chains, residues and atoms.
+    pub fn deep_copy(&self, python: Python) -> PyResult<Structure> {
+        let mut structure = self.empty_copy(python)?;
+        structure.chains = Structure::copy_chains(python, &self.chains)?;
+        structure.first_model = self.first_model;
+        structure.bonds = self.bonds.clone();
+        structure.warnings = self.warnings.clone();
+
+        for model in self.models.iter().flatten() {
+            let model = model.borrow(python);
+            let mut copy = Model::new(model.number);
+            copy.chains = Structure::copy_chains(python, &model.chains)?;
+
+            structure.models.push(Some(Py::new(python, copy)?));
+        }
+
+        Ok(structure)
+    }
+
+    /// Copies of the chains (with copies of all their residues and atoms).
+    pub fn copy_chains(
+        python: Python,
+        chains: &IndexMap<char, Option<Py<Chain>>>,
+    ) -> PyResult<IndexMap<char, Option<Py<Chain>>>> {
+        let mut copies = IndexMap::with_capacity(chains.len());
+
+        for (name, chain) in chains.iter() {
+            let chain = chain
+                .as_ref()
+                .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                .borrow(python)
+                .deep_copy(python)?;
+
+            copies.insert(*name, Some(Py::new(python, chain)?));
+        }
+
+        Ok(copies)
+    }
+
+    /// New structure built by transforming every residue into a new one (or dropping it
+    /// when the transform returns None). Chains left without residues are dropped.
+    pub fn map_residues(
+        &self,
+        python: Python,
+        mut transform: impl FnMut(&Chain, &Residue) -> PyResult<Option<Residue>>,
+    ) -> PyResult<Structure> {
+        let mut structure = self.empty_copy(python)?;
+
+        for chain in self.chains.values().map(|chain| {
+            chain
+                .as_ref()
+                .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                .borrow(python)
+        }) {
+            let mut new_chain = chain.empty_copy();
+
+            for (key, residue) in chain.residues.iter() {
+                let residue = residue
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python);
+
+                if let Some(new_residue) = transform(&chain, &residue)? {
+                    new_chain
+                        .residues
+                        .insert(key.clone(), Some(Py::new(python, new_residue)?));
+                }
+            }
+
+            if !new_chain.residues.is_empty() {
+                structure
+                    .chains
+                    .insert(chain.name, Some(Py::new(python, new_chain)?));
+            }
+        }
+
+        Ok(structure)
+    }
+
+    /// New references to the chains of the structure (for the lazy iterators).
+    pub fn chain_handles(&self, python: Python) -> Vec<Option<Py<Chain>>> {
+        self.chains
+            .values()
+            .map(|chain| {
+                Some(
+                    chain
+                        .as_ref()
+                        .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                        .clone_ref(python),
+                )
+            })
+            .collect()
+    }
+
+    /// All atoms of the structure (in file order).
+    pub fn collect_atoms(&self, python: Python) -> Vec<Py<Atom>> {
+        let mut atoms = Vec::new();
+
+        for chain in self.chains.values().map(|chain| {
+            chain
+                .as_ref()
+                .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                .borrow(python)
+        }) {
+            for residue in chain.residues.values().map(|residue| {
+                residue
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python)
+            }) {
+                for atom in residue.atoms.iter() {
+                    atoms.push(
+                        atom.as_ref()
+                            .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                            .clone_ref(python),
+                    );
+                }
+            }
+        }
+
+        atoms
+    }
+
+    /// Eigenvalues (in ascending order) of the gyration tensor of the atom positions, raises
+    /// a ValueError when the structure has no extent.
+    pub fn gyration_eigenvalues(&self, python: Python) -> PyResult<[f64; 3]> {
+        let eigenvalues = geometry::gyration_tensor(&self.positions(python))
+            .map(geometry::symmetric_eigenvalues)
+            .unwrap_or([0.0; 3]);
+
+        if eigenvalues.iter().sum::<f64>() <= 0.0 {
+            return Err(PyValueError::new_err(
+                "structure has no extent (no atoms or all atoms in one place)",
+            ));
+        }
+
+        Ok(eigenvalues)
+    }
+
+    /// Canonical description of the structure without coordinates: chain names with the
+    /// numbers, names and (sorted) atom names of their residues.
+    pub fn topology(&self, python: Python) -> Vec<(char, Vec<ResidueTopology>)> {
+        self.chains
+            .values()
+            .map(|chain| {
+                let chain = chain
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python);
+
+                let residues = chain
+                    .residues
+                    .values()
+                    .map(|residue| {
+                        let residue = residue
+                            .as_ref()
+                            .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                            .borrow(python);
+
+                        let mut names: Vec<String> = residue
+                            .atoms
+                            .iter()
+                            .map(|atom| {
+                                atom.as_ref()
+                                    .expect(concat!(
+                                        "memory error in: ",
+                                        file!(),
+                                        ", line: ",
+                                        line!()
+                                    ))
+                                    .borrow(python)
+                                    .name
+                                    .to_string()
+                            })
+                            .collect();
+                        names.sort_unstable();
+
+                        (residue.number, residue.name.to_string(), names)
+                    })
+                    .collect();
+
+                (chain.name, residues)
+            })
+            .collect()
+    }
+
+    /// Moves every atom of the structure to the position given by the transform.
+    pub fn map_positions(&self, python: Python, mut transform: impl FnMut(Vector) -> Vector) {
+        for atom in self.collect_atoms(python) {
+            let mut atom = atom.borrow_mut(python);
+            atom.position = transform(atom.position);
+        }
+    }
+
+    /// Like map_positions, but the atoms of the following models are transformed too.
+    pub fn map_all_positions(&self, python: Python, mut transform: impl FnMut(Vector) -> Vector) {
+        self.map_positions(python, &mut transform);
+
+        for model in self.models.iter().flatten() {
+            for chain in model.borrow(python).chains.values() {
+                let atoms = chain
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python)
+                    .collect_atoms(python);
+
+                for atom in atoms {
+                    let mut atom = atom.borrow_mut(python);
+                    atom.position = transform(atom.position);
+                }
+            }
+        }
+    }
+
+    /// Positions of all atoms of the structure (in file order).
+    pub fn positions(&self, python: Python) -> Vec<Vector> {
+        let mut positions = Vec::new();
+
+        for chain in self.chains.values().map(|chain| {
+            chain
+                .as_ref()
+                .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                .borrow(python)
+        }) {
+            for residue in chain.residues.values().map(|residue| {
+                residue
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python)
+            }) {
+                for atom in residue.atoms.iter().map(|atom| {
+                    atom.as_ref()
+                        .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                        .borrow(python)
+                }) {
+                    positions.push(atom.position);
+                }
+            }
+        }
+
+        positions
+    }
+
+    /// Positions of the atoms of the selection (in file order): "CA" for the alpha carbons,
+    /// "backbone" for the N, CA, C and O atoms of amino acids or "all" for all atoms. Raises
+    /// a ValueError for other selections.
+    pub fn selection_positions(&self, python: Python, selection: &str) -> PyResult<Vec<Vector>> {
+        if !["CA", "backbone", "all"].contains(&selection) {
+            return Err(PyValueError::new_err(format!(
+                "selection must be \"CA\", \"backbone\" or \"all\", got: \"{}\"",
+                selection
+            )));
+        }
+
+        let mut positions = Vec::new();
+
+        for chain in self.chains.values().map(|chain| {
+            chain
+                .as_ref()
+                .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                .borrow(python)
+        }) {
+            for residue in chain.residues.values().map(|residue| {
+                residue
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python)
+            }) {
+                let atoms = match selection {
+                    "all" => residue.collect_atoms(python),
+                    _ => residue.backbone(python),
+                };
+
+                positions.extend(
+                    atoms
+                        .iter()
+                        .map(|atom| atom.borrow(python))
+                        .filter(|atom| selection != "CA" || atom.name == "CA")
+                        .map(|atom| atom.position),
+                );
+            }
+        }
+
+        Ok(positions)
+    }
+
+    /// Positions of the selected atoms (see selection_positions) of this and the other
+    /// structure, raises a ValueError when their numbers differ or no atom is selected.
+    pub fn selection_pairs(
+        &self,
+        python: Python,
+        other: &Structure,
+        selection: &str,
+    ) -> PyResult<(Vec<Vector>, Vec<Vector>)> {
+        let positions = self.selection_positions(python, selection)?;
+        let other_positions = other.selection_positions(python, selection)?;
+
+        if positions.len() != other_positions.len() {
+            return Err(PyValueError::new_err(format!(
+                "numbers of selected atoms differ: {} and {} ({})",
+                positions.len(),
+                other_positions.len(),
+                selection
+            )));
+        }
+
+        if positions.is_empty() {
+            return Err(PyValueError::new_err(format!(
+                "no atoms selected ({})",
+                selection
+            )));
+        }
+
+        Ok((positions, other_positions))
+    }
+
+    /// (N, 3) NumPy array of the positions.
+    pub fn coordinates_array(python: Python, positions: &[Vector]) -> PyResult<Py<PyArray2<f64>>> {
+        let flat: Vec<f64> = positions
+            .iter()
+            .flat_map(|(x, y, z)| [*x, *y, *z])
+            .collect();
+
+        Ok(PyArray1::from_vec(python, flat)
+            .reshape([positions.len(), 3])?
+            .to_owned())
+    }
+
+    /// Appends the ATOM/HETATM records of the chains (each followed by a TER record).
+    pub fn push_chains(
+        python: Python,
+        chains: &IndexMap<char, Option<Py<Chain>>>,
+        pdb: &mut String,
+    ) {
+        for chain in chains.values().map(|chain| {
+            chain
+                .as_ref()
+                .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                .borrow(python)
+        }) {
+            for residue in chain.residues.values().map(|residue| {
+                residue
+                    .as_ref()
+                    .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                    .borrow(python)
+            }) {
+                for atom in residue.atoms.iter().map(|atom| {
+                    atom.as_ref()
+                        .expect(concat!("memory error in: ", file!(), ", line: ", line!()))
+                        .borrow(python)
+                }) {
+                    pdb.push_str(&writer::pdb_record(
+                        &atom,
+                        atom.number,
+                        &residue,
+                        chain.name,
+                    ));
+                    pdb.push('\n');
+                }
+            }
+
+            pdb.push_str("TER\n");
+        }
+    }
+
+    #[inline(always)]
+    pub fn set_header(&mut self, pdbid: &str, classification: &str, date: &str) {
+        self.pdbid = pdbid.to_string();
+        self.classification = classification.to_string();
+        self.date = date.to_string();
+    }
+
+    /// Appends the text of a continued record (e.g. TITLE) to the field.
+    #[inline(always)]
+    pub fn append_text(field: &mut String, text: &str) {
+        if !field.is_empty() && !text.is_empty() {
+            field.push(' ');
+        }
+
+        field.push_str(text);
     }
 
     #[inline(always)]
@@ -1,8 +1,13 @@
-use pyo3::{pyclass, pymethods};
+use pyo3::{
+    exceptions::PyValueError, pyclass, pymethods, types::PyType, PyCell, PyObject, PyResult,
+};
+
+/// Pickled state of a unit cell: a, b, c, alpha, beta and gamma.
+pub type UnitCellState = (f64, f64, f64, f64, f64, f64);
 
 /// UnitCell - a class that represents a unit cell of a PDB structure.
 #[pyclass(module = "nanoPDB", frozen)]
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct UnitCell {
     /// [float] Length of side 'a' of unit cell.
     #[pyo3(get)]
@@ -16,15 +21,15 @@ pub struct UnitCell {
     #[pyo3(get)]
     pub c: f64,
 
-    /// [float] Alpha angle ('b' -> 'c') of unit cell (in radians).
+    /// [float] Alpha angle ('b' -> 'c') of unit cell (in degrees).
     #[pyo3(get)]
     pub alpha: f64,
 
-    /// [float] Beta angle ('c' -> 'a') of unit cell (in radians).
+    /// [float] Beta angle ('c' -> 'a') of unit cell (in degrees).
     #[pyo3(get)]
     pub beta: f64,
 
-    /// [float] amma angle ('a' -> 'b') of unit cell (in radians).
+    /// [float] amma angle ('a' -> 'b') of unit cell (in degrees).
     #[pyo3(get)]
     pub gamma: f64,
 }
@@ -35,12 +40,163 @@ impl UnitCell {
     // Special methods
     // ----------------------------------------------------------------------------------------
 
+    pub fn __reduce__(slf: &PyCell<Self>) -> PyResult<(PyObject, (UnitCellState,))> {
+        Ok((
+            slf.get_type().getattr("_from_state")?.into(),
+            (slf.borrow().state(),),
+        ))
+    }
+
     pub fn __repr__(&self) -> String {
         format!("{:#}", self)
     }
+
+    // ----------------------------------------------------------------------------------------
+    // Methods
+    // ----------------------------------------------------------------------------------------
+
+    /// Rebuilds the unit cell from its pickled state (see __reduce__).
+    #[classmethod]
+    #[pyo3(name = "_from_state", signature = (state, /))]
+    pub fn py_from_state(_class: &PyType, state: UnitCellState) -> Self {
+        UnitCell::from_state(state)
+    }
+
+    /// Returns the matrix converting Cartesian coordinates to fractional coordinates.
+    ///
+    /// The matrix is the inverse of the orthogonalization matrix (see
+    /// fractional_to_cartesian) and corresponds to the SCALE records of the PDB file. A
+    /// ValueError is raised for a unit cell without volume (e.g. a structure without a
+    /// CRYST1 record).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// list[list[float]]
+    ///     The 3x3 matrix (rows), fractional = matrix * cartesian.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Computing the SCALE matrix of the structure.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> matrix = structure.unit_cell.cartesian_to_fractional()
+    /// >>> [[round(value, 6) for value in row] for row in matrix]
+    ///
+    /// [[0.012083, -0.0, 0.001328], [0.0, 0.010615, -0.0], [0.0, 0.0, 0.01538]]
+    #[pyo3(signature = (/))]
+    pub fn cartesian_to_fractional(&self) -> PyResult<[[f64; 3]; 3]> {
+        let [[m00, m01, m02], [_, m11, m12], [_, _, m22]] = self.fractional_to_cartesian();
+
+        if !(m00 * m11 * m22).is_normal() {
+            return Err(PyValueError::new_err("unit cell has no volume"));
+        }
+
+        Ok([
+            [
+                1.0 / m00,
+                -m01 / (m00 * m11),
+                (m01 * m12 - m02 * m11) / (m00 * m11 * m22),
+            ],
+            [0.0, 1.0 / m11, -m12 / (m11 * m22)],
+            [0.0, 0.0, 1.0 / m22],
+        ])
+    }
+
+    /// Returns the matrix converting fractional coordinates to Cartesian coordinates.
+    ///
+    /// The orthogonalization matrix follows the PDB convention: the 'a' axis is along x, the
+    /// 'b' axis lies in the xy plane and the 'c' axis completes the right-handed system.
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// list[list[float]]
+    ///     The 3x3 upper triangular matrix (rows), cartesian = matrix * fractional.
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Computing the orthogonalization matrix of the structure.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> matrix = structure.unit_cell.fractional_to_cartesian()
+    /// >>> [[round(value, 3) for value in row] for row in matrix]
+    ///
+    /// [[82.76, 0.0, -7.144], [0.0, 94.21, 0.0], [0.0, 0.0, 65.021]]
+    #[pyo3(signature = (/))]
+    pub fn fractional_to_cartesian(&self) -> [[f64; 3]; 3] {
+        let (cos_alpha, cos_beta, cos_gamma) = (
+            self.alpha.to_radians().cos(),
+            self.beta.to_radians().cos(),
+            self.gamma.to_radians().cos(),
+        );
+        let sin_gamma = self.gamma.to_radians().sin();
+
+        [
+            [self.a, self.b * cos_gamma, self.c * cos_beta],
+            [
+                0.0,
+                self.b * sin_gamma,
+                self.c * (cos_alpha - cos_beta * cos_gamma) / sin_gamma,
+            ],
+            [0.0, 0.0, self.volume() / (self.a * self.b * sin_gamma)],
+        ]
+    }
+
+    /// Returns the volume of the unit cell.
+    ///
+    /// The volume of the triclinic cell is V = abc * sqrt(1 - cos(alpha)^2 - cos(beta)^2 -
+    /// cos(gamma)^2 + 2 cos(alpha) cos(beta) cos(gamma)).
+    ///
+    ///
+    /// Returns
+    /// -------
+    /// float
+    ///     The volume of the unit cell (in cubic Angstroms).
+    ///
+    ///
+    /// Examples
+    /// --------
+    /// Computing the volume of the unit cell.
+    ///
+    /// >>> parser = nanoPDB.Parser()
+    /// >>> structure = parser.fetch("1zhy")
+    /// ...
+    /// >>> structure.unit_cell.volume()
+    ///
+    /// 506954.85113109805
+    #[pyo3(signature = (/))]
+    pub fn volume(&self) -> f64 {
+        let (cos_alpha, cos_beta, cos_gamma) = (
+            self.alpha.to_radians().cos(),
+            self.beta.to_radians().cos(),
+            self.gamma.to_radians().cos(),
+        );
+
+        self.a
+            * self.b
+            * self.c
+            * (1.0 - cos_alpha.powi(2) - cos_beta.powi(2) - cos_gamma.powi(2)
+                + 2.0 * cos_alpha * cos_beta * cos_gamma)
+                .sqrt()
+    }
 }
 
 impl UnitCell {
+    pub fn state(&self) -> UnitCellState {
+        (self.a, self.b, self.c, self.alpha, self.beta, self.gamma)
+    }
+
+    pub fn from_state((a, b, c, alpha, beta, gamma): UnitCellState) -> Self {
+        UnitCell::new(a, b, c, alpha, beta, gamma)
+    }
+
     #[inline(always)]
     pub fn new(a: f64, b: f64, c: f64, alpha: f64, beta: f64, gamma: f64) -> Self {
         UnitCell {
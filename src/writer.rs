@@ -0,0 +1,116 @@
+use crate::{
+    atom::Atom, elements, geometry, hybrid36, residue::Residue, residue_names, unit_cell::UnitCell,
+};
+
+/// Aromatic carbons of the standard amino acids (AutoDock type A).
+static AROMATIC_CARBONS: &[(&str, &[&str])] = &[
+    ("PHE", &["CG", "CD1", "CD2", "CE1", "CE2", "CZ"]),
+    ("TYR", &["CG", "CD1", "CD2", "CE1", "CE2", "CZ"]),
+    (
+        "TRP",
+        &["CG", "CD1", "CD2", "CE2", "CE3", "CZ2", "CZ3", "CH2"],
+    ),
+    ("HIS", &["CG", "CD2", "CE1"]),
+];
+
+/// Hydrogen bond accepting nitrogens of the standard amino acids (AutoDock type NA).
+static ACCEPTOR_NITROGENS: &[(&str, &[&str])] = &[("HIS", &["ND1", "NE2"])];
+
+/// Atom name field (columns 13-16), names of one-letter elements shorter than four
+/// characters start in column 14.
+#[inline(always)]
+fn atom_name_field(atom: &Atom) -> String {
+    match atom.name.len() < 4 && atom.element.len() < 2 {
+        true => format!(" {:<3}", atom.name),
+        false => format!("{:<4}", atom.name),
+    }
+}
+
+/// Charge field (columns 79-80), e.g. "2+" or "1-", blank for atoms without a charge.
+#[inline(always)]
+fn charge_field(charge: Option<i8>) -> String {
+    match charge {
+        Some(charge) if charge < 0 => format!("{}-", -(charge as i16)),
+        Some(charge) => format!("{}+", charge),
+        None => "  ".to_string(),
+    }
+}
+
+/// Columns 1-66 of the ATOM/HETATM record of an atom (with the given serial number). Serial
+/// and residue numbers too large for their columns are hybrid-36 encoded.
+pub fn atom_record(atom: &Atom, serial: i32, residue: &Residue, chain_name: char) -> String {
+    format!(
+        "{:<6}{} {}{}{:>3} {}{}{}   {:>8.3}{:>8.3}{:>8.3}{:>6.2}{:>6.2}",
+        atom.label.to_string(),
+        hybrid36::encode(serial, 5),
+        atom_name_field(atom),
+        atom.alt_loc.unwrap_or(' '),
+        residue.name,
+        chain_name,
+        hybrid36::encode(residue.number, 4),
+        residue.insertion_code.unwrap_or(' '),
+        atom.position.0,
+        atom.position.1,
+        atom.position.2,
+        atom.occupancy,
+        atom.b_factor,
+    )
+}
+
+/// Complete ATOM/HETATM record of an atom (with the given serial number), including the
+/// element and the charge.
+pub fn pdb_record(atom: &Atom, serial: i32, residue: &Residue, chain_name: char) -> String {
+    format!(
+        "{}          {:>2}{}",
+        atom_record(atom, serial, residue, chain_name),
+        atom.element,
+        charge_field(atom.charge),
+    )
+}
+
+/// HEADER record (classification in columns 11-50, date in 51-59, ID code in 63-66).
+pub fn header_record(classification: &str, date: &str, pdbid: &str) -> String {
+    format!("HEADER    {:<40}{:<9}   {:<4}", classification, date, pdbid)
+}
+
+/// Columns 1-54 of the CRYST1 record (the space group and Z are not stored).
+pub fn cryst1_record(unit_cell: &UnitCell) -> String {
+    format!(
+        "CRYST1{:>9.3}{:>9.3}{:>9.3}{:>7.2}{:>7.2}{:>7.2}",
+        unit_cell.a, unit_cell.b, unit_cell.c, unit_cell.alpha, unit_cell.beta, unit_cell.gamma,
+    )
+}
+
+/// AutoDock 4 atom type of an atom of the residue (given with all its atoms).
+///
+/// Carbons are aromatic (A) in the rings of PHE, TYR, TRP and HIS, otherwise aliphatic
+/// (C). Nitrogens are acceptors (NA) in the HIS ring, otherwise N. Oxygens are always
+/// acceptors (OA) and sulfurs SA. Hydrogens are polar (HD) when the nearest heavy atom of
+/// the residue is a nitrogen or an oxygen, otherwise H. Other elements keep their symbol
+/// (e.g. Zn, Fe, Mg).
+pub fn autodock_type(residue_name: &str, atom: &Atom, residue_atoms: &[Atom]) -> String {
+    let element = elements::normalize(&atom.element);
+    let name = atom.name.as_str();
+
+    match element.as_str() {
+        "C" if residue_names::lists_atom(AROMATIC_CARBONS, residue_name, name) => "A".to_string(),
+        "N" if residue_names::lists_atom(ACCEPTOR_NITROGENS, residue_name, name) => {
+            "NA".to_string()
+        }
+        "O" => "OA".to_string(),
+        "S" => "SA".to_string(),
+        "H" | "D" => {
+            let nearest = residue_atoms
+                .iter()
+                .filter(|other| !["H", "D"].contains(&elements::normalize(&other.element).as_str()))
+                .map(|other| (geometry::distance(atom.position, other.position), other))
+                .min_by(|first, second| first.0.total_cmp(&second.0));
+
+            match nearest.map(|(_, other)| elements::normalize(&other.element)) {
+                Some(heavy) if heavy == "N" || heavy == "O" => "HD".to_string(),
+                _ => "H".to_string(),
+            }
+        }
+        _ => elements::symbol(&element),
+    }
+}
@@ -0,0 +1,32 @@
+import os
+
+import nanoPDB
+
+path = os.path.join(os.path.dirname(__file__), "alt_locs.pdb")
+
+structure = nanoPDB.Parser().parse(path)
+atoms = [(atom.number, atom.alt_loc) for atom in structure.get_atoms()]
+
+print(atoms)
+assert atoms == [(1, None), (2, "A"), (3, "B"), (4, "A"), (5, "B")]
+
+structure = nanoPDB.Parser(alt_loc="best").parse(path)
+atoms = [(atom.number, atom.alt_loc) for atom in structure.get_atoms()]
+
+print(atoms)
+assert atoms == [(1, None), (3, "B"), (4, "A")]
+
+# Averaged conformers are merged into atoms without an alternate location:
+averaged = nanoPDB.Parser().parse(path).average_altlocs()
+atoms = [
+    (atom.name, atom.alt_loc, atom.position, atom.occupancy, round(atom.b_factor, 6))
+    for atom in averaged.get_atoms()
+]
+
+print(atoms)
+assert atoms == [
+    ("N", None, (0.0, 0.0, 0.0), 1.0, 10.0),
+    ("CA", None, (1.6, 0.0, 0.0), 1.0, 26.0),
+    ("CB", None, (1.5, 1.0, 0.0), 1.0, 25.0),
+]
+assert all(line[16] == " " for line in averaged.to_pdb().splitlines() if line.startswith("ATOM"))
@@ -0,0 +1,44 @@
+import math
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+# Atoms of butane-like geometries: a on the x axis, the b -> c bond along the y axis and d
+# rotated around it.
+lines = [
+    ("C1", (1.0, 0.0, 0.0)),
+    ("C2", (0.0, 0.0, 0.0)),
+    ("C3", (0.0, 1.5, 0.0)),
+    ("C4", (0.0, 1.5, 1.0)),
+    ("C5", (1.0, 1.5, 0.0)),
+    ("C6", (-1.0, 1.5, 0.0)),
+]
+content = "".join(
+    f"HETATM{serial:>5}  {name:<3} LIG A   1    {x:8.3f}{y:8.3f}{z:8.3f}  1.00  0.00           C\n"
+    for serial, (name, (x, y, z)) in enumerate(lines, 1)
+)
+a, b, c, d, cis, trans = parser.parse_string(content)[0][0]
+
+assert math.isclose(nanoPDB.Atom.angle(a, b, c), 90.0)
+assert math.isclose(nanoPDB.Atom.angle(b, c, d), 90.0)
+assert math.isclose(nanoPDB.Atom.angle(a, b, a), 0.0)
+
+assert math.isclose(nanoPDB.Atom.dihedral(a, b, c, d), -90.0)
+assert math.isclose(nanoPDB.Atom.dihedral(d, c, b, a), -90.0)
+assert math.isclose(nanoPDB.Atom.dihedral(a, b, c, cis), 0.0, abs_tol=1e-9)
+assert math.isclose(abs(nanoPDB.Atom.dihedral(a, b, c, trans)), 180.0)
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+chain = parser.parse(path)[0]
+previous, residue, following = chain[0], chain[1], chain[2]
+
+phi = nanoPDB.Atom.dihedral(
+    previous.atom("C"), residue.atom("N"), residue.atom("CA"), residue.atom("C")
+)
+psi = nanoPDB.Atom.dihedral(
+    residue.atom("N"), residue.atom("CA"), residue.atom("C"), following.atom("N")
+)
+assert (residue.number, phi, psi) == chain.phi_psi()[1]
+assert 100.0 < nanoPDB.Atom.angle(residue.atom("N"), residue.atom("CA"), residue.atom("C")) < 120.0
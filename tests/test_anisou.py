@@ -0,0 +1,41 @@
+import os
+import pickle
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+path = os.path.join(os.path.dirname(__file__), "anisou.pdb")
+structure = parser.parse(path)
+nitrogen, alpha_carbon, carbon, water = structure.get_atoms()
+
+assert nitrogen.u_aniso == (0.2406, 0.1892, 0.1614, 0.0198, 0.0519, -0.0328)
+assert alpha_carbon.u_aniso[0] == 0.1753
+assert carbon.u_aniso is None
+assert water.u_aniso == (0.3, 0.25, 0.2, -0.01, 0.005, 0.0)
+
+assert pickle.loads(pickle.dumps(nitrogen)).u_aniso == nitrogen.u_aniso
+assert structure.copy().get_atoms()[0].u_aniso == nitrogen.u_aniso
+
+# Skipped record types and skipped waters take their ANISOU records with them:
+assert parser.parse(path, {"ATOM", "HETATM"}).get_atoms()[0].u_aniso is None
+assert parser.parse(path, {"ATOM", "ANISOU"}).num_atoms() == 3
+assert nanoPDB.Parser(skip_water=True).parse(path).num_atoms() == 3
+
+assert all(atom.u_aniso is None for atom in parser.parse(
+    os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+).atoms())
+
+with open(path) as file:
+    content = file.read()
+
+mismatched = content.replace("ANISOU    2", "ANISOU    7")
+try:
+    parser.parse_string(mismatched)
+    assert False
+except Exception as error:
+    assert "does not match the preceding atom 2" in str(error)
+
+relaxed = parser.parse_string(mismatched, None, False)
+assert len(relaxed.warnings) == 1
+assert relaxed.get_atoms()[1].u_aniso is None
@@ -0,0 +1,16 @@
+import math
+import os
+
+import nanoPDB
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+residue = nanoPDB.Parser().parse(path)[0][0]
+
+first, second = residue.atom("N"), residue.atom("CA")
+expected = math.dist(first.position, second.position)
+
+print(first.distance_to(second))
+assert math.isclose(first.distance_to(second), expected)
+assert math.isclose(second.distance_to(first), expected)
+assert math.isclose(first.squared_distance_to(second), expected**2)
+assert first.distance_to(first) == 0.0
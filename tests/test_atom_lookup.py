@@ -0,0 +1,21 @@
+import os
+
+import nanoPDB
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+residue = nanoPDB.Parser().parse(path)[0][0]
+
+assert residue.atom("CA") is residue[1]
+assert residue.atom(" CA ") is residue[1]
+assert residue.atom("N").number == 1
+print(residue.atom("CA"))
+
+assert "CA" in residue
+assert " SD " in residue
+assert "OG" not in residue
+
+try:
+    residue.atom("OG")
+    assert False
+except KeyError as error:
+    print(error)
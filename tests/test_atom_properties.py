@@ -0,0 +1,29 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = parser.parse(path)
+
+atom = structure[0][0][0]
+assert atom.element == "N"
+assert atom.mass() == 14.007
+assert atom.vdw_radius() == 1.55
+
+content = (
+    "HETATM    1 ZN    ZN A   1       0.000   0.000   0.000  1.00  0.00          ZN\n"
+    "HETATM    2 FE    FE A   2       0.000   0.000   0.000  1.00  0.00          Fe\n"
+    "HETATM    3  X1  UNK A   3       0.000   0.000   0.000  1.00  0.00          XX\n"
+)
+zinc, iron, unknown = parser.parse_string(content).atoms()
+
+assert abs(zinc.mass() - 65.38) < 0.01
+assert zinc.vdw_radius() == 1.39
+assert abs(iron.mass() - 55.845) < 0.01
+assert unknown.mass() is None
+assert unknown.vdw_radius() is None
+
+masses = [atom.mass() for atom in structure.atoms()]
+assert all(mass is not None for mass in masses)
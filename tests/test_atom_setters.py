@@ -0,0 +1,44 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = parser.parse(path)
+atom = structure[0][0][0]
+
+atom.set_b_factor(55.25)
+atom.set_occupancy(0.5)
+assert atom.b_factor == 55.25
+assert atom.occupancy == 0.5
+
+# Both bounds are allowed, values outside of [0.0, 1.0] are rejected:
+for value in (0.0, 1.0):
+    atom.set_occupancy(value)
+    assert atom.occupancy == value
+
+for value in (-0.01, 1.01, 2.0):
+    try:
+        atom.set_occupancy(value)
+        assert False
+    except ValueError as error:
+        assert "occupancy must be in range [0.0, 1.0]" in str(error)
+
+assert atom.occupancy == 1.0
+
+# The edits are written to the PDB output (occupancy columns 55-60, B-factor 61-66):
+atom.set_occupancy(0.25)
+line = next(
+    line
+    for line in structure.to_pdb().splitlines()
+    if line.startswith("ATOM") and int(line[6:11]) == atom.number
+)
+
+print(line)
+assert line[54:60] == "  0.25"
+assert line[60:66] == " 55.25"
+
+reparsed = parser.parse_string(structure.to_pdb())
+assert reparsed[0][0][0].occupancy == 0.25
+assert reparsed[0][0][0].b_factor == 55.25
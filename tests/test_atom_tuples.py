@@ -0,0 +1,37 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = parser.parse(path)
+
+atom_tuples = structure.atom_tuples()
+print(atom_tuples[:2])
+assert atom_tuples[0] == (1, "N", "MET", -1, "A", 42.854, 36.56, 10.394)
+
+# One tuple per atom record of the file, in file order:
+with open(path) as file:
+    expected = [
+        (
+            int(line[6:11]),
+            line[12:16].strip(),
+            line[17:20].strip(),
+            int(line[22:26]),
+            line[21],
+            float(line[30:38]),
+            float(line[38:46]),
+            float(line[46:54]),
+        )
+        for line in file
+        if line.startswith(("ATOM", "HETATM"))
+    ]
+
+assert atom_tuples == expected
+assert len(atom_tuples) == structure.num_atoms()
+
+# The same data as the Atom objects:
+atoms = structure.get_atoms()
+assert [(entry[0], entry[1]) for entry in atom_tuples] == [(a.number, a.name) for a in atoms]
+assert [entry[5:] for entry in atom_tuples] == [atom.position for atom in atoms]
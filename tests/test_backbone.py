@@ -0,0 +1,29 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = parser.parse(path)
+
+residue = structure[0][0]
+backbone = residue.backbone()
+assert [atom.name for atom in backbone] == ["N", "CA", "C", "O"]
+assert backbone[1] is residue.atom("CA")
+assert residue.is_complete_backbone()
+
+water = structure.waters()[0]
+assert water.backbone() == []
+assert not water.is_complete_backbone()
+
+content = (
+    "ATOM      1  CA  GLY A   1       0.000   0.000   0.000  1.00  0.00           C\n"
+    "ATOM      2  N   GLY A   1       1.000   0.000   0.000  1.00  0.00           N\n"
+    "ATOM      3  C   GLY A   1       2.000   0.000   0.000  1.00  0.00           C\n"
+)
+residue = parser.parse_string(content)[0][0]
+
+# Backbone order does not depend on the order of the atoms in the file, missing O is skipped:
+assert [atom.name for atom in residue.backbone()] == ["N", "CA", "C"]
+assert not residue.is_complete_backbone()
@@ -0,0 +1,40 @@
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+content = (
+    "ATOM      1  N1   DG A   1       0.000   0.000   0.000  1.00  0.00           N\n"
+    "ATOM      2  N1   DA A   2      20.000   0.000   0.000  1.00  0.00           N\n"
+    "ATOM      3  N3   DC B   3       2.900   0.000   0.000  1.00  0.00           N\n"
+    "ATOM      4  N3   DT B   4      20.000   2.800   0.000  1.00  0.00           N\n"
+    "ATOM      5  N3   DC B   5      22.000   0.000   0.000  1.00  0.00           N\n"
+)
+structure = parser.parse_string(content)
+
+# G pairs with C and A with T, the closer C of the residue 5 does not match A:
+pairs = structure.base_pairs()
+
+print(pairs)
+assert pairs == [(1, 3), (2, 4)]
+assert structure.base_pairs(cutoff=2.85) == [(2, 4)]
+assert structure.base_pairs(cutoff=2.5) == []
+
+# Both guanines are within the cutoff of the same cytosine, only the closer one pairs with it:
+shared = parser.parse_string(
+    "ATOM      1  N1   DG A   1       0.000   0.000   0.000  1.00  0.00           N\n"
+    "ATOM      2  N1   DG A   2       6.000   0.000   0.000  1.00  0.00           N\n"
+    "ATOM      3  N3   DC B   3       3.200   0.000   0.000  1.00  0.00           N\n"
+)
+
+print(shared.base_pairs())
+assert shared.base_pairs() == [(2, 3)]
+
+# The second guanine takes the next cytosine instead of sharing the first one:
+shared = parser.parse_string(
+    "ATOM      1  N1   DG A   1       0.000   0.000   0.000  1.00  0.00           N\n"
+    "ATOM      2  N1   DG A   2       6.000   0.000   0.000  1.00  0.00           N\n"
+    "ATOM      3  N3   DC B   3       2.900   0.000   0.000  1.00  0.00           N\n"
+    "ATOM      4  N3   DC B   4       9.300   0.000   0.000  1.00  0.00           N\n"
+)
+
+assert shared.base_pairs() == [(1, 3), (2, 4)]
@@ -0,0 +1,42 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+content = (
+    # The ligand:
+    "HETATM    1  C1  LIG A 101       0.000   0.000   0.000  1.00  0.00           C\n"
+    "HETATM    2  C2  LIG A 101       1.500   0.000   0.000  1.00  0.00           C\n"
+    # Residue 1 has one atom within 4 A of C2, residue 2 is too far away:
+    "ATOM      3  N   GLY A   1       5.400   0.000   0.000  1.00  0.00           N\n"
+    "ATOM      4  CA  GLY A   1       6.800   0.000   0.000  1.00  0.00           C\n"
+    "ATOM      5  N   GLY A   2      20.000   0.000   0.000  1.00  0.00           N\n"
+    # A water next to C1 and a second ligand far away:
+    "HETATM    6  O   HOH A 201      -3.000   0.000   0.000  1.00  0.00           O\n"
+    "HETATM    7  C1  LIG B 102      40.000   0.000   0.000  1.00  0.00           C\n"
+)
+structure = parser.parse_string(content)
+
+site = structure.binding_site("LIG", 4.0)
+print(site)
+
+# Whole residues are kept, the second LIG copy is a ligand as well:
+assert [atom.number for atom in site.get_atoms()] == [1, 2, 3, 4, 6, 7]
+assert [atom.number for atom in structure.binding_site("LIG", 3.5).get_atoms()] == [1, 2, 6, 7]
+assert structure.binding_site("LIG", 2.0).num_atoms() == 3
+
+# The original structure is not modified:
+assert structure.num_atoms() == 7
+
+try:
+    structure.binding_site("XYZ", 4.0)
+    assert False
+except ValueError:
+    pass
+
+# The cholesterol pocket of the local fixture:
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+pocket = parser.parse(path).binding_site("CLR", 5.0)
+assert pocket.num_atoms() == 255
+assert any(atom.name == "O1" for atom in pocket.get_atoms())
@@ -0,0 +1,23 @@
+import os
+
+import nanoPDB
+
+path = os.path.join(os.path.dirname(__file__), "blank_elements.pdb")
+
+structure = nanoPDB.Parser().parse(path)
+elements = [(atom.name, atom.element) for atom in structure.get_atoms()]
+
+print(elements)
+assert elements == [
+    ("N", "N"),
+    ("CA", "C"),
+    ("OG1", "O"),
+    ("HG21", "H"),
+    ("1HB", "H"),
+    ("CA", "CA"),
+    ("FE", "FE"),
+    ("XX1", "X"),
+]
+
+structure = nanoPDB.Parser(guess_elements=False).parse(path)
+assert all(atom.element == "" for atom in structure.get_atoms())
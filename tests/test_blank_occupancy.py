@@ -0,0 +1,13 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+path = os.path.join(os.path.dirname(__file__), "blank_occupancy.pdb")
+structure = parser.parse(path)
+
+occupancies = [atom.occupancy for atom in structure.get_atoms()]
+
+print(occupancies)
+assert occupancies == [1.0, 1.0, 1.0, 1.0, 0.5]
+assert parser.validate_file(path) == []
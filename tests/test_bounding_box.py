@@ -0,0 +1,25 @@
+import os
+
+import nanoPDB
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = nanoPDB.Parser().parse(path)
+
+
+def expected(atoms):
+    positions = [atom.position for atom in atoms]
+    return tuple(map(min, zip(*positions))), tuple(map(max, zip(*positions)))
+
+
+print(structure.bounding_box())
+assert structure.bounding_box() == expected(structure.atoms())
+assert structure[0].bounding_box() == structure.bounding_box()
+assert structure[0][0].bounding_box() == expected(structure[0][0])
+
+empty = nanoPDB.Parser().parse_string("END\n")
+
+try:
+    empty.bounding_box()
+    assert False
+except ValueError as error:
+    print(error)
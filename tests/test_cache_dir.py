@@ -0,0 +1,20 @@
+import os
+import shutil
+import tempfile
+
+import nanoPDB
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+
+with tempfile.TemporaryDirectory() as cache_dir:
+    shutil.copy(path, os.path.join(cache_dir, "1ZHY.pdb"))
+    parser = nanoPDB.Parser(cache_dir=cache_dir)
+
+    # Cached structures are read without a download:
+    structure = parser.fetch("1zhy")
+    print(structure)
+    assert structure.pdbid == "1ZHY"
+    assert structure.num_atoms() == nanoPDB.Parser().parse(path).num_atoms()
+
+    assert [structure.pdbid for structure in parser.fetch_many(["1ZHY"])] == ["1ZHY"]
+    assert parser.parse("1zhy", {"ATOM"}).num_atoms() == 3514
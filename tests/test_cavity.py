@@ -0,0 +1,42 @@
+import math
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+record = "HETATM{0:>5}  C   UNK A{0:>4}    {1:>8.3f}{2:>8.3f}{3:>8.3f}  1.00  0.00           C\n"
+
+
+def structure_of(positions):
+    return parser.parse_string(
+        "".join(record.format(index + 1, *position) for index, position in enumerate(positions))
+    )
+
+
+def sphere(radius, count):
+    # Evenly spread points (Fibonacci lattice) on the sphere:
+    golden_angle = math.pi * (3.0 - math.sqrt(5.0))
+
+    for index in range(count):
+        z = 1.0 - 2.0 * (index + 0.5) / count
+        ring = math.sqrt(1.0 - z * z)
+        yield (
+            radius * ring * math.cos(golden_angle * index),
+            radius * ring * math.sin(golden_angle * index),
+            radius * z,
+        )
+
+
+# Hollow shell of carbons (radius 8.0), the cavity is bounded by their van der Waals
+# surface (1.70):
+shell = structure_of(list(sphere(8.0, 600)))
+radius = shell.largest_cavity_radius(grid_spacing=0.5)
+
+print(radius)
+assert abs(radius - (8.0 - 1.7)) < 0.5
+assert abs(shell.largest_cavity_radius() - radius) < 1.0
+
+# Densely packed atoms leave no cavity:
+packed = structure_of(
+    [(x * 1.5, y * 1.5, z * 1.5) for x in range(6) for y in range(6) for z in range(6)]
+)
+assert packed.largest_cavity_radius(0.5) == 0.0
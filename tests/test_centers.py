@@ -0,0 +1,51 @@
+import math
+import os
+import warnings
+
+import nanoPDB
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = nanoPDB.Parser().parse(path)
+residue = structure[0][0]
+
+positions = [atom.position for atom in residue]
+expected = tuple(sum(axis) / len(positions) for axis in zip(*positions))
+print(residue.center_of_geometry())
+assert all(map(math.isclose, residue.center_of_geometry(), expected))
+assert structure.center_of_geometry() == structure[0].center_of_geometry()
+assert structure.center_of_mass() == structure[0].center_of_mass()
+
+masses = {"N": 14.007, "C": 12.011, "O": 15.999, "S": 32.06}
+total = sum(masses[atom.element] for atom in residue)
+expected = tuple(
+    sum(masses[atom.element] * atom.position[axis] for atom in residue) / total
+    for axis in range(3)
+)
+print(residue.center_of_mass())
+assert all(math.isclose(a, b, rel_tol=1e-4) for a, b in zip(residue.center_of_mass(), expected))
+
+# Atoms of unknown elements have no weight and raise a warning:
+content = """\
+ATOM      1  N   MET A  -1      42.854  36.560  10.394  1.00 37.27           N
+ATOM      2  CA  MET A  -1      42.250  35.232  10.096  1.00 37.04           C
+HETATM    3  X1  UNK A   1       0.000   0.000   0.000  1.00 37.04          XX
+"""
+structure = nanoPDB.Parser().parse_string(content)
+
+with warnings.catch_warnings(record=True) as caught:
+    warnings.simplefilter("always")
+    structure[0][0].center_of_mass()
+    center = structure[0][1].center_of_geometry()
+    assert len(caught) == 0
+
+    try:
+        structure[0][1].center_of_mass()
+        assert False
+    except ValueError as error:
+        print(error)
+
+    structure.center_of_mass()
+
+assert [str(warning.message) for warning in caught] == ["unknown elements (mass 0.0): XX"] * 2
+assert issubclass(caught[0].category, UserWarning)
+assert center == (0.0, 0.0, 0.0)
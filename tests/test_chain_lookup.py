@@ -0,0 +1,24 @@
+import os
+
+import nanoPDB
+
+path = os.path.join(os.path.dirname(__file__), "1abc.cif")
+structure = nanoPDB.Parser().parse_cif(path)
+
+assert structure["A"] is structure[0]
+assert structure["B"] is structure[1]
+assert structure.chain("B") is structure[1]
+print(structure["A"])
+
+for lookup in (lambda: structure["Z"], lambda: structure.chain("Z")):
+    try:
+        lookup()
+        assert False
+    except KeyError as error:
+        print(error)
+
+try:
+    structure[2]
+    assert False
+except IndexError:
+    pass
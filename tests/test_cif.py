@@ -0,0 +1,48 @@
+import os
+import shutil
+import tempfile
+
+import nanoPDB
+
+path = os.path.join(os.path.dirname(__file__), "1abc.cif")
+
+with tempfile.TemporaryDirectory() as cache_dir:
+    shutil.copy(path, os.path.join(cache_dir, "1ABC.cif"))
+    structure = nanoPDB.Parser(cache_dir=cache_dir).fetch_cif("1abc")
+
+print(structure)
+assert (structure.pdbid, structure.classification, structure.date) == (
+    "1ABC",
+    "DE NOVO PROTEIN",
+    "26-APR-05",
+)
+assert structure.title == "STRUCTURE OF A SMALL TEST PROTEIN WITH A WATER"
+assert structure.experimental_method == "X-RAY DIFFRACTION"
+assert structure.resolution == 1.5
+assert structure.unit_cell.beta == 96.27
+
+chains = [
+    (chain.name, [(residue.name, residue.number, residue.insertion_code) for residue in chain])
+    for chain in structure
+]
+print(chains)
+assert chains == [
+    ("A", [("GLY", 1, None), ("SER", 1, "A"), ("HOH", 101, None), ("ZN", 102, None)]),
+    ("B", [("DA", 1, None)]),
+]
+
+atoms = [
+    (atom.number, atom.name, atom.alt_loc, atom.element, atom.charge, atom.occupancy)
+    for atom in structure.get_atoms()
+]
+print(atoms)
+assert atoms == [
+    (1, "N", None, "N", None, 1.0),
+    (2, "CA", None, "C", None, 1.0),
+    (3, "OG", "A", "O", None, 0.6),
+    (4, "OG", "B", "O", None, 0.4),
+    (6, "O", None, "O", None, 1.0),
+    (7, "ZN", None, "ZN", 2, 1.0),
+    (5, "O5'", None, "O", None, 1.0),
+]
+assert structure[0][2][0].label == "HETATM"
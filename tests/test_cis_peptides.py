@@ -0,0 +1,44 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+record = "ATOM  {0:>5}  {1:<3} {2} A{3:>4}    {4:>8.3f}{5:>8.3f}{6:>8.3f}  1.00  0.00           {7}\n"
+
+
+def dipeptide(second_name, second_ca):
+    atoms = [
+        ("N", "ALA", 10, (-2.300, 0.700, 0.000)),
+        ("CA", "ALA", 10, (-1.000, 1.200, 0.000)),
+        ("C", "ALA", 10, (0.000, 0.000, 0.000)),
+        ("N", second_name, 11, (1.330, 0.000, 0.000)),
+        ("CA", second_name, 11, second_ca),
+        ("C", second_name, 11, (3.400, 0.500, 0.000)),
+    ]
+
+    return parser.parse_string(
+        "".join(
+            record.format(serial, name, residue, number, *position, name[0])
+            for serial, (name, residue, number, position) in enumerate(atoms, start=1)
+        )
+    )
+
+
+# The CA atoms on the same side of the peptide bond (omega close to 0 degrees):
+cis = dipeptide("PRO", (2.000, 1.200, 0.000))
+print(cis.cis_peptides())
+assert cis.cis_peptides() == [11]
+
+# Omega of 20 degrees is within the default tolerance, but not within 10 degrees:
+tilted = dipeptide("PRO", (2.000, 1.128, 0.410))
+assert tilted.cis_peptides() == [11]
+assert tilted.cis_peptides(tolerance=10.0) == []
+
+# The CA atoms on the opposite sides (omega close to 180 degrees):
+trans = dipeptide("GLY", (2.000, -1.200, 0.000))
+assert trans.cis_peptides() == []
+
+# The cis-proline of the local fixture:
+structure = parser.parse(os.path.join(os.path.dirname(__file__), "1zhy.pdb"))
+assert structure.cis_peptides() == [145]
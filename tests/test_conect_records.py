@@ -0,0 +1,15 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+path = os.path.join(os.path.dirname(__file__), "conect_records.pdb")
+structure = parser.parse(path)
+
+print(structure.bonds)
+assert structure.bonds == [(1, 2), (1, 5), (2, 3), (2, 4)]
+assert structure.bonded_to(2) == [1, 3, 4]
+assert structure.bonded_to(6) == []
+
+structure = parser.parse(path, {"HETATM"})
+assert structure.bonds == []
@@ -0,0 +1,49 @@
+import itertools
+import math
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+
+with open(path) as file:
+    lines = [line for line in file if line.startswith(("ATOM", "HETATM"))][:600]
+
+structure = parser.parse_string("".join(lines))
+atoms = structure.get_atoms()
+residues = [
+    (chain.name, residue.number, residue.insertion_code)
+    for chain in structure
+    for residue in chain
+    for _ in residue
+]
+
+
+def brute_force(cutoff, exclude_same_residue):
+    return [
+        (first, second)
+        for first, second in itertools.combinations(range(len(atoms)), 2)
+        if math.dist(atoms[first].position, atoms[second].position) <= cutoff
+        and not (exclude_same_residue and residues[first] == residues[second])
+    ]
+
+
+for cutoff in (2.0, 4.0, 6.5):
+    assert structure.contacts(cutoff) == brute_force(cutoff, True)
+    assert structure.contacts(cutoff, False) == brute_force(cutoff, False)
+
+full = parser.parse(path)
+contacts = full.contacts()
+assert len(contacts) == 13059
+assert all(first < second for first, second in contacts)
+assert len(full.contacts(4.0, False)) > len(contacts)
+assert full.contacts(cutoff=4.0, exclude_same_residue=False) == full.contacts(4.0, False)
+assert full.contacts(exclude_same_residue=True) == contacts
+
+try:
+    full.contacts(0.0)
+    assert False
+except ValueError:
+    pass
@@ -0,0 +1,33 @@
+import itertools
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+record = "HETATM{0:>5}  C   UNK A{0:>4}    {1:>8.3f}{2:>8.3f}{3:>8.3f}  1.00  0.00           C\n"
+
+
+def structure_of(positions):
+    return parser.parse_string(
+        "".join(record.format(index + 1, *position) for index, position in enumerate(positions))
+    )
+
+
+# Cube with the edge of 2 Angstroms and an atom in its center:
+corners = list(itertools.product((0.0, 2.0), repeat=3))
+vertices, volume = structure_of(corners + [(1.0, 1.0, 1.0)]).convex_hull()
+
+print(sorted(vertices), volume)
+assert sorted(vertices) == sorted(corners)
+assert abs(volume - 8.0) < 1e-9
+
+# Coplanar atoms (a square with its center) have no volume:
+square = [(0.0, 0.0, 0.0), (2.0, 0.0, 0.0), (0.0, 2.0, 0.0), (2.0, 2.0, 0.0), (1.0, 1.0, 0.0)]
+vertices, volume = structure_of(square).convex_hull()
+
+print(sorted(vertices), volume)
+assert sorted(vertices) == sorted(square)
+assert volume == 0.0
+
+# Fewer than four atoms:
+vertices, volume = structure_of(square[:2]).convex_hull()
+assert sorted(vertices) == sorted(square[:2]) and volume == 0.0
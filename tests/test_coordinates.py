@@ -0,0 +1,21 @@
+import os
+
+import numpy
+
+import nanoPDB
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = nanoPDB.Parser().parse(path)
+
+coordinates = structure.coordinates()
+print(coordinates.shape)
+assert coordinates.shape == (structure.num_atoms(), 3)
+assert coordinates.dtype == numpy.float64
+assert coordinates.flags["C_CONTIGUOUS"]
+assert [tuple(row) for row in coordinates] == [atom.position for atom in structure.atoms()]
+
+selection = structure.select_atoms_glob("*G*")
+selected = structure.coordinates_of(selection)
+assert selected.shape == (len(selection), 3)
+assert [tuple(row) for row in selected] == [atom.position for atom in selection]
+assert structure.coordinates_of([]).shape == (0, 3)
@@ -0,0 +1,40 @@
+import copy
+import os
+
+import nanoPDB
+
+directory = os.path.dirname(__file__)
+structure = nanoPDB.Parser().parse(os.path.join(directory, "models.pdb"))
+bonded = nanoPDB.Parser().parse(os.path.join(directory, "conect_records.pdb"))
+
+
+def atoms(structure):
+    return [atom for model in structure.models for atom in model.get_chains()[0].get_atoms()]
+
+
+for duplicate in (structure.copy(), copy.copy(structure), copy.deepcopy(structure)):
+    assert duplicate is not structure
+    assert duplicate.pdbid == structure.pdbid
+    assert len(duplicate.models) == len(structure.models)
+    assert [model.number for model in duplicate.models] == [
+        model.number for model in structure.models
+    ]
+    assert [atom.position for atom in atoms(duplicate)] == [
+        atom.position for atom in atoms(structure)
+    ]
+
+    # No atom, residue or chain is shared:
+    assert all(first is not second for first, second in zip(atoms(duplicate), atoms(structure)))
+    assert duplicate[0] is not structure[0]
+    assert duplicate[0][0] is not structure[0][0]
+    assert duplicate.unit_cell is not structure.unit_cell
+
+    duplicate[0][0][0].set_b_factor(99.0)
+    duplicate.translate((1.0, 0.0, 0.0))
+    assert structure[0][0][0].b_factor != 99.0
+    assert structure[0][0][0].position != duplicate[0][0][0].position
+
+duplicate = copy.deepcopy(bonded)
+assert duplicate.bonds == bonded.bonds
+assert duplicate.to_pdb() == bonded.to_pdb()
+assert copy.deepcopy([bonded, bonded])[0] is not bonded
@@ -0,0 +1,30 @@
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+content = (
+    "ATOM      1  N   CYS A   1       0.000   0.000   0.000  1.00  0.00           N\n"
+    "ATOM      2  CA  CYS A   1       1.460   0.000   0.000  1.00  0.00           C\n"
+    "ATOM      3  O   CYS A   1       2.000   1.400   0.000  1.00  0.00           O\n"
+    "ATOM      4  SG  CYS A   1       1.300   2.400   0.000  1.00  0.00           S\n"
+    "ATOM      5  H   CYS A   1       1.800  -0.500   0.900  1.00  0.00           H\n"
+    "HETATM    6 ZN    ZN A 101      10.000   0.000   0.000  1.00  0.00          ZN\n"
+    "HETATM    7 XX   UNK A 102      12.000   0.000   0.000  1.00  0.00          XX\n"
+    "HETATM    8  X   UNK A 103      14.000   0.000   0.000  1.00  0.00            \n"
+)
+structure = parser.parse_string(content)
+colors = {atom.name: atom.cpk_color() for atom in structure.get_atoms()}
+
+print(colors)
+
+# The RasMol CPK colors:
+assert colors["N"] == (143, 143, 255)
+assert colors["CA"] == (200, 200, 200)
+assert colors["O"] == (240, 0, 0)
+assert colors["SG"] == (255, 200, 50)
+assert colors["H"] == (255, 255, 255)
+assert colors["ZN"] == (165, 42, 42)
+
+# Unknown and blank elements are deep pink:
+assert colors["XX"] == (255, 20, 147)
+assert colors["X"] == (255, 20, 147)
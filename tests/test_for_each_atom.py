@@ -0,0 +1,32 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = parser.parse(path)
+
+fields = []
+count = parser.for_each_atom(path, fields.append)
+
+assert count == structure.num_atoms() == len(fields)
+assert fields[0] == ("ATOM", 1, "N", "MET", -1, "A", 42.854, 36.56, 10.394, 1.0, "N")
+assert [atom[6:9] for atom in fields] == [atom.position for atom in structure.atoms()]
+
+# Same callback convention as parse_streaming, returning False stops parsing:
+streamed = []
+assert parser.parse_streaming(path, streamed.append) == count
+assert streamed == fields
+assert parser.for_each_atom(path, lambda atom: atom[1] < 3) == 3
+
+
+def fail(atom):
+    raise RuntimeError("stop")
+
+
+try:
+    parser.for_each_atom(path, fail)
+    raise AssertionError("exception not propagated")
+except RuntimeError as error:
+    assert str(error) == "stop"
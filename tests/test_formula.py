@@ -0,0 +1,35 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+content = (
+    "ATOM      1  N   GLY A   1       0.000   0.000   0.000  1.00  0.00           N\n"
+    "ATOM      2  CA  GLY A   1       1.460   0.000   0.000  1.00  0.00           C\n"
+    "ATOM      3  C   GLY A   1       2.000   1.400   0.000  1.00  0.00           C\n"
+    "ATOM      4  O   GLY A   1       1.300   2.400   0.000  1.00  0.00           O\n"
+    "ATOM      5  HA2 GLY A   1       1.800  -0.500   0.900  1.00  0.00           H\n"
+    "ATOM      6  HA3 GLY A   1       1.800  -0.500  -0.900  1.00  0.00           H\n"
+    "ATOM      7  SG  CYS A   2       4.000   1.000   0.000  1.00  0.00           S\n"
+    "HETATM    8 ZN    ZN A 101      10.000   0.000   0.000  1.00  0.00          ZN\n"
+    "HETATM    9 FE   FE2 A 102      12.000   0.000   0.000  1.00  0.00          FE\n"
+    "HETATM   10  O   HOH A 201      14.000   0.000   0.000  1.00  0.00           O\n"
+)
+structure = parser.parse_string(content)
+
+# Hill order: C, H, then alphabetical (Fe before N), counts of 1 omitted:
+print(structure.formula())
+assert structure.formula() == "C2 H2 Fe N O2 S Zn"
+assert structure.formula(include_hetatm=False) == "C2 H2 N O S"
+
+# Without carbon all elements are in alphabetical order:
+inorganic = parser.parse_string(content.replace("           C\n", "          CL\n"))
+assert inorganic.formula() == "Cl2 Fe H2 N O2 S Zn"
+
+# The protein of the local fixture:
+structure = parser.parse(os.path.join(os.path.dirname(__file__), "1zhy.pdb"))
+print(structure.formula(False))
+assert structure.formula(False) == "C2251 N578 O678 S7"
+assert structure.formula().startswith("C")
+assert structure.formula() != structure.formula(False)
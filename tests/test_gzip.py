@@ -0,0 +1,22 @@
+import gzip
+import os
+import shutil
+import tempfile
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = parser.parse(path)
+
+with tempfile.TemporaryDirectory() as directory:
+    compressed_path = os.path.join(directory, "1zhy.pdb.gz")
+
+    with open(path, "rb") as source, gzip.open(compressed_path, "wb") as target:
+        shutil.copyfileobj(source, target)
+
+    compressed = parser.parse(compressed_path)
+
+print(compressed)
+assert compressed.pdbid == structure.pdbid
+assert compressed.num_atoms() == structure.num_atoms()
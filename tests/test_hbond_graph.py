@@ -0,0 +1,21 @@
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+content = (
+    "ATOM      1  N   GLY A   1       0.000   0.000   0.000  1.00  0.00           N\n"
+    "ATOM      2  CA  GLY A   1      -1.460   0.000   0.000  1.00  0.00           C\n"
+    "ATOM      3  C   GLY A   2       4.000   1.000   0.000  1.00  0.00           C\n"
+    "ATOM      4  O   GLY A   2       2.900   0.000   0.000  1.00  0.00           O\n"
+    "HETATM    5  O   HOH A 101       2.900   2.700   0.000  1.00  0.00           O\n"
+)
+structure = parser.parse_string(content)
+
+# The backbone N donates to the backbone O of the other residue, the water donates to the
+# backbone O as well (the backbone O only accepts):
+edges = structure.hbond_graph()
+
+print(edges)
+assert edges == [(0, 3), (4, 3)]
+assert structure.hbond_graph(include_waters=False) == [(0, 3)]
+assert structure.hbond_graph(cutoff=2.5) == []
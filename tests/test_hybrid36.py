@@ -0,0 +1,31 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+path = os.path.join(os.path.dirname(__file__), "hybrid36.pdb")
+structure = parser.parse(path)
+
+atoms = list(structure.atoms())
+print([atom.number for atom in atoms])
+assert [atom.number for atom in atoms] == [99999, 100000, 100001, 43770016]
+assert [residue.number for residue in structure.residues()] == [9999, 10000, 1223056]
+assert structure.bonds == [(99999, 100000), (100000, 100001), (100000, 43770016)]
+
+assert parser.validate_file(path) == []
+
+# Numbers too large for their columns are written hybrid-36 encoded again:
+pdb = structure.to_pdb()
+assert "ATOM  A0000  CA  GLY AA000" in pdb
+assert "ATOM  a0000  O   GLY Aa000" in pdb
+
+structure = parser.parse_string(pdb)
+assert [atom.number for atom in structure.atoms()] == [99999, 100000, 100001, 43770016]
+
+try:
+    parser.parse_string(
+        "ATOM  A00a0  N   GLY A   1       0.000   0.000   0.000  1.00  0.00           N\n"
+    )
+    raise AssertionError("mixed case hybrid-36 number parsed")
+except Exception as error:
+    assert str(error) == "error in line: 1, cannot parse numeric"
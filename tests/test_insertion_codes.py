@@ -0,0 +1,12 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+path = os.path.join(os.path.dirname(__file__), "insertion_codes.pdb")
+structure = parser.parse(path)
+
+residues = [(residue.number, residue.insertion_code) for residue in structure.get_residues()]
+
+print(residues)
+assert residues == [(99, None), (100, None), (100, "A"), (100, "B"), (101, None)]
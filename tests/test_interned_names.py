@@ -0,0 +1,24 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = parser.parse(path)
+
+alpha_carbons = [atom for atom in structure.atoms() if atom.name == "CA"]
+assert len(alpha_carbons) > 1
+assert all(atom.name is alpha_carbons[0].name for atom in alpha_carbons)
+assert all(atom.element is alpha_carbons[0].element for atom in alpha_carbons)
+
+glycines = [residue for residue in structure.residues() if residue.name == "GLY"]
+assert len(glycines) > 1
+assert all(residue.name is glycines[0].name for residue in glycines)
+
+names = {atom.name for atom in structure.atoms()}
+assert len(names) < structure.num_atoms()
+assert all(isinstance(name, str) for name in names)
+
+atom_tuples = structure.atom_tuples()
+assert atom_tuples[1][2] is atom_tuples[2][2]
@@ -0,0 +1,62 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = parser.parse(path)
+
+
+def edited(structure, edit):
+    lines = []
+
+    for line in structure.to_pdb().splitlines():
+        if line.startswith(("ATOM", "HETATM")):
+            line = edit(line)
+
+        lines.append(line)
+
+    return parser.parse_string("\n".join(lines))
+
+
+def shifted(line):
+    x, y, z = float(line[30:38]), float(line[38:46]), float(line[46:54])
+    return "{}{:8.3f}{:8.3f}{:8.3f}{}".format(line[:30], x + 1.5, y - 2.0, z, line[54:])
+
+
+# Only the coordinates differ, the topology is the same:
+moved = edited(structure, shifted)
+assert moved.rmsd(structure) > 1.0
+assert moved.is_isomorphic(structure)
+assert structure.is_isomorphic(moved)
+
+# A renamed atom (the first CA becomes CB):
+first_ca = next(atom.number for atom in structure.get_atoms() if atom.name == "CA")
+
+
+def renamed(line):
+    if int(line[6:11]) == first_ca:
+        return line[:12] + " CB " + line[16:]
+
+    return line
+
+
+assert not edited(structure, renamed).is_isomorphic(structure)
+
+# A residue with a changed number (the first residue of the first chain):
+first_residue = next(iter(structure[0])).number
+
+
+def renumbered(line):
+    if line[21] == structure[0].name and int(line[22:26]) == first_residue:
+        return "{}{:>4}{}".format(line[:22], first_residue - 100, line[26:])
+
+    return line
+
+
+renumbered_structure = edited(structure, renumbered)
+print(renumbered_structure)
+assert next(iter(renumbered_structure[0])).number == first_residue - 100
+assert not renumbered_structure.is_isomorphic(structure)
+assert not structure.is_isomorphic(renumbered_structure)
@@ -0,0 +1,36 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = parser.parse(path)
+
+sulfurs = [atom.number for atom in structure.iter_element("S")]
+print(sulfurs)
+assert sulfurs[:3] == [7, 559, 668]
+assert sulfurs == [atom.number for atom in structure.get_atoms() if atom.element == "S"]
+
+# The symbol is case-insensitive:
+assert [atom.number for atom in structure.iter_element("s")] == sulfurs
+
+# The iterator is lazy and single-pass:
+iterator = structure.iter_element("S")
+assert iter(iterator) is iterator
+assert next(iterator).number == 7
+assert [atom.number for atom in iterator] == sulfurs[1:]
+
+try:
+    next(iterator)
+    assert False
+except StopIteration:
+    pass
+
+# Elements absent from the structure give an empty iterator:
+assert list(structure.iter_element("ZN")) == []
+
+# The atoms are the atoms of the structure, not copies:
+atom = next(structure.iter_element("S"))
+atom.set_b_factor(99.0)
+assert structure.get_atoms()[6].b_factor == 99.0
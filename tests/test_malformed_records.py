@@ -0,0 +1,24 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+path = os.path.join(os.path.dirname(__file__), "malformed_records.pdb")
+
+try:
+    parser.parse(path)
+    assert False
+except Exception as error:
+    assert str(error) == "error in line: 2, cannot parse numeric"
+
+structure = parser.parse(path, None, False)
+
+print(structure.warnings)
+assert structure.warnings == [
+    "error in line: 2, cannot parse numeric",
+    "error in line: 4, ATOM/HETATM line to short",
+]
+assert [atom.name for atom in structure.get_atoms()] == ["N", "C"]
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+assert parser.parse(path, None, False).warnings == []
@@ -0,0 +1,30 @@
+import os
+import tempfile
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+
+with open(path) as file:
+    content = file.read()
+
+structure = parser.parse(path)
+assert structure.to_pdb() == parser.parse_string(content).to_pdb()
+
+with tempfile.TemporaryDirectory() as directory:
+    empty_path = os.path.join(directory, "empty.pdb")
+    open(empty_path, "wb").close()
+    assert parser.parse(empty_path).num_atoms() == 0
+
+    invalid_path = os.path.join(directory, "invalid.pdb")
+
+    with open(invalid_path, "wb") as file:
+        file.write(content.encode()[:162] + b"\xff" + content.encode()[163:])
+
+    try:
+        parser.parse(invalid_path)
+        assert False
+    except Exception as error:
+        print(error)
+        assert str(error).startswith("line: 3, ")
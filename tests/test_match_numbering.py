@@ -0,0 +1,50 @@
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+reference = parser.parse_string(
+    "ATOM      1  N   GLY A   1       0.000   0.000   0.000  1.00  0.00           N\n"
+    "ATOM      2  CA  GLY A   1       1.460   0.000   0.000  1.00  0.00           C\n"
+    "ATOM      3  N   ALA A   2       3.000   0.000   0.000  1.00  0.00           N\n"
+    "ATOM      4  CA  ALA A   2       4.460   0.000   0.000  1.00  0.00           C\n"
+)
+
+# Same atoms (in a different order within the residue), other numbering and coordinates:
+content = (
+    "ATOM     11  CA  GLY A  10       1.000   1.000   0.000  1.00  0.00           C\n"
+    "ATOM     12  N   GLY A  10       0.000   1.000   0.000  1.00  0.00           N\n"
+    "ATOM     13  N   ALA A  11       3.000   1.000   0.000  1.00  0.00           N\n"
+    "ATOM     14  CA  ALA A  11       4.000   1.000   0.000  1.00  0.00           C\n"
+)
+structure = parser.parse_string(content)
+
+# Residue numbers are compared unless they are copied too:
+try:
+    structure.match_numbering(reference)
+    assert False
+except ValueError as error:
+    print(error)
+
+structure.match_numbering(reference, residues=True)
+atoms = [(atom.name, atom.number, atom.position) for atom in structure.get_atoms()]
+
+print(atoms)
+assert [number for _, number, _ in atoms] == [2, 1, 3, 4]
+assert atoms[0][2] == (1.0, 1.0, 0.0)
+assert [residue.number for residue in structure.get_residues()] == [1, 2]
+
+# With matching residue numbers only the atoms are renumbered:
+structure = parser.parse_string(content.replace("A  10", "A   1").replace("A  11", "A   2"))
+structure.match_numbering(reference)
+assert [atom.number for atom in structure.get_atoms()] == [2, 1, 3, 4]
+
+# Structures of different molecules cannot be matched:
+mismatched = parser.parse_string(content.replace(" CA  ALA", " CB  ALA"))
+
+try:
+    mismatched.match_numbering(reference, residues=True)
+    assert False
+except ValueError as error:
+    print(error)
+
+assert [atom.number for atom in mismatched.get_atoms()] == [11, 12, 13, 14]
@@ -0,0 +1,40 @@
+import json
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = parser.parse(path)
+
+metadata = structure.metadata_dict()
+print(metadata)
+assert metadata == {
+    "pdbid": "1ZHY",
+    "classification": "LIPID BINDING PROTEIN",
+    "date": "26-APR-05",
+    "title": "STRUCTURE OF YEAST OXYSTEROL BINDING PROTEIN OSH4 IN COMPLEX WITH CHOLESTEROL",
+    "experimental_method": "X-RAY DIFFRACTION",
+    "resolution": 1.6,
+    "chain_ids": ["A"],
+    "num_chains": 1,
+    "num_residues": 701,
+    "num_atoms": 3806,
+}
+
+# Plain data, ready for a JSON response:
+assert json.loads(json.dumps(metadata)) == metadata
+
+# The chain names in file order, a structure without a header:
+metadata = parser.parse_string(
+    "ATOM      1  N   GLY B   1       0.000   0.000   0.000  1.00  0.00           N\n"
+    "ATOM      2  N   GLY A   1       5.000   0.000   0.000  1.00  0.00           N\n"
+    "HETATM    3  O   HOH A 101      10.000   0.000   0.000  1.00  0.00           O\n"
+).metadata_dict()
+
+assert metadata["chain_ids"] == ["B", "A"]
+assert metadata["num_chains"] == 2
+assert metadata["num_residues"] == 3
+assert metadata["num_atoms"] == 3
+assert metadata["pdbid"] == "" and metadata["resolution"] is None
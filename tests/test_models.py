@@ -0,0 +1,22 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+path = os.path.join(os.path.dirname(__file__), "models.pdb")
+structure = parser.parse(path)
+
+print(structure.models)
+assert [model.number for model in structure.models] == [1, 2, 3]
+assert len(structure) == 3
+assert structure.num_atoms() == 2
+assert [len(model) for model in structure.models] == [1, 1, 1]
+assert structure.model(2)[0][0][0].position == (0.2, 0.0, 0.0)
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = parser.parse(path)
+
+assert len(structure.models) == 1
+assert len(structure) == 1
+assert structure.model(0)[0].name == "A"
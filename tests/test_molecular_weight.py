@@ -0,0 +1,45 @@
+import os
+import warnings
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = parser.parse(path)
+
+residue = structure[0][0]
+assert residue.name == "MET"
+assert abs(residue.molecular_weight() - 122.121) < 1e-6
+
+dry = structure.molecular_weight()
+wet = structure.molecular_weight(True)
+assert abs(dry - 46959.245) < 1e-3
+assert abs(wet - 51150.983) < 1e-3
+assert abs(sum(chain.molecular_weight(True) for chain in structure) - wet) < 1e-6
+assert abs(structure.remove_water().molecular_weight(True) - dry) < 1e-6
+assert structure.molecular_weight(include_water=True) == wet
+assert structure.molecular_weight(include_hydrogens=False) == structure.molecular_weight(
+    False, False
+)
+
+content = (
+    "ATOM      1  O   HOH A   1       0.000   0.000   0.000  1.00  0.00           O\n"
+    "ATOM      2  H1  HOH A   1       0.957   0.000   0.000  1.00  0.00           H\n"
+    "ATOM      3  H2  HOH A   1      -0.240   0.927   0.000  1.00  0.00           H\n"
+    "HETATM    4 ZN    ZN A   2       5.000   0.000   0.000  1.00  0.00          ZN\n"
+    "HETATM    5  X1  UNK A   3       9.000   0.000   0.000  1.00  0.00          XX\n"
+)
+small = parser.parse_string(content)
+water = small[0][0]
+
+assert water.molecular_weight() == 0.0
+assert abs(water.molecular_weight(True) - 18.015) < 1e-3
+assert abs(water.molecular_weight(True, False) - 15.999) < 1e-3
+
+with warnings.catch_warnings(record=True) as caught:
+    warnings.simplefilter("always")
+    weight = small.molecular_weight()
+
+assert abs(weight - 65.38) < 0.01
+assert len(caught) == 1 and "XX" in str(caught[0].message)
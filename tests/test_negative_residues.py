@@ -0,0 +1,14 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+structure = parser.parse(os.path.join(os.path.dirname(__file__), "negative_residues.pdb"))
+chain = structure[0]
+
+numbers = [residue.number for residue in chain]
+
+print(numbers)
+assert numbers == [-3, -2, -1, 0, 1]
+assert [len(residue) for residue in chain] == [3, 3, 3, 3, 3]
+assert structure.num_atoms() == 15
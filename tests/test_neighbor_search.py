@@ -0,0 +1,51 @@
+import math
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = parser.parse(path)
+atoms = structure.get_atoms()
+
+
+def brute_force(center, radius):
+    return [atom.number for atom in atoms if math.dist(atom.position, center) <= radius]
+
+
+search = structure.neighbor_search()
+assert len(search) == len(atoms)
+
+for center, radius in [
+    (structure.center_of_geometry(), 6.0),
+    (atoms[0].position, 3.0),
+    (atoms[-1].position, 10.0),
+    ((0.0, 0.0, 0.0), 5.0),
+]:
+    expected = brute_force(center, radius)
+    assert [atom.number for atom in structure.atoms_within(center, radius)] == expected
+    assert [atom.number for atom in search.atoms_within(center, radius)] == expected
+
+# Cell sizes only change the speed:
+small = structure.neighbor_search(cell_size=1.0)
+assert [atom.number for atom in small.atoms_within(atoms[100].position, 7.5)] == brute_force(
+    atoms[100].position, 7.5
+)
+
+alpha_carbon = structure[0][0][1]
+assert alpha_carbon.name == "CA"
+assert [atom.name for atom in structure.neighbors(alpha_carbon, 1.7)] == ["N", "C", "CB"]
+assert [atom.name for atom in search.neighbors(alpha_carbon, 1.7)] == ["N", "C", "CB"]
+assert search.atoms_within(alpha_carbon.position, 0.0)[0].name == "CA"
+
+for call in (
+    lambda: structure.atoms_within((0.0, 0.0, 0.0), -1.0),
+    lambda: search.neighbors(alpha_carbon, -1.0),
+    lambda: structure.neighbor_search(0.0),
+):
+    try:
+        call()
+        assert False
+    except ValueError:
+        pass
@@ -0,0 +1,28 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+structure = parser.parse(os.path.join(os.path.dirname(__file__), "1zhy.pdb"))
+chain = structure[0]
+residues = chain.get_residues()[:20]
+residue = chain[0]
+
+# Nested loops over the same object each get their own iterator:
+pairs = [(first, second) for first in chain for second in chain]
+assert len(pairs) == len(chain) ** 2
+assert [first for first, _ in pairs[:: len(chain)]] == chain.get_residues()
+
+atoms = [(first.number, second.number) for first in residue for second in residue]
+numbers = [atom.number for atom in residue]
+assert atoms == [(first, second) for first in numbers for second in numbers]
+
+structure = parser.parse(os.path.join(os.path.dirname(__file__), "ter_records.pdb"))
+names = [(first.name, second.name) for first in structure for second in structure]
+assert names == [(first, second) for first in "ACB" for second in "ACB"]
+
+# An iterator left unfinished does not affect the next loop:
+iterator = iter(chain)
+next(iterator)
+assert list(chain)[: len(residues)] == residues
+assert next(iterator) is chain[1]
@@ -0,0 +1,34 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+record = "{0:<6}{1:>5}  P   {2:>3} {3}{1:>4}       0.000   0.000{4:>8.3f}  1.00  0.00           P\n"
+
+
+def chain(name, residues):
+    return "".join(
+        record.format(kind, serial, residue, name, 10.0 * serial)
+        for serial, (kind, residue) in enumerate(residues, start=1)
+    )
+
+
+content = (
+    chain("A", [("ATOM", name) for name in ("DC", "DG", "DA", "DT", "DU")])
+    + chain("B", [("ATOM", name) for name in ("A", "C", "G", "U")])
+    # Unknown polymer residues are 'N', waters and ligands are skipped:
+    + chain("C", [("ATOM", "DA"), ("ATOM", "PSU"), ("ATOM", "DG"), ("HETATM", "HOH")])
+    + chain("D", [("HETATM", "HEM"), ("HETATM", "HOH")])
+)
+structure = parser.parse_string(content)
+sequences = {chain.name: chain.nucleotide_sequence() for chain in structure}
+
+print(sequences)
+assert sequences == {"A": "CGATU", "B": "ACGU", "C": "ANG", "D": ""}
+
+# A protein chain has no bases, every residue is 'N':
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+protein = parser.parse(path)[0]
+assert set(protein.nucleotide_sequence()) == {"N"}
+assert len(protein.nucleotide_sequence()) == len(protein.sequence())
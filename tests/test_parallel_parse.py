@@ -0,0 +1,60 @@
+import os
+import tempfile
+
+import nanoPDB
+
+
+def positions(structure):
+    return [
+        [atom.position for chain in model for residue in chain for atom in residue]
+        for model in structure.models
+    ]
+
+
+parser = nanoPDB.Parser()
+
+for name in ["models.pdb", "1zhy.pdb", "ter_records.pdb", "insertion_codes.pdb"]:
+    path = os.path.join(os.path.dirname(__file__), name)
+    serial = parser.parse(path)
+    parallel = parser.parse(path, parallel=True)
+
+    assert parallel.to_pdb() == serial.to_pdb()
+    assert positions(parallel) == positions(serial)
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+assert parser.parse(path, {"ATOM"}, True, True).num_atoms() == 3514
+
+path = os.path.join(os.path.dirname(__file__), "malformed_records.pdb")
+serial = parser.parse(path, None, False)
+parallel = parser.parse(path, strict=False, parallel=True)
+
+assert parallel.warnings == serial.warnings
+assert parallel.to_pdb() == serial.to_pdb()
+
+try:
+    parser.parse(path, parallel=True)
+    raise AssertionError("malformed record parsed")
+except Exception as error:
+    assert str(error) == "error in line: 2, cannot parse numeric"
+
+lines = []
+
+for number in range(1, 201):
+    lines.append(f"MODEL     {number:>4}")
+
+    for serial, name, x, element in [(1, "N  ", number, "N"), (2, "CA ", number + 1.46, "C")]:
+        lines.append(
+            f"ATOM  {serial:>5}  {name} GLY A   1    {x:>8.3f}   0.000   0.000  1.00  0.00"
+            f"           {element}"
+        )
+
+    lines.append("ENDMDL")
+
+with tempfile.NamedTemporaryFile("w", suffix=".pdb", delete=False) as file:
+    file.write("\n".join(lines) + "\nEND\n")
+
+structure = parser.parse(file.name, None, True, True)
+os.remove(file.name)
+
+assert [model.number for model in structure.models] == list(range(1, 201))
+assert [model[0][0][0].position[0] for model in structure.models] == list(range(1, 201))
@@ -0,0 +1,28 @@
+import gzip
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = parser.parse(path)
+
+with open(path, "rb") as file:
+    data = file.read()
+
+# Plain and gzip-compressed bytes give the same structure as the file:
+for content in (data, gzip.compress(data)):
+    parsed = parser.parse_bytes(content)
+
+    print(parsed)
+    assert parsed.pdbid == structure.pdbid
+    assert parsed.num_atoms() == structure.num_atoms()
+    assert parsed.to_pdb() == structure.to_pdb()
+
+# Invalid UTF-8 is rejected:
+try:
+    parser.parse_bytes(data[:100] + b"\xff\xfe" + data[100:])
+    assert False
+except Exception as error:
+    assert "invalid utf-8" in str(error)
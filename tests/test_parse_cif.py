@@ -0,0 +1,57 @@
+import gzip
+import os
+import tempfile
+
+import nanoPDB
+
+path = os.path.join(os.path.dirname(__file__), "1abc.cif")
+parser = nanoPDB.Parser()
+
+structure = parser.parse_cif(path)
+print(structure)
+assert (structure.pdbid, structure.classification, structure.date) == (
+    "1ABC",
+    "DE NOVO PROTEIN",
+    "26-APR-05",
+)
+assert structure.num_atoms() == 7
+
+# Gzip-compressed file:
+with tempfile.TemporaryDirectory() as directory:
+    compressed = os.path.join(directory, "1abc.cif.gz")
+
+    with open(path, "rb") as source, gzip.open(compressed, "wb") as target:
+        target.write(source.read())
+
+    assert parser.parse_cif(compressed).num_atoms() == structure.num_atoms()
+
+# Single atom (the _atom_site category written as items), columns in a different order:
+content = """data_TEST
+_atom_site.label_atom_id   "O5'"
+_atom_site.group_PDB       HETATM
+_atom_site.Cartn_x         1.000
+_atom_site.Cartn_y         -2.500
+_atom_site.Cartn_z         3.250
+_atom_site.occupancy       ?
+_atom_site.B_iso_or_equiv  .
+_atom_site.type_symbol     O
+_atom_site.label_comp_id   HOH
+_atom_site.label_asym_id   W
+_atom_site.label_seq_id    .
+_atom_site.auth_seq_id     7
+_atom_site.id              1
+"""
+
+with tempfile.TemporaryDirectory() as directory:
+    single = os.path.join(directory, "single.mmcif")
+
+    with open(single, "w") as file:
+        file.write(content)
+
+    structure = parser.parse_cif(single)
+
+atom = structure[0][0][0]
+print(atom)
+assert (structure[0].name, structure[0][0].name, structure[0][0].number) == ("W", "HOH", 7)
+assert (atom.name, atom.label, atom.position) == ("O5'", "HETATM", (1.0, -2.5, 3.25))
+assert (atom.occupancy, atom.b_factor) == (1.0, 0.0)
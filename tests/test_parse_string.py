@@ -0,0 +1,41 @@
+import os
+
+import nanoPDB
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+parser = nanoPDB.Parser()
+
+with open(path) as file:
+    content = file.read()
+
+structure = parser.parse_string(content)
+expected = parser.parse(path)
+print(structure)
+assert structure.pdbid == expected.pdbid
+assert structure.num_atoms() == expected.num_atoms()
+assert structure.bonds == expected.bonds
+
+atoms_only = parser.parse_string(content, {"ATOM"})
+assert atoms_only.num_atoms() == parser.parse(path, {"ATOM"}).num_atoms()
+
+try:
+    parser.parse_string(content, {"HELIX"})
+    assert False
+except ValueError as error:
+    assert str(error) == "unsupported record type: HELIX"
+
+with open(os.path.join(os.path.dirname(__file__), "malformed_records.pdb")) as file:
+    malformed = file.read()
+
+try:
+    parser.parse_string(malformed)
+    assert False
+except Exception as error:
+    print(error)
+
+assert parser.parse_string(malformed, strict=False).warnings == parser.parse(
+    os.path.join(os.path.dirname(__file__), "malformed_records.pdb"), strict=False
+).warnings
+assert parser.parse_string(malformed, records={"ATOM"}, strict=False).warnings == parser.parse(
+    os.path.join(os.path.dirname(__file__), "malformed_records.pdb"), None, False
+).warnings
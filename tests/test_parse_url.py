@@ -0,0 +1,41 @@
+import functools
+import http.server
+import os
+import threading
+
+import nanoPDB
+
+directory = os.path.dirname(os.path.abspath(__file__))
+
+
+class Handler(http.server.SimpleHTTPRequestHandler):
+    def log_message(self, format, *args):
+        pass
+
+
+# The server runs on a Python thread, so the download only completes when the parser
+# releases the GIL while waiting for the response:
+server = http.server.ThreadingHTTPServer(
+    ("127.0.0.1", 0), functools.partial(Handler, directory=directory)
+)
+threading.Thread(target=server.serve_forever, daemon=True).start()
+url = "http://127.0.0.1:{}/".format(server.server_address[1])
+
+parser = nanoPDB.Parser(timeout=10)
+local = parser.parse(os.path.join(directory, "1zhy.pdb"))
+
+structure = parser.parse(url + "1zhy.pdb")
+print(structure)
+assert structure.pdbid == local.pdbid
+assert structure.num_atoms() == local.num_atoms()
+assert structure.to_pdb() == local.to_pdb()
+
+assert parser.parse(url + "1zhy.pdb", {"ATOM"}).num_atoms() == 3514
+
+try:
+    parser.parse(url + "missing.pdb")
+    assert False
+except Exception as error:
+    assert "404" in str(error)
+
+server.shutdown()
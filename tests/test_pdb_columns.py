@@ -0,0 +1,43 @@
+import os
+
+import nanoPDB
+
+columns = nanoPDB.PDB_COLUMNS
+
+print(dict(columns))
+assert list(columns) == (
+    "record serial name alt_loc residue_name chain residue_number insertion_code x y z "
+    "occupancy b_factor element charge"
+).split()
+assert columns["b_factor"] == (60, 66)
+assert columns["x"] == (30, 38)
+
+# The mapping is read-only:
+try:
+    columns["x"] = (0, 1)
+    assert False
+except TypeError:
+    pass
+
+# Slicing the records with the ranges gives the atom fields:
+parser = nanoPDB.Parser()
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+atoms = parser.parse(path).get_atoms()
+
+
+def field(line, name):
+    start, end = columns[name]
+    return line[start:end]
+
+
+with open(path) as file:
+    lines = [line for line in file if line.startswith(("ATOM", "HETATM"))]
+
+for line, atom in zip(lines, atoms):
+    assert field(line, "record").strip() in ("ATOM", "HETATM")
+    assert int(field(line, "serial")) == atom.number
+    assert field(line, "name").strip() == atom.name
+    assert float(field(line, "occupancy")) == atom.occupancy
+    assert float(field(line, "b_factor")) == atom.b_factor
+    assert field(line, "element").strip() == atom.element
+    assert tuple(float(field(line, axis)) for axis in "xyz") == atom.position
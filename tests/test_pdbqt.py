@@ -0,0 +1,31 @@
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+content = (
+    "ATOM      1  N   GLY A   1       0.000   0.000   0.000  1.00  0.00           N1+\n"
+    "ATOM      2  CA  GLY A   1       1.460   0.000   0.000  1.00  0.00           C0+\n"
+    "ATOM      3  O   GLY A   1       2.000   1.200   0.000  1.00  0.00           O1-\n"
+)
+structure = parser.parse_string(content)
+lines = structure.to_pdbqt_string().splitlines()
+
+print(lines)
+assert [line[66:].split() for line in lines[:3]] == [
+    ["+1.000", "N"],
+    ["+0.000", "C"],
+    ["-1.000", "OA"],
+]
+assert lines[3] == "TER"
+
+# Every atom needs a charge, blank charge fields are not taken as neutral:
+partial = parser.parse_string(content.replace("C0+", "C  "))
+
+try:
+    partial.to_pdbqt_string()
+    assert False
+except ValueError as error:
+    print(error)
+    assert str(error) == (
+        "1 of 3 atoms have no charge (columns 79-80 are blank), the first is atom 2"
+    )
@@ -0,0 +1,36 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = parser.parse(path)
+
+torsions = structure[0].phi_psi()
+assert torsions[0][0] == -1 and torsions[0][1] is None and torsions[0][2] is not None
+assert torsions[-1][2] is None
+assert all(-180.0 <= angle <= 180.0 for _, *angles in torsions for angle in angles if angle)
+assert [torsion for torsion in torsions if None not in torsion] == structure.ramachandran()
+
+# A chain break (missing residue 2) ends the torsions on both of its sides:
+with open(path) as file:
+    lines = [
+        line
+        for line in file
+        if line.startswith("ATOM") and line[22:26].strip() in ("0", "1", "3", "4")
+    ]
+
+chain = parser.parse_string("".join(lines))[0]
+numbers = [number for number, _, _ in chain.phi_psi()]
+assert numbers == [0, 1, 3, 4]
+
+by_number = {number: (phi, psi) for number, phi, psi in chain.phi_psi()}
+assert by_number[0][0] is None and by_number[1][1] is None
+assert by_number[3][0] is None and by_number[4][1] is None
+assert by_number[0][1] == torsions[1][2]
+
+# Residues lacking backbone atoms are skipped:
+lines = [line for line in lines if not (line[22:26].strip() == "3" and line[12:16] == " CA ")]
+chain = parser.parse_string("".join(lines))[0]
+assert [number for number, _, _ in chain.phi_psi()] == [0, 1, 4]
@@ -0,0 +1,53 @@
+import os
+import pickle
+
+import nanoPDB
+
+directory = os.path.dirname(__file__)
+structure = nanoPDB.Parser().parse(os.path.join(directory, "1zhy.pdb"))
+models = nanoPDB.Parser().parse(os.path.join(directory, "models.pdb"))
+cif = nanoPDB.Parser().parse_cif(os.path.join(directory, "1abc.cif"))
+
+for original in (structure, models, cif):
+    for protocol in range(pickle.HIGHEST_PROTOCOL + 1):
+        restored = pickle.loads(pickle.dumps(original, protocol))
+
+        assert isinstance(restored, nanoPDB.Structure)
+        assert restored.to_pdb() == original.to_pdb()
+        assert (restored.title, restored.experimental_method, restored.resolution) == (
+            original.title,
+            original.experimental_method,
+            original.resolution,
+        )
+        assert restored.bonds == original.bonds
+        assert restored.warnings == original.warnings
+        assert [model.number for model in restored.models] == [
+            model.number for model in original.models
+        ]
+
+print(pickle.loads(pickle.dumps(structure)))
+
+atom = structure[0][0][0]
+restored = pickle.loads(pickle.dumps(atom))
+assert str(restored) == str(atom)
+assert (restored.label, restored.alt_loc) == (atom.label, atom.alt_loc)
+
+residue = pickle.loads(pickle.dumps(cif[0][1]))
+assert (residue.name, residue.number, residue.insertion_code) == ("SER", 1, "A")
+assert [(atom.name, atom.alt_loc, atom.occupancy) for atom in residue] == [
+    (atom.name, atom.alt_loc, atom.occupancy) for atom in cif[0][1]
+]
+
+chain = pickle.loads(pickle.dumps(cif["B"]))
+assert chain.name == "B"
+assert [str(atom) for atom in chain.get_atoms()] == [str(atom) for atom in cif["B"].get_atoms()]
+
+unit_cell = pickle.loads(pickle.dumps(structure.unit_cell))
+assert str(unit_cell) == str(structure.unit_cell)
+
+# Exposure marks survive pickling:
+structure.mark_exposure(1.4, 8)
+restored = pickle.loads(pickle.dumps(structure))
+assert [atom.is_exposed for atom in restored.atoms()] == [
+    atom.is_exposed for atom in structure.atoms()
+]
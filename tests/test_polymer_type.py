@@ -0,0 +1,43 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+record = "{0:<6}{1:>5}  P   {2:>3} {3}{1:>4}       0.000   0.000{4:>8.3f}  1.00  0.00           P\n"
+
+
+def chain(name, residues):
+    return "".join(
+        record.format(kind, serial, residue, name, 10.0 * serial)
+        for serial, (kind, residue) in enumerate(residues, start=1)
+    )
+
+
+content = (
+    chain("A", [("ATOM", "MET"), ("ATOM", "GLY"), ("HETATM", "MSE"), ("HETATM", "HOH")])
+    + chain("B", [("ATOM", "DA"), ("ATOM", "DC"), ("ATOM", "DG"), ("ATOM", "DT")])
+    + chain("C", [("ATOM", "A"), ("ATOM", "C"), ("ATOM", "G"), ("ATOM", "U")])
+    + chain("D", [("ATOM", "DA"), ("ATOM", "U")])
+    + chain("E", [("ATOM", "ALA"), ("ATOM", "DA")])
+    + chain("F", [("ATOM", "ALA"), ("ATOM", "XYZ")])
+    + chain("G", [("HETATM", "HOH"), ("HETATM", "HEM")])
+    + chain("H", [("ATOM", "DT"), ("HETATM", "HEM"), ("HETATM", "HOH")])
+)
+structure = parser.parse_string(content)
+types = {chain.name: chain.polymer_type() for chain in structure}
+
+print(types)
+assert types == {
+    "A": "protein",
+    "B": "dna",
+    "C": "rna",
+    "D": "other",
+    "E": "other",
+    "F": "other",
+    "G": "other",
+    "H": "dna",
+}
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+assert all(chain.polymer_type() == "protein" for chain in parser.parse(path))
@@ -0,0 +1,57 @@
+import math
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = parser.parse(path)
+
+
+def dihedral(a, b, c, d):
+    b0 = [a[i] - b[i] for i in range(3)]
+    b1 = [c[i] - b[i] for i in range(3)]
+    b2 = [d[i] - c[i] for i in range(3)]
+    length = math.sqrt(sum(value**2 for value in b1))
+    b1 = [value / length for value in b1]
+    v = [b0[i] - sum(b0[k] * b1[k] for k in range(3)) * b1[i] for i in range(3)]
+    w = [b2[i] - sum(b2[k] * b1[k] for k in range(3)) * b1[i] for i in range(3)]
+    x = sum(v[i] * w[i] for i in range(3))
+    cross = [
+        b1[1] * v[2] - b1[2] * v[1],
+        b1[2] * v[0] - b1[0] * v[2],
+        b1[0] * v[1] - b1[1] * v[0],
+    ]
+    y = sum(cross[i] * w[i] for i in range(3))
+    return math.degrees(math.atan2(y, x))
+
+
+# Backbone atom positions of the first chain, by residue number:
+backbone = {}
+
+for residue in structure[0]:
+    atoms = {atom.name: atom.position for atom in residue.get_atoms()}
+    backbone[residue.number] = atoms
+
+plot = structure.ramachandran()
+print(plot[:3])
+
+# Only residues with both torsions, the N-terminal residue -1 has no phi:
+assert all(len(entry) == 3 for entry in plot)
+assert plot[0][0] == 0
+assert -1 not in [number for number, _, _ in plot]
+
+for number, phi, psi in plot[:20]:
+    previous, current, following = backbone[number - 1], backbone[number], backbone[number + 1]
+    expected_phi = dihedral(previous["C"], current["N"], current["CA"], current["C"])
+    expected_psi = dihedral(current["N"], current["CA"], current["C"], following["N"])
+
+    assert abs(phi - expected_phi) < 1e-9
+    assert abs(psi - expected_psi) < 1e-9
+
+assert abs(plot[0][1] - -79.71) < 0.01 and abs(plot[0][2] - 148.10) < 0.01
+
+# Structures without a polymer have an empty plot:
+ligand = parser.parse(os.path.join(os.path.dirname(__file__), "conect_records.pdb"))
+assert ligand.ramachandran() == []
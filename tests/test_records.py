@@ -0,0 +1,43 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = parser.parse(path)
+assert structure.pdbid == "1ZHY"
+assert structure.classification == "LIPID BINDING PROTEIN"
+
+# Only ATOM records: no HETATM groups, no header:
+atoms_only = parser.parse(path, records={"ATOM"})
+
+print(atoms_only)
+assert atoms_only.num_atoms() == 3514
+assert all(not line.startswith("HETATM") for line in atoms_only.to_pdb().splitlines())
+assert atoms_only.pdbid == ""
+assert atoms_only.classification == ""
+assert atoms_only.date == ""
+assert atoms_only.num_atoms() < structure.num_atoms()
+
+# CONECT records are dropped together with the HETATM atoms they connect:
+path = os.path.join(os.path.dirname(__file__), "conect_records.pdb")
+assert parser.parse(path).bonds != []
+assert parser.parse(path, records={"ATOM"}).bonds == []
+assert parser.parse(path, records={"ATOM"}).num_atoms() == 0
+
+# Unknown record names are rejected before the file is read:
+for records in ({"ATOM", "ATOMS"}, {"LINK"}, {"atom"}):
+    try:
+        parser.parse(path, records=records)
+        assert False
+    except ValueError as error:
+        assert str(error) == "unsupported record type: {}".format(
+            next(iter(records - {"ATOM"}))
+        )
+
+try:
+    parser.parse("missing.pdb", records={"SHEET"})
+    assert False
+except ValueError as error:
+    assert str(error) == "unsupported record type: SHEET"
@@ -0,0 +1,33 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+structure = parser.parse(os.path.join(os.path.dirname(__file__), "1zhy.pdb"))
+chain = structure[0]
+
+print(repr(structure), repr(chain), repr(chain[0]), repr(chain[0][0]))
+assert repr(structure) == "<Structure 1ZHY: 1 chains, 3806 atoms>"
+assert repr(chain) == "<Chain A: 701 residues>"
+assert repr(chain[0]) == "<Residue MET -1: 8 atoms>"
+assert repr(chain[0][0]) == "<Atom N 1: (42.854, 36.56, 10.394)>"
+assert repr(structure.models) == "[<Model 1: 1 chains>]"
+
+# The verbose forms are kept for str():
+assert str(chain) == "Chain {\n    name: 'A',\n}"
+assert str(chain[0][0]).startswith("Atom {\n")
+
+structure = parser.parse(os.path.join(os.path.dirname(__file__), "insertion_codes.pdb"))
+assert repr(structure.get_residues()[2]) == "<Residue GLY 100A: 1 atoms>"
+
+# Structures without a PDB ID:
+content = (
+    "ATOM      1  N   GLY A   1       0.000   0.000   0.000  1.00  0.00           N\n"
+    "ATOM      2  N   GLY A   1       0.000   0.000   0.000  1.00  0.00           N\n"
+)
+structure = parser.parse_string(content)
+assert repr(structure) == "<Structure: 1 chains, 2 atoms>"
+
+# The cached atom count follows atoms removed in place:
+assert structure[0][0].deduplicate_atoms() == 1
+assert repr(structure) == "<Structure: 1 chains, 1 atoms>"
@@ -0,0 +1,38 @@
+import collections
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = parser.parse(path)
+
+kinds = collections.Counter(residue.kind() for residue in structure.residues())
+assert kinds == {"amino_acid": 436, "water": 262, "ligand": 3}
+assert kinds["water"] == len(structure.waters())
+
+names = ["ALA", "TRP", "MSE", "SEP", "DA", "U", "HOH", "wat", "DOD", "ZN", "HEM"]
+content = "".join(
+    f"HETATM{number:>5}  C   {name:>3} A{number:>4}       0.000   0.000   0.000  1.00  0.00"
+    "           C\n"
+    for number, name in enumerate(names, 1)
+)
+residues = list(parser.parse_string(content).residues())
+
+assert [residue.kind() for residue in residues] == [
+    "amino_acid",
+    "amino_acid",
+    "amino_acid",
+    "amino_acid",
+    "nucleotide",
+    "nucleotide",
+    "water",
+    "water",
+    "water",
+    "ligand",
+    "ligand",
+]
+assert [residue.is_amino_acid() for residue in residues] == [True] * 4 + [False] * 7
+assert [residue.is_nucleic() for residue in residues] == [False] * 4 + [True] * 2 + [False] * 5
+assert [residue.is_water() for residue in residues] == [False] * 6 + [True] * 3 + [False] * 2
@@ -0,0 +1,23 @@
+import os
+
+import nanoPDB
+
+path = os.path.join(os.path.dirname(__file__), "1abc.cif")
+chain = nanoPDB.Parser().parse_cif(path)["A"]
+
+assert chain.residue(1) is chain[0]
+assert chain.residue(1, "A") is chain[1]
+assert chain.residue(1, insertion_code="A") is chain[1]
+assert chain.residue(102).name == "ZN"
+print(chain.residue(1, "A"))
+
+assert 1 in chain
+assert 101 in chain
+assert 50 not in chain
+
+for number, insertion_code in ((50, None), (1, "B"), (101, "A")):
+    try:
+        chain.residue(number, insertion_code)
+        assert False
+    except KeyError as error:
+        print(error)
@@ -0,0 +1,73 @@
+import http.server
+import os
+import threading
+import time
+
+import nanoPDB
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+
+with open(path, "rb") as file:
+    content = file.read()
+
+requests = []
+
+
+class Handler(http.server.BaseHTTPRequestHandler):
+    # /flaky answers 503 to the first two requests, /missing is always 404:
+    def do_GET(self):
+        requests.append(self.path)
+
+        if self.path == "/flaky" and requests.count("/flaky") > 2:
+            self.send_response(200)
+            self.send_header("Content-Length", str(len(content)))
+            self.end_headers()
+            self.wfile.write(content)
+        else:
+            self.send_response(503 if self.path == "/flaky" else 404)
+            self.send_header("Content-Length", "0")
+            self.end_headers()
+
+    def log_message(self, format, *args):
+        pass
+
+
+server = http.server.ThreadingHTTPServer(("127.0.0.1", 0), Handler)
+threading.Thread(target=server.serve_forever, daemon=True).start()
+url = "http://127.0.0.1:{}/".format(server.server_address[1])
+
+# Server errors are retried, after 0.5 s and then 1.0 s:
+start = time.monotonic()
+structure = nanoPDB.Parser(timeout=10, retries=3).parse(url + "flaky")
+elapsed = time.monotonic() - start
+
+print(structure, elapsed)
+assert structure.num_atoms() == nanoPDB.Parser().parse(path).num_atoms()
+assert requests.count("/flaky") == 3
+assert 1.5 <= elapsed < 5.0
+
+# Without enough retries the last server error is raised:
+requests.clear()
+
+try:
+    nanoPDB.Parser(timeout=10, retries=1).parse(url + "flaky")
+    assert False
+except Exception as error:
+    assert "503" in str(error)
+
+assert requests.count("/flaky") == 2
+
+# Client errors are not retried:
+requests.clear()
+start = time.monotonic()
+
+try:
+    nanoPDB.Parser(timeout=10, retries=3).parse(url + "missing")
+    assert False
+except Exception as error:
+    assert "404" in str(error)
+
+assert requests == ["/missing"]
+assert time.monotonic() - start < 0.5
+
+server.shutdown()
@@ -0,0 +1,41 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = parser.parse(path)
+
+assert structure.rmsd(structure) == 0.0
+assert structure.rmsd(structure, "all") == 0.0
+
+shifted = structure.copy()
+shifted.translate((3.0, 4.0, 0.0))
+
+for selection in ("CA", "backbone", "all"):
+    assert abs(structure.rmsd(shifted, selection) - 5.0) < 1e-9
+    assert structure.rmsd(shifted, selection=selection) == structure.rmsd(shifted, selection)
+
+# Calcium ions named CA are not alpha carbons:
+content = (
+    "ATOM      1  N   GLY A   1       0.000   0.000   0.000  1.00  0.00           N\n"
+    "ATOM      2  CA  GLY A   1       1.460   0.000   0.000  1.00  0.00           C\n"
+    "HETATM    3 CA    CA A   2       5.000   0.000   0.000  1.00  0.00          CA\n"
+)
+first = parser.parse_string(content)
+second = parser.parse_string(content.replace("   1.460", "   2.460"))
+assert first.rmsd(second) == 1.0
+assert abs(first.rmsd(second, "all") - (1.0 / 3.0) ** 0.5) < 1e-12
+
+try:
+    structure.rmsd(structure.remove_water(), "all")
+    assert False
+except ValueError as error:
+    assert "3806 and 3544" in str(error)
+
+try:
+    structure.rmsd(structure, "sidechain")
+    assert False
+except ValueError:
+    pass
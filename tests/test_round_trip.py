@@ -0,0 +1,51 @@
+import os
+import tempfile
+
+import nanoPDB
+
+
+def atom_records(structure):
+    return [
+        (
+            atom.number,
+            atom.name,
+            atom.alt_loc,
+            atom.element,
+            atom.charge,
+            tuple(round(coordinate, 3) for coordinate in atom.position),
+            atom.occupancy,
+            atom.b_factor,
+        )
+        for atom in structure.get_atoms()
+    ]
+
+
+parser = nanoPDB.Parser()
+
+for name in ["1zhy.pdb", "models.pdb", "insertion_codes.pdb", "alt_locs.pdb"]:
+    structure = parser.parse(os.path.join(os.path.dirname(__file__), name))
+
+    with tempfile.TemporaryDirectory() as directory:
+        path = os.path.join(directory, name)
+        structure.save(path)
+        copy = parser.parse(path)
+
+    print(name, copy)
+    assert atom_records(copy) == atom_records(structure)
+    assert [chain.name for chain in copy] == [chain.name for chain in structure]
+    assert [model.number for model in copy.models] == [
+        model.number for model in structure.models
+    ]
+    assert (copy.pdbid, copy.classification, copy.date) == (
+        structure.pdbid,
+        structure.classification,
+        structure.date,
+    )
+
+# Alternate locations are written to column 17:
+structure = parser.parse(os.path.join(os.path.dirname(__file__), "alt_locs.pdb"))
+lines = structure.to_pdb().splitlines()
+print(lines[1])
+assert [line[16] for line in lines if line.startswith("ATOM")][:5] == [" ", "A", "B", "A", "B"]
+copy = parser.parse_string(structure.to_pdb())
+assert [atom.alt_loc for atom in copy.get_atoms()][:5] == [None, "A", "B", "A", "B"]
@@ -0,0 +1,45 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = parser.parse(path)
+
+polymer = structure.select_atoms("ATOM")
+hetero = structure.select_atoms("HETATM")
+
+assert polymer.num_atoms() == 3514
+assert polymer.num_atoms() + hetero.num_atoms() == structure.num_atoms()
+assert all(atom.label == "ATOM" for atom in polymer.atoms())
+assert all(atom.label == "HETATM" for atom in hetero.atoms())
+assert polymer.waters() == []
+assert len(hetero.waters()) == len(structure.waters())
+
+content = (
+    "ATOM      1  N   MSE A   1       0.000   0.000   0.000  1.00  0.00           N\n"
+    "HETATM    2 SE   MSE A   1       1.000   0.000   0.000  1.00  0.00          SE\n"
+    "ATOM      3  N   GLY A   2       2.000   0.000   0.000  1.00  0.00           N\n"
+    "HETATM    4 ZN    ZN B 101       3.000   0.000   0.000  1.00  0.00          ZN\n"
+)
+
+structure = parser.parse_string(content)
+
+# Residues with both record types keep only the selected atoms, empty chains are dropped:
+polymer = structure.select_atoms("ATOM")
+assert [chain.name for chain in polymer] == ["A"]
+assert [[atom.name for atom in residue] for residue in polymer.residues()] == [["N"], ["N"]]
+
+hetero = structure.select_atoms("HETATM")
+assert [chain.name for chain in hetero] == ["A", "B"]
+assert [[atom.name for atom in residue] for residue in hetero.residues()] == [["SE"], ["ZN"]]
+
+# The selection is a copy:
+assert polymer[0][0][0] is not structure[0][0][0]
+
+try:
+    structure.select_atoms("atom")
+    raise AssertionError("invalid label accepted")
+except ValueError as error:
+    assert str(error) == 'label must be "ATOM" or "HETATM", got: "atom"'
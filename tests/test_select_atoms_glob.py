@@ -0,0 +1,31 @@
+import fnmatch
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = parser.parse(path)
+atoms = structure.get_atoms()
+
+# The same selection as fnmatch (without character classes), in file order:
+for pattern in ("*G*", "C?", "O*", "N", "*1", "?", "*", "C*2", "XYZ*"):
+    selected = [atom.number for atom in structure.select_atoms_glob(pattern)]
+    expected = [atom.number for atom in atoms if fnmatch.fnmatchcase(atom.name, pattern)]
+
+    assert selected == expected, pattern
+
+print(len(structure.select_atoms_glob("*G*")))
+assert len(structure.select_atoms_glob("*G*")) == 451
+assert len(structure.select_atoms_glob("*")) == structure.num_atoms()
+assert structure.select_atoms_glob("XYZ*") == []
+
+# "?" matches exactly one character, "*" also an empty sequence:
+assert {atom.name for atom in structure.select_atoms_glob("C?")} >= {"CA", "CB", "CG"}
+assert "C" not in {atom.name for atom in structure.select_atoms_glob("C?")}
+assert "C" in {atom.name for atom in structure.select_atoms_glob("C*")}
+
+# Matching is case-sensitive and against the whole name:
+assert structure.select_atoms_glob("ca") == []
+assert all(atom.name == "CA" for atom in structure.select_atoms_glob("CA"))
@@ -0,0 +1,50 @@
+import os
+import pickle
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = parser.parse(path)
+chain = structure[0]
+
+seqres = chain.seqres()
+assert len(seqres) == 438
+assert seqres[:5] == ["GLY", "ALA", "MET", "ASP", "PRO"]
+assert seqres[-3:] == ["ILE", "VAL", "LEU"]
+assert len(seqres) - sum(residue.is_amino_acid() for residue in chain) == 2
+
+assert pickle.loads(pickle.dumps(structure))[0].seqres() == seqres
+assert structure.copy()[0].seqres() == seqres
+assert structure.remove_water()[0].seqres() == seqres
+assert parser.parse(path, {"ATOM"})[0].seqres() == []
+
+# Records of two chains, with the continuation of chain A out of order:
+content = (
+    "SEQRES   2 A    5  ALA LYS                                                  \n"
+    "SEQRES   1 A    5  MET GLY SER                                              \n"
+    "SEQRES   1 B    2  DA  DT                                                   \n"
+    "MODEL        1\n"
+    "ATOM      1  CA  GLY A   2       0.000   0.000   0.000  1.00  0.00           C\n"
+    "ATOM      2  P    DA B   1       5.000   0.000   0.000  1.00  0.00           P\n"
+    "ENDMDL\n"
+    "MODEL        2\n"
+    "ATOM      1  CA  GLY A   2       1.000   0.000   0.000  1.00  0.00           C\n"
+    "ATOM      2  P    DA B   1       6.000   0.000   0.000  1.00  0.00           P\n"
+    "ENDMDL\n"
+)
+small = parser.parse_string(content)
+
+assert small["A"].seqres() == ["MET", "GLY", "SER", "ALA", "LYS"]
+assert small["B"].seqres() == ["DA", "DT"]
+assert small.model(1).get_chains()[0].seqres() == ["MET", "GLY", "SER", "ALA", "LYS"]
+
+malformed = "SEQRES   X A    5  ALA LYS\n" + content
+try:
+    parser.parse_string(malformed)
+    assert False
+except Exception as error:
+    assert "cannot parse SEQRES serial number" in str(error)
+
+assert len(parser.parse_string(malformed, None, False).warnings) == 1
@@ -0,0 +1,37 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+# Hemoglobin subunit alpha (UniProt P69905), the checksum of its UniProt entry:
+sequence = (
+    "MVLSPADKTNVKAAWGKVGAHAGEYGAEALERMFLSFPTTKTYFPHFDLSHGSAQVKGHGKKVADALTNAVAHVDDMPNALSA"
+    "LSDLHAHKLRVDPVNFKLLSHCLLVTLAAHLPAEFTPAVHASLDKFLASVSTVLTSKYR"
+)
+names = dict(
+    zip(
+        "ACDEFGHIKLMNPQRSTVWY",
+        "ALA CYS ASP GLU PHE GLY HIS ILE LYS LEU MET ASN PRO GLN ARG SER THR VAL TRP TYR".split(),
+    )
+)
+record = "ATOM  {0:>5}  CA  {1} A{0:>4}    {2:>8.3f}   0.000   0.000  1.00  0.00           C\n"
+content = "".join(
+    record.format(number, names[code], 3.8 * number)
+    for number, code in enumerate(sequence, start=1)
+)
+
+chain = parser.parse_string(content)[0]
+assert chain.sequence() == sequence
+assert chain.sequence_hash() == "15E13666573BBBAE"
+
+# A chain of the local fixture:
+structure = parser.parse(os.path.join(os.path.dirname(__file__), "1zhy.pdb"))
+print(structure[0].sequence_hash())
+assert structure[0].sequence_hash() == "DF6AEF59DCD2892F"
+
+# Equal sequences have equal checksums, coordinates do not matter:
+moved = structure.copy()
+moved.translate((5.0, 0.0, 0.0))
+assert moved[0].sequence_hash() == structure[0].sequence_hash()
+assert chain.sequence_hash() != structure[0].sequence_hash()
@@ -0,0 +1,45 @@
+import itertools
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+record = "HETATM{0:>5}  C   UNK A{0:>4}    {1:>8.3f}{2:>8.3f}{3:>8.3f}  1.00  0.00           C\n"
+
+
+def shape(points):
+    return parser.parse_string(
+        "".join(record.format(number, *point) for number, point in enumerate(points, start=1))
+    )
+
+
+# Rod (collinear atoms):
+rod = shape([(0.0, 0.0, 1.5 * i) for i in range(10)])
+assert abs(rod.shape_anisotropy() - 1.0) < 1e-9
+assert abs(rod.asphericity() - 1.0) < 1e-9
+
+# Corners of a cube (isotropic gyration tensor):
+cube = shape(list(itertools.product((0.0, 4.0), repeat=3)))
+assert abs(cube.shape_anisotropy()) < 1e-9
+assert abs(cube.asphericity()) < 1e-9
+
+# Corners of a square (planar symmetric):
+square = shape([(0.0, 0.0, 0.0), (4.0, 0.0, 0.0), (0.0, 4.0, 0.0), (4.0, 4.0, 0.0)])
+assert abs(square.shape_anisotropy() - 0.25) < 1e-9
+assert abs(square.asphericity() - 0.25) < 1e-9
+
+# Structures without extent are rejected:
+for structure in (shape([(1.0, 2.0, 3.0)]), shape([(1.0, 2.0, 3.0), (1.0, 2.0, 3.0)])):
+    for method in (structure.shape_anisotropy, structure.asphericity):
+        try:
+            method()
+            assert False
+        except ValueError:
+            pass
+
+# The protein of the local fixture is somewhat elongated:
+structure = parser.parse(os.path.join(os.path.dirname(__file__), "1zhy.pdb"))
+print(structure.shape_anisotropy(), structure.asphericity())
+assert 0.0572 <= structure.shape_anisotropy() < 0.0573
+assert 0.2306 <= structure.asphericity() < 0.2307
@@ -0,0 +1,41 @@
+import nanoPDB
+
+parser = nanoPDB.Parser()
+record = "ATOM  {0:>5}  CA  GLY {1}{2:>4}    {3:>8.3f}   0.000   0.000  1.00  0.00           C\n"
+
+residues = [("A", 1), ("A", 2), ("A", 3), ("A", 10), ("A", 11), ("A", 50), ("B", 1), ("B", 2)]
+structure = parser.parse_string(
+    "".join(
+        record.format(index + 1, chain, number, 3.8 * index)
+        for index, (chain, number) in enumerate(residues)
+    )
+)
+structure.split_on_gaps(5)
+
+chains = [(chain.name, [residue.number for residue in chain]) for chain in structure]
+
+print(chains)
+assert chains == [("A", [1, 2, 3]), ("C", [10, 11]), ("D", [50]), ("B", [1, 2])]
+assert [chain.name for chain in structure.models[0]] == ["A", "C", "D", "B"]
+
+# Restarted numbering is a jump as well, a threshold covering all jumps keeps the chains:
+structure = parser.parse_string(
+    "".join(
+        record.format(index + 1, "A", number, 3.8 * index)
+        for index, number in enumerate([5, 6, 1])
+    )
+)
+structure.split_on_gaps(100)
+assert [chain.name for chain in structure] == ["A"]
+
+structure.split_on_gaps(2)
+assert [(chain.name, [residue.number for residue in chain]) for chain in structure] == [
+    ("A", [5, 6]),
+    ("B", [1]),
+]
+
+try:
+    structure.split_on_gaps(0)
+    assert False
+except ValueError:
+    pass
@@ -0,0 +1,20 @@
+import os
+
+import nanoPDB
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = nanoPDB.Parser().parse(path)
+
+nested = [atom for chain in structure for residue in chain for atom in residue]
+flat = list(structure.atoms())
+
+print(len(flat))
+assert len(flat) == structure.num_atoms() == len(nested)
+assert [atom.number for atom in flat] == [atom.number for atom in nested]
+
+iterator = structure.atoms()
+assert iter(iterator) is iterator
+assert next(iterator).number == nested[0].number
+
+# Atoms are shared with the structure, not copied:
+assert flat[0] is structure[0][0][0]
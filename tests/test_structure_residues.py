@@ -0,0 +1,20 @@
+import os
+
+import nanoPDB
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = nanoPDB.Parser().parse(path)
+
+nested = [residue for chain in structure for residue in chain]
+flat = list(structure.residues())
+
+print(len(flat))
+assert len(flat) == structure.num_residues() == len(nested) == 701
+assert [(residue.name, residue.number) for residue in flat] == [
+    (residue.name, residue.number) for residue in nested
+]
+assert flat[0] is structure[0][0]
+
+iterator = structure.residues()
+assert iter(iterator) is iterator
+assert next(iterator).name == "MET"
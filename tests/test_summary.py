@@ -0,0 +1,46 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = parser.parse(path)
+
+summary = structure.summary()
+print(summary)
+assert summary == {
+    "pdbid": "1ZHY",
+    "classification": "LIPID BINDING PROTEIN",
+    "date": "26-APR-05",
+    "title": "STRUCTURE OF YEAST OXYSTEROL BINDING PROTEIN OSH4 IN COMPLEX WITH CHOLESTEROL",
+    "experimental_method": "X-RAY DIFFRACTION",
+    "resolution": 1.6,
+    "num_chains": 1,
+    "num_residues": 701,
+    "num_atoms": 3806,
+    "has_unit_cell": True,
+}
+
+# The counts follow the contents:
+assert structure.remove_water().summary()["num_atoms"] < summary["num_atoms"]
+
+# A structure without header records (and without a CRYST1 record):
+summary = parser.parse_string(
+    "ATOM      1  N   GLY A   1       0.000   0.000   0.000  1.00  0.00           N\n"
+    "ATOM      2  CA  GLY A   1       1.460   0.000   0.000  1.00  0.00           C\n"
+    "ATOM      3  N   GLY B   1       5.000   0.000   0.000  1.00  0.00           N\n"
+).summary()
+
+assert summary == {
+    "pdbid": "",
+    "classification": "",
+    "date": "",
+    "title": "",
+    "experimental_method": "",
+    "resolution": None,
+    "num_chains": 2,
+    "num_residues": 2,
+    "num_atoms": 3,
+    "has_unit_cell": False,
+}
@@ -0,0 +1,80 @@
+import math
+import os
+import random
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = parser.parse(path)
+
+
+def rotation(axis, angle):
+    x, y, z = (value / math.sqrt(sum(value**2 for value in axis)) for value in axis)
+    c, s = math.cos(angle), math.sin(angle)
+
+    return [
+        [c + x * x * (1 - c), x * y * (1 - c) - z * s, x * z * (1 - c) + y * s],
+        [y * x * (1 - c) + z * s, c + y * y * (1 - c), y * z * (1 - c) - x * s],
+        [z * x * (1 - c) - y * s, z * y * (1 - c) + x * s, c + z * z * (1 - c)],
+    ]
+
+
+# A rigidly moved copy is superposed back exactly:
+moved = structure.copy()
+moved.rotate(rotation((1.0, 2.0, -0.5), 2.5))
+moved.translate((10.0, -20.0, 5.0))
+assert moved.rmsd(structure) > 10.0
+
+for selection in ("CA", "backbone", "all"):
+    assert moved.superpose(structure, selection) < 1e-6
+    assert moved.rmsd(structure, "all") < 1e-6
+
+moved.translate((1.0, 0.0, 0.0))
+assert moved.superpose(structure, selection="backbone") < 1e-6
+
+# Half-turn about the z axis:
+flipped = structure.copy()
+flipped.rotate(rotation((0.0, 0.0, 1.0), math.pi))
+assert flipped.superpose(structure) < 1e-6
+
+
+def moved_atoms(structure, move):
+    lines = []
+
+    for line in structure.to_pdb().splitlines():
+        if line.startswith(("ATOM", "HETATM")):
+            x, y, z = move(float(line[30:38]), float(line[38:46]), float(line[46:54]))
+            line = "{}{:8.3f}{:8.3f}{:8.3f}{}".format(line[:30], x, y, z, line[54:])
+
+        lines.append(line)
+
+    return parser.parse_string("\n".join(lines))
+
+
+# The superposition can only lower the RMSD of perturbed atoms:
+random.seed(7)
+noisy = moved_atoms(
+    structure,
+    lambda x, y, z: (x + random.gauss(0, 0.5), y + random.gauss(0, 0.5), z + random.gauss(0, 0.5)),
+)
+
+before = noisy.rmsd(structure)
+after = noisy.superpose(structure)
+assert after <= before + 1e-9
+assert abs(noisy.rmsd(structure) - after) < 1e-9
+
+# Mirror images can not be superposed with a proper rotation:
+mirrored = moved_atoms(structure, lambda x, y, z: (-x, y, z))
+assert mirrored.superpose(structure) > 1.0
+
+phi_before = structure[0].phi_psi()[5][1]
+phi_after = mirrored[0].phi_psi()[5][1]
+assert abs(phi_before + phi_after) < 1e-6
+
+try:
+    moved.superpose(structure.remove_water(), "all")
+    assert False
+except ValueError as error:
+    assert "3806 and 3544" in str(error)
@@ -0,0 +1,27 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+path = os.path.join(os.path.dirname(__file__), "ter_records.pdb")
+structure = parser.parse(path)
+
+chains = [
+    (chain.name, [residue.name for residue in chain.get_residues()])
+    for chain in structure.get_chains()
+]
+
+print(chains)
+assert chains == [("A", ["ALA", "GLY"]), ("C", ["LYS", "LEU", "HOH"]), ("B", ["SER"])]
+
+# HETATM records are split after a TER that closed a non-water HETATM segment:
+path = os.path.join(os.path.dirname(__file__), "ter_hetatm_records.pdb")
+structure = parser.parse(path)
+
+chains = [
+    (chain.name, [(residue.name, residue.number) for residue in chain.get_residues()])
+    for chain in structure.get_chains()
+]
+
+print(chains)
+assert chains == [("A", [("LIG", 1)]), ("B", [("LIG", 2), ("HOH", 101)])]
@@ -0,0 +1,24 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+structure = parser.parse(path)
+
+assert structure.title == (
+    "STRUCTURE OF YEAST OXYSTEROL BINDING PROTEIN OSH4 IN COMPLEX WITH CHOLESTEROL"
+)
+assert parser.parse(path, {"ATOM"}).title == ""
+
+content = (
+    "TITLE     CRYSTAL STRUCTURE OF A VERY LONG TITLE THAT IS CONTINUED OVER THREE    \n"
+    "TITLE    2 RECORDS, WITH CONTINUATION NUMBERS IN COLUMNS 9-10 AND THE TEXT      \n"
+    "TITLE    3 FROM COLUMN 11                                                       \n"
+    "ATOM      1  CA  GLY A   1       0.000   0.000   0.000  1.00  0.00           C\n"
+)
+assert parser.parse_string(content).title == (
+    "CRYSTAL STRUCTURE OF A VERY LONG TITLE THAT IS CONTINUED OVER THREE RECORDS, WITH "
+    "CONTINUATION NUMBERS IN COLUMNS 9-10 AND THE TEXT FROM COLUMN 11"
+)
@@ -0,0 +1,44 @@
+import math
+import os
+
+import nanoPDB
+
+directory = os.path.dirname(__file__)
+structure = nanoPDB.Parser().parse(os.path.join(directory, "models.pdb"))
+
+
+def positions(structure):
+    return [
+        [atom.position for atom in model.get_chains()[0].get_atoms()] for model in structure.models
+    ]
+
+
+original = positions(structure)
+
+moved = structure.copy()
+moved.translate((1.0, -2.0, 0.5))
+
+# The copy is independent of the original:
+assert positions(structure) == original
+
+for model_positions, model_original in zip(positions(moved), original):
+    for position, (x, y, z) in zip(model_positions, model_original):
+        assert all(map(math.isclose, position, (x + 1.0, y - 2.0, z + 0.5)))
+
+# Rotation by 90 degrees about the z axis, applied to all models:
+rotated = structure.copy()
+rotated.rotate([[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]])
+
+for model_positions, model_original in zip(positions(rotated), original):
+    for position, (x, y, z) in zip(model_positions, model_original):
+        assert position == (-y, x, z)
+
+for matrix in (
+    [[2.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+    [[-1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+):
+    try:
+        structure.rotate(matrix)
+        assert False
+    except ValueError as error:
+        print(error)
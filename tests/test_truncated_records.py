@@ -0,0 +1,25 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+path = os.path.join(os.path.dirname(__file__), "truncated_records.pdb")
+structure = parser.parse(path)
+
+atoms = [
+    (atom.name, atom.element, atom.occupancy, atom.b_factor) for atom in structure.get_atoms()
+]
+
+print(atoms)
+assert atoms == [
+    ("N", "N", 1.0, 12.5),
+    ("CA", "C", 1.0, 0.0),
+    ("1HA", "H", 1.0, 3.0),
+    ("C", "C", 0.5, 0.0),
+]
+
+# Lines ending within the B-factor keep its leading columns:
+partial = parser.parse_string(
+    "ATOM      1  N   GLY A   1       0.000   0.000   0.000  1.00  7.2\n"
+)
+assert partial[0][0][0].b_factor == 7.2
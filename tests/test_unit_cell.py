@@ -0,0 +1,58 @@
+import math
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+unit_cell = parser.parse(path).unit_cell
+
+with open(path) as file:
+    scale = [
+        [float(value) for value in line[10:40].split()]
+        for line in file
+        if line.startswith("SCALE")
+    ]
+
+to_fractional = unit_cell.cartesian_to_fractional()
+to_cartesian = unit_cell.fractional_to_cartesian()
+
+print(to_fractional)
+assert all(
+    abs(to_fractional[row][column] - scale[row][column]) < 1e-6
+    for row in range(3)
+    for column in range(3)
+)
+
+for row in range(3):
+    for column in range(3):
+        product = sum(to_cartesian[row][k] * to_fractional[k][column] for k in range(3))
+        assert abs(product - (row == column)) < 1e-12
+
+assert abs(unit_cell.volume() - 506954.851) < 1e-3
+
+path = os.path.join(os.path.dirname(__file__), "models.pdb")
+
+try:
+    parser.parse(path).unit_cell.cartesian_to_fractional()
+    assert False
+except ValueError as error:
+    assert str(error) == "unit cell has no volume"
+
+
+def cell(a, b, c, alpha, beta, gamma):
+    content = "CRYST1{:9.3f}{:9.3f}{:9.3f}{:7.2f}{:7.2f}{:7.2f} P 1           1\n".format(
+        a, b, c, alpha, beta, gamma
+    )
+    return parser.parse_string(content).unit_cell
+
+
+# Orthorhombic cell: the volume is abc:
+assert abs(cell(10.0, 20.0, 30.0, 90.0, 90.0, 90.0).volume() - 6000.0) < 1e-9
+
+# Hexagonal cell: abc * sin(gamma):
+assert abs(cell(5.0, 5.0, 7.0, 90.0, 90.0, 120.0).volume() - 175.0 * math.sqrt(3) / 2) < 1e-9
+
+# Rhombohedral (primitive cell of a face-centred cube with the edge 10 * sqrt(2)), the
+# volume is a quarter of the cube, 1000 / sqrt(2):
+assert abs(cell(10.0, 10.0, 10.0, 60.0, 60.0, 60.0).volume() - 1000.0 / math.sqrt(2)) < 1e-9
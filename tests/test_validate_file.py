@@ -0,0 +1,21 @@
+import os
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+path = os.path.join(os.path.dirname(__file__), "invalid_records.pdb")
+
+warnings = parser.validate_file(path)
+
+print(warnings)
+assert warnings == [
+    "line: 3, coordinate (columns 31-38) is misaligned",
+    "line: 4, atom number (columns 7-11) is not numeric",
+    "line: 5, missing element (columns 77-78)",
+]
+
+# The problems are only reported, the file is not modified:
+assert parser.validate_file(path) == warnings
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+assert parser.validate_file(path) == []
@@ -0,0 +1,39 @@
+import os
+
+import nanoPDB
+
+path = os.path.join(os.path.dirname(__file__), "1zhy.pdb")
+
+parser = nanoPDB.Parser()
+structure = parser.parse(path)
+waters = len(structure.waters())
+assert waters > 0
+
+dry = structure.remove_water()
+assert dry.waters() == []
+assert dry.num_atoms() == structure.num_atoms() - waters
+assert structure.num_atoms() == 3806
+
+# Other HETATM groups are kept:
+hetatm = [atom for atom in structure.atoms() if atom.label == "HETATM"]
+assert len([atom for atom in dry.atoms() if atom.label == "HETATM"]) == len(hetatm) - waters
+
+skipped = nanoPDB.Parser(skip_water=True).parse(path)
+assert skipped.to_pdb() == dry.to_pdb()
+
+content = (
+    "ATOM      1  N   GLY A   1       0.000   0.000   0.000  1.00  0.00           N\n"
+    "HETATM    2  O   hoh A   2       3.000   0.000   0.000  1.00  0.00           O\n"
+    "HETATM    3  O   WAT B   1       6.000   0.000   0.000  1.00  0.00           O\n"
+    "HETATM    4 ZN    ZN A 101       9.000   0.000   0.000  1.00  0.00          ZN\n"
+)
+
+structure = parser.parse_string(content)
+assert [residue.name for residue in structure.remove_water().residues()] == ["GLY", "ZN"]
+assert structure.remove_water().num_chains() == 1
+
+structure = nanoPDB.Parser(skip_water=True).parse_string(content)
+assert [residue.name for residue in structure.residues()] == ["GLY", "ZN"]
+
+path = os.path.join(os.path.dirname(__file__), "1abc.cif")
+assert nanoPDB.Parser(skip_water=True).parse_cif(path).waters() == []
@@ -0,0 +1,52 @@
+import os
+import tempfile
+
+import nanoPDB
+
+parser = nanoPDB.Parser()
+
+content = (
+    "ATOM      1  N   GLY A   1       0.000   0.000   0.000  1.00  0.00           N\n"
+    "ATOM      2  CA  GLY A   1       1.460   0.000   0.000  1.00  0.00           C\n"
+    "ATOM      3  C   GLY A   1       2.000   1.400   0.000  1.00  0.00           C\n"
+    "ATOM      4  N   ALA B   5       5.000   0.000   0.000  1.00  0.00           N\n"
+    "ATOM      5  CA  ALA B   5       6.460   0.000   0.000  1.00  0.00           C\n"
+    "ATOM      6  CB  ALA B   5       7.000   1.400   0.000  1.00  0.00           C\n"
+)
+structure = parser.parse_string(content)
+atoms = structure.get_atoms()
+
+# The selection is given out of order, the atoms are written in the structure order:
+carbons = [atoms[5], atoms[1], atoms[4], atoms[2]]
+
+with tempfile.TemporaryDirectory() as directory:
+    path = os.path.join(directory, "carbons.pdb")
+
+    structure.write_selection(path, carbons)
+
+    with open(path) as file:
+        lines = file.read().splitlines()
+
+    print("\n".join(lines))
+    # Each chain ends with a TER record:
+    assert [line[:4] for line in lines] == ["ATOM", "ATOM", "TER", "ATOM", "ATOM", "TER", "END"]
+
+    written = parser.parse(path)
+    assert [atom.name for atom in written.get_atoms()] == ["CA", "C", "CA", "CB"]
+    assert [atom.number for atom in written.get_atoms()] == [1, 2, 3, 4]
+    assert [chain.name for chain in written] == ["A", "B"]
+    assert [residue.number for chain in written for residue in chain] == [1, 5]
+    assert written.get_atoms()[3].position == (7.0, 1.4, 0.0)
+
+    # The original atom numbers:
+    structure.write_selection(path, carbons, renumber=False)
+    assert [atom.number for atom in parser.parse(path).get_atoms()] == [2, 3, 5, 6]
+
+    # Atoms of another structure are rejected:
+    other = parser.parse_string(content)
+
+    try:
+        structure.write_selection(path, [other.get_atoms()[0]])
+        assert False
+    except ValueError:
+        pass